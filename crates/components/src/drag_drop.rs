@@ -1,12 +1,23 @@
 use dioxus::prelude::*;
 use freya_elements::{
     self as dioxus_elements,
-    events::MouseEvent,
+    events::{
+        DragEvent,
+        DragPayload,
+        MouseEvent,
+    },
     MouseButton,
 };
-use freya_hooks::use_node_signal;
+use freya_hooks::{
+    use_node_signal,
+    use_platform,
+};
 use torin::prelude::CursorPoint;
 
+/// Distance the cursor has to move while pressed for a [`DragZone`] to start dragging,
+/// so clicking the elements inside of it still works.
+const DRAG_THRESHOLD: f64 = 5.0;
+
 /// Properties for the [`DragProvider`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct DragProviderProps {
@@ -23,34 +34,49 @@ pub fn DragProvider<T: 'static>(DragProviderProps { children }: DragProviderProp
 
 /// Properties for the [`DragZone`] component.
 #[derive(Props, Clone, PartialEq)]
-pub struct DragZoneProps<T: Clone + 'static + PartialEq> {
+pub struct DragZoneProps<T: Clone + 'static + PartialEq + Send + Sync> {
     /// Element visible when dragging the element. This follows the cursor.
     drag_element: Element,
     /// Inner children for the DropZone.
     children: Element,
     /// Data that will be handled to the destination [`DropZone`].
     data: T,
+    /// Plain text representation of the data, for drop targets that don't know about its type.
+    text: Option<String>,
+    /// URI representation of the data, for drop targets that don't know about its type.
+    uri: Option<String>,
     /// Hide the [`DragZone`] children when dragging.
     #[props(default = false)]
     hide_while_dragging: bool,
 }
 
-/// Make the inner children draggable to other [`DropZone`].
+/// Make the inner children draggable to other [`DropZone`], even if they are in other windows.
+///
+/// Dragging starts once the cursor moves a few pixels while pressed, until then the mouse events
+/// reach the inner children as usual. When the cursor leaves the window, a snapshot of the [`DragZone`] is used as preview.
+///
+/// The drag session is handled by Freya within the windows of the app. When the cursor leaves them, it's handed to the
+/// drag out handler registered with `LaunchConfig::with_drag_out_handler`, which can start a native drag of the `text` or `uri`
+/// representations so other applications can receive them.
 #[allow(non_snake_case)]
-pub fn DragZone<T: 'static + Clone + PartialEq>(
+pub fn DragZone<T: 'static + Clone + PartialEq + Send + Sync>(
     DragZoneProps {
         data,
         children,
         drag_element,
+        text,
+        uri,
         hide_while_dragging,
     }: DragZoneProps<T>,
 ) -> Element {
-    let mut drags = use_context::<Signal<Option<T>>>();
+    let mut drags = try_use_context::<Signal<Option<T>>>();
+    let platform = use_platform();
     let mut dragging = use_signal(|| false);
     let mut pos = use_signal(CursorPoint::default);
+    let mut pressed_at = use_signal::<Option<CursorPoint>>(|| None);
     let (node_reference, size) = use_node_signal();
 
-    let onglobalmousemove = move |e: MouseEvent| {
+    let onglobaldragover = move |e: DragEvent| {
         if *dragging.read() {
             let size = size.read();
             let coord = e.get_screen_coordinates();
@@ -68,8 +94,19 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
         if e.data.trigger_button != Some(MouseButton::Left) {
             return;
         }
-        let size = size.read();
+        pressed_at.set(Some(e.get_screen_coordinates()));
+    };
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        let Some(pressed_at) = *pressed_at.read() else {
+            return;
+        };
         let coord = e.get_screen_coordinates();
+        if *dragging.read() || (coord - pressed_at).length() < DRAG_THRESHOLD {
+            return;
+        }
+
+        let size = size.read();
         pos.set(
             (
                 coord.x - size.area.min_x() as f64,
@@ -78,27 +115,47 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
                 .into(),
         );
         dragging.set(true);
-        *drags.write() = Some(data.clone());
+        if let Some(drags) = drags.as_mut() {
+            *drags.write() = Some(data.clone());
+        }
+
+        let mut payload = DragPayload::new(data.clone());
+        if let Some(text) = &text {
+            payload = payload.with_text(text);
+        }
+        if let Some(uri) = &uri {
+            payload = payload.with_uri(uri);
+        }
+        platform.start_drag(payload, Some(size.area));
     };
 
     let onglobalclick = move |_: MouseEvent| {
+        pressed_at.set(None);
+    };
+
+    let onglobaldragend = move |_: DragEvent| {
+        pressed_at.set(None);
         if *dragging.read() {
             dragging.set(false);
             pos.set((0.0, 0.0).into());
-            *drags.write() = None;
+            if let Some(drags) = drags.as_mut() {
+                *drags.write() = None;
+            }
         }
     };
 
-    // Extend by 1. so that the cursor click can reach the drop zone
+    // Extend by 1. so that the cursor can reach the drop zone
     let x = pos.read().x + 1.;
     let y = pos.read().y + 1.;
 
     rsx!(
         rect {
             reference: node_reference,
-            onglobalclick,
-            onglobalmousemove,
+            onglobaldragend,
+            onglobaldragover,
             onmousedown,
+            onglobalmousemove,
+            onglobalclick,
             if *dragging.read() {
                 rect {
                     position: "absolute",
@@ -123,6 +180,10 @@ pub struct DropZoneProps<T: 'static + PartialEq + Clone> {
     children: Element,
     /// Handler for the `ondrop` event.
     ondrop: EventHandler<T>,
+    /// Handler for the `ondragover` event, fired when something is dragged over the [DropZone].
+    ondragover: Option<EventHandler<DragEvent>>,
+    /// Handler for the `ondragleave` event, fired when something dragged leaves the [DropZone].
+    ondragleave: Option<EventHandler<DragEvent>>,
     /// Width of the [DropZone].
     #[props(default = "auto".to_string())]
     width: String,
@@ -131,24 +192,40 @@ pub struct DropZoneProps<T: 'static + PartialEq + Clone> {
     height: String,
 }
 
-/// Elements from [`DragZone`]s can be dropped here.
+/// Elements from [`DragZone`]s can be dropped here, even if they come from other windows.
 #[allow(non_snake_case)]
-pub fn DropZone<T: 'static + Clone + PartialEq>(props: DropZoneProps<T>) -> Element {
-    let mut drags = use_context::<Signal<Option<T>>>();
+pub fn DropZone<T: 'static + Clone + PartialEq + Send + Sync>(props: DropZoneProps<T>) -> Element {
+    let mut drags = try_use_context::<Signal<Option<T>>>();
 
-    let onmouseup = move |e: MouseEvent| {
+    let ondrop = move |e: DragEvent| {
         e.stop_propagation();
-        if let Some(current_drags) = &*drags.read() {
-            props.ondrop.call(current_drags.clone());
+        if let Some(data) = e.payload.value::<T>() {
+            props.ondrop.call(data.clone());
         }
-        if drags.read().is_some() {
-            *drags.write() = None;
+        if let Some(drags) = drags.as_mut() {
+            if drags.read().is_some() {
+                *drags.write() = None;
+            }
+        }
+    };
+
+    let ondragover = move |e: DragEvent| {
+        if let Some(ondragover) = &props.ondragover {
+            ondragover.call(e);
+        }
+    };
+
+    let ondragleave = move |e: DragEvent| {
+        if let Some(ondragleave) = &props.ondragleave {
+            ondragleave.call(e);
         }
     };
 
     rsx!(
         rect {
-            onmouseup,
+            ondrop,
+            ondragover,
+            ondragleave,
             width: props.width,
             height: props.height,
             {props.children}
@@ -311,4 +388,143 @@ mod test {
             Some("Enabled: true")
         );
     }
+
+    #[tokio::test]
+    pub async fn drag_drop_events() {
+        fn drop_app() -> Element {
+            let mut hovering = use_signal(|| false);
+            let mut dropped = use_signal(String::new);
+
+            rsx!(
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    DragZone {
+                        data: 1,
+                        text: "One".to_string(),
+                        drag_element: rsx!(
+                            label {
+                                "Moving"
+                            }
+                        ),
+                        label {
+                            "Move"
+                        }
+                    }
+                }
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    ondragover: move |_| hovering.set(true),
+                    ondragleave: move |_| hovering.set(false),
+                    ondrop: move |e: DragEvent| {
+                        dropped.set(e.payload.text().unwrap_or_default().to_string());
+                    },
+                    label {
+                        "Hovering: {hovering}, Dropped: {dropped}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drop_app);
+        let root = utils.root();
+        let label = root.get(1).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Start dragging
+        utils.move_cursor((5., 20.)).await;
+
+        // Drag over the drop target
+        utils.move_cursor((5., 300.)).await;
+        assert_eq!(label.get(0).text(), Some("Hovering: true, Dropped: "));
+
+        // Leave the drop target
+        utils.move_cursor((5., 5.)).await;
+        assert_eq!(label.get(0).text(), Some("Hovering: false, Dropped: "));
+
+        // Drop it
+        utils.move_cursor((5., 300.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: (5.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("Hovering: true, Dropped: One"));
+    }
+
+    #[tokio::test]
+    pub async fn click_inside_drag_zone() {
+        fn drag_app() -> Element {
+            let mut clicks = use_signal(|| 0);
+
+            rsx!(
+                DragZone {
+                    data: (),
+                    drag_element: rsx!(
+                        label {
+                            "Moving"
+                        }
+                    ),
+                    Button {
+                        onpress: move |_| clicks += 1,
+                        label {
+                            "Clicks {clicks}"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drag_app);
+        utils.wait_for_update().await;
+
+        utils.click_cursor((15., 15.)).await;
+        assert!(utils.get_by_name("Clicks 1").is_some());
+        assert!(utils.get_by_name("Moving").is_none());
+
+        // Moving less than the drag threshold still clicks
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (15.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((17., 16.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: (17.0, 16.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert!(utils.get_by_name("Clicks 2").is_some());
+        assert!(utils.get_by_name("Moving").is_none());
+
+        // Dragging doesn't click
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (15.0, 15.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((15., 100.)).await;
+        assert!(utils.get_by_name("Moving").is_some());
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: (15.0, 100.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert!(utils.get_by_name("Clicks 2").is_some());
+        assert!(utils.get_by_name("Moving").is_none());
+    }
 }
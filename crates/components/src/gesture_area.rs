@@ -1,6 +1,9 @@
 use std::{
     collections::VecDeque,
-    time::Instant,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use dioxus::prelude::*;
@@ -8,10 +11,15 @@ use freya_elements::{
     self as dioxus_elements,
     events::{
         touch::TouchPhase,
+        GestureEvent,
         TouchEvent,
     },
 };
 use futures_util::StreamExt;
+use tokio::time::sleep_until;
+use torin::prelude::CursorPoint;
+
+use crate::VelocityTracker;
 
 /// Distance between the first tap and the second tap in [`Gesture::DoubleTap`] gesture.
 const DOUBLE_TAP_DISTANCE: f64 = 100.0;
@@ -22,15 +30,98 @@ const DOUBLE_TAP_TIMEOUT: u128 = 300; // 300ms
 /// Minimum time between the end of the first time to the start of the second tap in a [`Gesture::DoubleTap`] gesture.
 const DOUBLE_TAP_MIN: u128 = 40; // 40ms
 
+/// Distance a finger can move before it starts a [`Gesture::Pan`], and no longer counts as a [`Gesture::LongPress`].
+const PAN_THRESHOLD: f64 = 10.0;
+
+/// How long a finger has to be held down without moving to trigger a [`Gesture::LongPress`].
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// Minimum velocity of a [`Gesture::Pan`] when released to trigger a [`Gesture::Swipe`], in pixels per millisecond.
+const SWIPE_VELOCITY: f32 = 0.5;
+
 /// In-memory events queue maximum size.
 const MAX_EVENTS_QUEUE: usize = 20;
 
 /// Gesture emitted by the [`GestureArea`] component.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Gesture {
     TapUp,
     TapDown,
     DoubleTap,
+    /// A finger was held down without moving.
+    LongPress,
+    /// A finger is moving across the area.
+    Pan(PanGesture),
+    /// A finger quickly moved in a direction and was released.
+    Swipe(SwipeDirection),
+    /// Two fingers, on the screen or a touchpad, are moving closer or apart.
+    Pinch(PinchGesture),
+    /// Two fingers, on the screen or a touchpad, are rotating.
+    Rotate(RotateGesture),
+}
+
+/// Direction of a [`Gesture::Swipe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl SwipeDirection {
+    /// Get the direction of a gesture released with the given velocity, if it was fast enough.
+    fn from_velocity(velocity_x: f32, velocity_y: f32) -> Option<Self> {
+        if velocity_x.abs().max(velocity_y.abs()) < SWIPE_VELOCITY {
+            None
+        } else if velocity_x.abs() > velocity_y.abs() {
+            if velocity_x > 0. {
+                Some(Self::Right)
+            } else {
+                Some(Self::Left)
+            }
+        } else if velocity_y > 0. {
+            Some(Self::Down)
+        } else {
+            Some(Self::Up)
+        }
+    }
+}
+
+/// Data of a [`Gesture::Pan`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PanGesture {
+    pub phase: TouchPhase,
+    /// Movement since the previous [`Gesture::Pan`].
+    pub delta: CursorPoint,
+    /// Movement since the pan started.
+    pub translation: CursorPoint,
+    /// Velocity of the finger, in pixels per millisecond.
+    pub velocity: CursorPoint,
+}
+
+/// Data of a [`Gesture::Pinch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PinchGesture {
+    pub phase: TouchPhase,
+    /// Scale since the pinch started, e.g `2.0` once the fingers are twice as far apart.
+    pub scale: f64,
+    /// Scale since the previous [`Gesture::Pinch`].
+    pub delta: f64,
+    /// Point between the fingers, or the cursor when using a touchpad, relative to the window.
+    pub center: CursorPoint,
+}
+
+/// Data of a [`Gesture::Rotate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotateGesture {
+    pub phase: TouchPhase,
+    /// Degrees rotated since the rotation started, positive when clockwise like the `rotate` attribute.
+    pub rotation: f64,
+    /// Degrees rotated since the previous [`Gesture::Rotate`].
+    pub delta: f64,
+    /// Point between the fingers, or the cursor when using a touchpad, relative to the window.
+    pub center: CursorPoint,
 }
 
 /// Properties for the [`GestureArea`] component.
@@ -44,7 +135,292 @@ pub struct GestureAreaProps {
 
 type EventsQueue = VecDeque<(Instant, TouchEvent)>;
 
-/// Detect complex touch gestures such as [`Gesture::DoubleTap`].
+/// Events handled by the [`GestureArea`].
+enum GestureInput {
+    Touch(TouchEvent),
+    TouchpadPinch(GestureEvent),
+    TouchpadRotate(GestureEvent),
+}
+
+/// A finger moving across the area.
+struct Pan {
+    origin: CursorPoint,
+    last: CursorPoint,
+    /// Whether the finger moved far enough to be a [`Gesture::Pan`].
+    is_panning: bool,
+}
+
+/// Distance and angle between two fingers.
+struct TwoFingers {
+    initial_distance: f64,
+    initial_angle: f64,
+    distance: f64,
+    angle: f64,
+}
+
+/// Keep an angle in degrees between -180 and 180.
+fn normalize_degrees(degrees: f64) -> f64 {
+    (degrees + 180.).rem_euclid(360.) - 180.
+}
+
+/// Recognizes the gestures made with one or multiple fingers, and with touchpads.
+#[derive(Default)]
+struct GestureRecognizer {
+    /// Fingers touching the area, in the order they started touching it.
+    fingers: Vec<(u64, CursorPoint)>,
+    pan: Option<Pan>,
+    two_fingers: Option<TwoFingers>,
+    velocity_tracker: VelocityTracker,
+    long_press_deadline: Option<tokio::time::Instant>,
+    touchpad_scale: f64,
+    touchpad_rotation: f64,
+}
+
+impl GestureRecognizer {
+    fn process_touch(&mut self, event: &TouchEvent, ongesture: &EventHandler<Gesture>) {
+        let finger_id = event.get_finger_id();
+        let location = event.get_screen_coordinates();
+        let phase = event.get_touch_phase();
+
+        if phase == TouchPhase::Started {
+            self.fingers.push((finger_id, location));
+
+            match self.fingers.len() {
+                1 => {
+                    self.pan = Some(Pan {
+                        origin: location,
+                        last: location,
+                        is_panning: false,
+                    });
+                    self.velocity_tracker.reset();
+                    self.long_press_deadline =
+                        Some(tokio::time::Instant::now() + LONG_PRESS_DURATION);
+                }
+                2 => {
+                    self.long_press_deadline = None;
+                    self.end_pan(TouchPhase::Cancelled, ongesture);
+
+                    let (distance, angle) = self.measure_fingers();
+                    self.two_fingers = Some(TwoFingers {
+                        initial_distance: distance,
+                        initial_angle: angle,
+                        distance,
+                        angle,
+                    });
+
+                    let center = self.fingers_center();
+                    ongesture.call(Gesture::Pinch(PinchGesture {
+                        phase,
+                        scale: 1.,
+                        delta: 1.,
+                        center,
+                    }));
+                    ongesture.call(Gesture::Rotate(RotateGesture {
+                        phase,
+                        rotation: 0.,
+                        delta: 0.,
+                        center,
+                    }));
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        let Some(index) = self.fingers.iter().position(|(id, _)| *id == finger_id) else {
+            return;
+        };
+
+        if phase == TouchPhase::Moved {
+            self.fingers[index].1 = location;
+
+            if self.two_fingers.is_some() {
+                // Only the first two fingers are part of the gestures
+                if index < 2 {
+                    self.move_two_fingers(ongesture);
+                }
+            } else if let Some(pan) = &mut self.pan {
+                let delta = (location - pan.last).to_point();
+                self.velocity_tracker.push(delta.x as f32, delta.y as f32);
+                let (velocity_x, velocity_y) = self.velocity_tracker.velocity();
+
+                let pan_phase = if pan.is_panning {
+                    Some(TouchPhase::Moved)
+                } else if location.distance_to(pan.origin) > PAN_THRESHOLD {
+                    pan.is_panning = true;
+                    self.long_press_deadline = None;
+                    Some(TouchPhase::Started)
+                } else {
+                    None
+                };
+
+                if let Some(pan_phase) = pan_phase {
+                    ongesture.call(Gesture::Pan(PanGesture {
+                        phase: pan_phase,
+                        delta: if pan_phase == TouchPhase::Started {
+                            (location - pan.origin).to_point()
+                        } else {
+                            delta
+                        },
+                        translation: (location - pan.origin).to_point(),
+                        velocity: (velocity_x as f64, velocity_y as f64).into(),
+                    }));
+                }
+
+                pan.last = location;
+            }
+        } else {
+            self.long_press_deadline = None;
+
+            if self.two_fingers.is_some() && index < 2 {
+                self.end_two_fingers(phase, ongesture);
+            }
+
+            self.fingers.remove(index);
+
+            if self.fingers.is_empty() {
+                self.end_pan(phase, ongesture);
+            }
+        }
+    }
+
+    fn process_touchpad_pinch(&mut self, event: &GestureEvent, ongesture: &EventHandler<Gesture>) {
+        let phase = event.get_phase();
+        if phase == TouchPhase::Started {
+            self.touchpad_scale = 1.;
+        }
+
+        let delta = 1. + event.get_delta();
+        self.touchpad_scale *= delta;
+
+        ongesture.call(Gesture::Pinch(PinchGesture {
+            phase,
+            scale: self.touchpad_scale,
+            delta,
+            center: event.get_screen_coordinates(),
+        }));
+    }
+
+    fn process_touchpad_rotation(
+        &mut self,
+        event: &GestureEvent,
+        ongesture: &EventHandler<Gesture>,
+    ) {
+        let phase = event.get_phase();
+        if phase == TouchPhase::Started {
+            self.touchpad_rotation = 0.;
+        }
+
+        // Touchpads rotate counterclockwise with positive deltas
+        let delta = -event.get_delta();
+        self.touchpad_rotation += delta;
+
+        ongesture.call(Gesture::Rotate(RotateGesture {
+            phase,
+            rotation: self.touchpad_rotation,
+            delta,
+            center: event.get_screen_coordinates(),
+        }));
+    }
+
+    /// Distance and angle in degrees between the first two fingers.
+    fn measure_fingers(&self) -> (f64, f64) {
+        let vector = self.fingers[1].1 - self.fingers[0].1;
+        (vector.length(), vector.y.atan2(vector.x).to_degrees())
+    }
+
+    /// Point between the first two fingers.
+    fn fingers_center(&self) -> CursorPoint {
+        self.fingers[0].1.lerp(self.fingers[1].1, 0.5)
+    }
+
+    fn move_two_fingers(&mut self, ongesture: &EventHandler<Gesture>) {
+        let (distance, angle) = self.measure_fingers();
+        let center = self.fingers_center();
+        let Some(two_fingers) = &mut self.two_fingers else {
+            return;
+        };
+
+        if distance != two_fingers.distance
+            && two_fingers.initial_distance > 0.
+            && two_fingers.distance > 0.
+        {
+            ongesture.call(Gesture::Pinch(PinchGesture {
+                phase: TouchPhase::Moved,
+                scale: distance / two_fingers.initial_distance,
+                delta: distance / two_fingers.distance,
+                center,
+            }));
+        }
+
+        if angle != two_fingers.angle {
+            ongesture.call(Gesture::Rotate(RotateGesture {
+                phase: TouchPhase::Moved,
+                rotation: normalize_degrees(angle - two_fingers.initial_angle),
+                delta: normalize_degrees(angle - two_fingers.angle),
+                center,
+            }));
+        }
+
+        two_fingers.distance = distance;
+        two_fingers.angle = angle;
+    }
+
+    fn end_two_fingers(&mut self, phase: TouchPhase, ongesture: &EventHandler<Gesture>) {
+        let center = self.fingers_center();
+        let Some(two_fingers) = self.two_fingers.take() else {
+            return;
+        };
+
+        let scale = if two_fingers.initial_distance > 0. {
+            two_fingers.distance / two_fingers.initial_distance
+        } else {
+            1.
+        };
+
+        ongesture.call(Gesture::Pinch(PinchGesture {
+            phase,
+            scale,
+            delta: 1.,
+            center,
+        }));
+        ongesture.call(Gesture::Rotate(RotateGesture {
+            phase,
+            rotation: normalize_degrees(two_fingers.angle - two_fingers.initial_angle),
+            delta: 0.,
+            center,
+        }));
+    }
+
+    fn end_pan(&mut self, phase: TouchPhase, ongesture: &EventHandler<Gesture>) {
+        let Some(pan) = self.pan.take() else {
+            return;
+        };
+
+        if !pan.is_panning {
+            return;
+        }
+
+        let (velocity_x, velocity_y) = self.velocity_tracker.velocity();
+
+        ongesture.call(Gesture::Pan(PanGesture {
+            phase,
+            delta: CursorPoint::zero(),
+            translation: (pan.last - pan.origin).to_point(),
+            velocity: (velocity_x as f64, velocity_y as f64).into(),
+        }));
+
+        if phase == TouchPhase::Ended {
+            if let Some(direction) = SwipeDirection::from_velocity(velocity_x, velocity_y) {
+                ongesture.call(Gesture::Swipe(direction));
+            }
+        }
+    }
+}
+
+/// Detect complex touch gestures such as [`Gesture::DoubleTap`], [`Gesture::Pan`] or [`Gesture::Pinch`].
+///
+/// Pinching and rotating with two fingers on a touchpad are also recognized as [`Gesture::Pinch`] and [`Gesture::Rotate`].
 ///
 /// # Example
 ///
@@ -65,11 +441,43 @@ type EventsQueue = VecDeque<(Instant, TouchEvent)>;
 #[allow(non_snake_case)]
 pub fn GestureArea(props: GestureAreaProps) -> Element {
     let event_emitter = use_coroutine(
-        move |mut rx: UnboundedReceiver<(Instant, TouchEvent)>| async move {
+        move |mut rx: UnboundedReceiver<(Instant, GestureInput)>| async move {
             let mut touch_events = VecDeque::<(Instant, TouchEvent)>::new();
+            let mut recognizer = GestureRecognizer::default();
+
+            loop {
+                let new_input = match recognizer.long_press_deadline {
+                    Some(deadline) => {
+                        tokio::select! {
+                            new_input = rx.next() => new_input,
+                            _ = sleep_until(deadline) => {
+                                // LongPress
+                                recognizer.long_press_deadline = None;
+                                props.ongesture.call(Gesture::LongPress);
+                                continue;
+                            }
+                        }
+                    }
+                    None => rx.next().await,
+                };
 
-            while let Some(new_event) = rx.next().await {
-                touch_events.push_back(new_event);
+                let Some((time, input)) = new_input else {
+                    break;
+                };
+
+                let touch_event = match input {
+                    GestureInput::Touch(event) => event,
+                    GestureInput::TouchpadPinch(event) => {
+                        recognizer.process_touchpad_pinch(&event, &props.ongesture);
+                        continue;
+                    }
+                    GestureInput::TouchpadRotate(event) => {
+                        recognizer.process_touchpad_rotation(&event, &props.ongesture);
+                        continue;
+                    }
+                };
+
+                touch_events.push_back((time, touch_event.clone()));
 
                 // Keep the touch events queue under a certain size
                 if touch_events.len() > MAX_EVENTS_QUEUE {
@@ -139,24 +547,35 @@ pub fn GestureArea(props: GestureAreaProps) -> Element {
                         _ => {}
                     }
                 }
+
+                // Pan, Swipe, Pinch and Rotate
+                recognizer.process_touch(&touch_event, &props.ongesture);
             }
         },
     );
 
     let ontouchcancel = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((Instant::now(), GestureInput::Touch(e)));
     };
 
     let ontouchend = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((Instant::now(), GestureInput::Touch(e)));
     };
 
     let ontouchmove = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((Instant::now(), GestureInput::Touch(e)));
     };
 
     let ontouchstart = move |e: TouchEvent| {
-        event_emitter.send((Instant::now(), e));
+        event_emitter.send((Instant::now(), GestureInput::Touch(e)));
+    };
+
+    let onpinch = move |e: GestureEvent| {
+        event_emitter.send((Instant::now(), GestureInput::TouchpadPinch(e)));
+    };
+
+    let onrotate = move |e: GestureEvent| {
+        event_emitter.send((Instant::now(), GestureInput::TouchpadRotate(e)));
     };
 
     rsx!(
@@ -165,6 +584,8 @@ pub fn GestureArea(props: GestureAreaProps) -> Element {
             ontouchend: ontouchend,
             ontouchmove: ontouchmove,
             ontouchstart: ontouchstart,
+            onpinch,
+            onrotate,
             {props.children}
         }
     )
@@ -178,7 +599,10 @@ mod test {
     use freya_testing::prelude::*;
     use tokio::time::sleep;
 
-    use crate::gesture_area::DOUBLE_TAP_MIN;
+    use crate::gesture_area::{
+        DOUBLE_TAP_MIN,
+        LONG_PRESS_DURATION,
+    };
 
     /// This test simulates a `DoubleTap` gesture in this order:
     /// 1. Touch start
@@ -309,4 +733,161 @@ mod test {
 
         assert_eq!(utils.root().get(1).get(0).text(), Some("TapUp"));
     }
+
+    fn gestures_log_app() -> Element {
+        let mut log = use_signal(Vec::<String>::new);
+
+        let ongesture = move |e: Gesture| {
+            let entry = match e {
+                Gesture::Pan(pan) => format!("Pan {:?}", pan.phase),
+                Gesture::Pinch(pinch) => format!("Pinch {:?} {:.1}", pinch.phase, pinch.scale),
+                Gesture::Rotate(rotate) => {
+                    format!("Rotate {:?} {:.0}", rotate.phase, rotate.rotation)
+                }
+                gesture => format!("{gesture:?}"),
+            };
+            log.write().push(entry);
+        };
+        let text = log.read().join(", ");
+
+        rsx!(
+            GestureArea {
+                ongesture,
+                rect {
+                    width: "100%",
+                    height: "100%",
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    fn touch(
+        utils: &mut TestingHandler<()>,
+        location: (f64, f64),
+        finger_id: u64,
+        phase: TouchPhase,
+    ) {
+        let name = match phase {
+            TouchPhase::Started => TouchEventName::TouchStart,
+            TouchPhase::Moved => TouchEventName::TouchMove,
+            TouchPhase::Ended => TouchEventName::TouchEnd,
+            TouchPhase::Cancelled => TouchEventName::TouchCancel,
+        };
+        utils.push_event(TestEvent::Touch {
+            name,
+            location: location.into(),
+            phase,
+            finger_id,
+            force: None,
+        });
+    }
+
+    /// Simulates a fast `Pan` to the right that ends in a `Swipe`.
+    #[tokio::test]
+    pub async fn pan_and_swipe() {
+        let mut utils = launch_test_with_config(
+            gestures_log_app,
+            TestingConfig::<()> {
+                virtual_clock: true,
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+
+        for (x, phase) in [
+            (10., TouchPhase::Started),
+            (60., TouchPhase::Moved),
+            (110., TouchPhase::Moved),
+            (110., TouchPhase::Ended),
+        ] {
+            touch(&mut utils, (x, 50.), 0, phase);
+            utils.advance(Duration::from_millis(16)).await;
+        }
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("TapDown, Pan Started, Pan Moved, TapUp, Pan Ended, Swipe(Right)")
+        );
+    }
+
+    /// Simulates two fingers moving apart and then rotating.
+    #[tokio::test]
+    pub async fn pinch_and_rotate() {
+        let mut utils = launch_test(gestures_log_app);
+        utils.wait_for_update().await;
+
+        for (location, finger_id, phase) in [
+            ((100., 100.), 0, TouchPhase::Started),
+            ((200., 100.), 1, TouchPhase::Started),
+            ((300., 100.), 1, TouchPhase::Moved),
+            ((100., 300.), 1, TouchPhase::Moved),
+            ((100., 300.), 1, TouchPhase::Ended),
+        ] {
+            touch(&mut utils, location, finger_id, phase);
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("TapDown, TapDown, Pinch Started 1.0, Rotate Started 0, Pinch Moved 2.0, Rotate Moved 90, TapUp, Pinch Ended 2.0, Rotate Ended 90")
+        );
+    }
+
+    /// Simulates a finger held down without moving.
+    #[tokio::test]
+    pub async fn long_press() {
+        let mut utils = launch_test_with_config(
+            gestures_log_app,
+            TestingConfig::<()> {
+                virtual_clock: true,
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+
+        touch(&mut utils, (50., 50.), 0, TouchPhase::Started);
+        utils.advance(LONG_PRESS_DURATION / 2).await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("TapDown"));
+
+        utils.advance(LONG_PRESS_DURATION).await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("TapDown, LongPress")
+        );
+    }
+
+    /// Simulates pinching on a touchpad.
+    #[tokio::test]
+    pub async fn touchpad_pinch() {
+        let mut utils = launch_test(gestures_log_app);
+        utils.wait_for_update().await;
+
+        for (delta, phase) in [
+            (0., TouchPhase::Started),
+            (0.5, TouchPhase::Moved),
+            (0., TouchPhase::Ended),
+        ] {
+            utils.push_event(TestEvent::Gesture {
+                name: GestureEventName::Pinch,
+                cursor: (50., 50.).into(),
+                delta,
+                phase,
+            });
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(1).get(0).text(),
+            Some("Pinch Started 1.0, Pinch Moved 1.5, Pinch Ended 1.5")
+        );
+    }
 }
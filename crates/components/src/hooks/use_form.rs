@@ -2,39 +2,487 @@
 
 use std::{
     collections::HashMap,
-    fmt::Display,
+    fmt::{
+        self,
+        Display,
+    },
+    future::Future,
     hash::Hash,
+    pin::Pin,
+    rc::Rc,
 };
 
 use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
 
 use crate::{
     ButtonProps,
+    DropdownItemProps,
     InputMode,
     InputProps,
+    SliderProps,
+    SwitchProps,
 };
 
-type SubmitCallback<Id> = Box<dyn Fn(&HashMap<Id, String>)>;
+/// Values of all the fields of a form.
+pub type FormData<Id> = HashMap<Id, FieldValue>;
+
+type SubmitCallback<Id> = Box<dyn Fn(&FormData<Id>)>;
+type Validator = Rc<dyn Fn(&FieldValue) -> Result<(), String>>;
+type AsyncValidator = Rc<dyn Fn(FieldValue) -> Pin<Box<dyn Future<Output = Result<(), String>>>>>;
+
+/// Value of a form field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    /// Text, for [crate::Input].
+    Text(String),
+    /// Number, for [crate::Slider].
+    Number(f64),
+    /// Boolean, for [crate::Checkbox] and [crate::Switch].
+    Bool(bool),
+    /// Selected option, for [crate::Radio] and [crate::DropdownItem].
+    Selection(Option<String>),
+}
+
+impl Default for FieldValue {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl FieldValue {
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Get the number of this value, parsing it if this is a [FieldValue::Text].
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            Self::Text(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_selection(&self) -> Option<&str> {
+        match self {
+            Self::Selection(selection) => selection.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Empty text, `false` and no selection are considered empty.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.trim().is_empty(),
+            Self::Number(_) => false,
+            Self::Bool(value) => !value,
+            Self::Selection(selection) => selection.is_none(),
+        }
+    }
+}
+
+impl Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::Number(number) => number.fmt(f),
+            Self::Bool(value) => value.fmt(f),
+            Self::Selection(selection) => f.write_str(selection.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// Initial value and validators of a form field.
+#[derive(Clone)]
+pub struct FormField {
+    initial: FieldValue,
+    validators: Vec<Validator>,
+    async_validators: Vec<AsyncValidator>,
+}
+
+impl FormField {
+    pub fn new(initial: impl Into<FieldValue>) -> Self {
+        Self {
+            initial: initial.into(),
+            validators: Vec::new(),
+            async_validators: Vec::new(),
+        }
+    }
+
+    pub fn text(initial: impl Into<String>) -> Self {
+        Self::new(FieldValue::Text(initial.into()))
+    }
+
+    pub fn number(initial: f64) -> Self {
+        Self::new(FieldValue::Number(initial))
+    }
+
+    pub fn bool(initial: bool) -> Self {
+        Self::new(FieldValue::Bool(initial))
+    }
+
+    pub fn selection(initial: Option<String>) -> Self {
+        Self::new(FieldValue::Selection(initial))
+    }
+
+    /// Add a validator that returns an error message when the value is not valid.
+    pub fn with_validator(
+        mut self,
+        validator: impl Fn(&FieldValue) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validators.push(Rc::new(validator));
+        self
+    }
+
+    /// Add an async validator, it only runs once all the sync validators pass.
+    pub fn with_async_validator<F: Future<Output = Result<(), String>> + 'static>(
+        mut self,
+        validator: impl Fn(FieldValue) -> F + 'static,
+    ) -> Self {
+        self.async_validators
+            .push(Rc::new(move |value| Box::pin(validator(value))));
+        self
+    }
+
+    /// Fail with the given message when the value [is empty](FieldValue::is_empty).
+    pub fn required(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.with_validator(move |value| {
+            if value.is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    fn validate(&self, value: &FieldValue) -> Result<(), String> {
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator(value))
+    }
+}
+
+async fn validate_async(validators: Vec<AsyncValidator>, value: FieldValue) -> Result<(), String> {
+    for validator in validators {
+        validator(value.clone()).await?;
+    }
+    Ok(())
+}
+
+/// Fields of a form, see [use_form].
+pub struct FormConfig<Id> {
+    fields: Vec<(Id, FormField)>,
+}
+
+impl<Id> Default for FormConfig<Id> {
+    fn default() -> Self {
+        Self { fields: Vec::new() }
+    }
+}
+
+impl<Id> FormConfig<Id> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_field(mut self, id: Id, field: FormField) -> Self {
+        self.fields.push((id, field));
+        self
+    }
+}
+
+/// State of a form field.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct FieldState {
+    pub value: FieldValue,
+    pub initial: FieldValue,
+    /// Whether the user already interacted with the field.
+    pub touched: bool,
+    /// Latest validation error, even if it is not displayed yet.
+    pub error: Option<String>,
+    /// Whether the async validators are running.
+    pub validating: bool,
+    focused: bool,
+    validation: usize,
+}
+
+impl FieldState {
+    fn new(initial: FieldValue, error: Option<String>) -> Self {
+        Self {
+            value: initial.clone(),
+            initial,
+            error,
+            ..Self::default()
+        }
+    }
+
+    /// Whether the value is different from the initial one.
+    pub fn is_dirty(&self) -> bool {
+        self.value != self.initial
+    }
+}
 
 /// Form controller
 ///
-/// Use [`Self::input()`] to register inputs
-/// And [`Self::submit()`] to register a submitter button
-#[derive(Clone)]
-pub struct UseForm<Id: Hash + Eq + 'static> {
-    data: Signal<HashMap<Id, String>>,
-    onsubmit: Signal<SubmitCallback<Id>>,
+/// Use [`Self::input()`] and the other methods to register components,
+/// and [`Self::submit()`] to register a submitter button.
+///
+/// Errors are only shown once a field has been touched or the form was submitted,
+/// and the submission is blocked while any field is invalid.
+pub struct UseForm<Id: 'static> {
+    fields: Signal<HashMap<Id, FieldState>>,
+    config: CopyValue<HashMap<Id, FormField>>,
+    submitted: Signal<bool>,
+    onsubmit: CopyValue<SubmitCallback<Id>>,
+}
+
+impl<Id: 'static> Clone for UseForm<Id> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<Id: Clone + Hash + Eq + Display> UseForm<Id> {
-    /// Register an [crate::Input] component.
+impl<Id: 'static> Copy for UseForm<Id> {}
+
+impl<Id: Clone + Hash + Eq + Display + 'static> UseForm<Id> {
+    /// Get the state of a field.
+    pub fn field(&self, id: &Id) -> FieldState {
+        self.fields.read().get(id).cloned().unwrap_or_default()
+    }
+
+    /// Get the value of a field.
+    pub fn value(&self, id: &Id) -> FieldValue {
+        self.field(id).value
+    }
+
+    /// Get the values of all the fields.
+    pub fn data(&self) -> FormData<Id> {
+        self.fields
+            .read()
+            .iter()
+            .map(|(id, field)| (id.clone(), field.value.clone()))
+            .collect()
+    }
+
+    /// Get the error of a field, only once it has been touched or the form was submitted.
+    pub fn error(&self, id: &Id) -> Option<String> {
+        let field = self.field(id);
+        if field.touched || *self.submitted.read() {
+            field.error
+        } else {
+            None
+        }
+    }
+
+    /// Whether all the fields are valid and there are no validations running.
+    pub fn is_valid(&self) -> bool {
+        self.fields
+            .read()
+            .values()
+            .all(|field| field.error.is_none() && !field.validating)
+    }
+
+    /// Whether any field has a value different from its initial one.
+    pub fn is_dirty(&self) -> bool {
+        self.fields.read().values().any(FieldState::is_dirty)
+    }
+
+    /// Change the value of a field and validate it.
+    pub fn set_value(&self, id: Id, value: impl Into<FieldValue>) {
+        let value = value.into();
+        let mut fields = self.fields;
+        let mut fields = fields.write();
+        let field = fields.entry(id.clone()).or_default();
+        field.value = value.clone();
+        field.validation += 1;
+        field.validating = false;
+
+        let config = self.config.peek();
+        let Some(field_config) = config.get(&id) else {
+            return;
+        };
+
+        field.error = field_config.validate(&value).err();
+
+        if field.error.is_none() && !field_config.async_validators.is_empty() {
+            field.validating = true;
+            let validation = field.validation;
+            let validators = field_config.async_validators.clone();
+            let mut fields = self.fields;
+            spawn(async move {
+                let res = validate_async(validators, value).await;
+                let mut fields = fields.write();
+                if let Some(field) = fields.get_mut(&id) {
+                    // Discard the result if the value changed in the meantime
+                    if field.validation == validation {
+                        field.error = res.err();
+                        field.validating = false;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Mark a field as touched, so its error is shown.
+    pub fn touch(&self, id: Id) {
+        let mut fields = self.fields;
+        fields.write().entry(id).or_default().touched = true;
+    }
+
+    /// Toggle a [FieldValue::Bool] field.
+    pub fn toggle(&self, id: Id) {
+        let value = self
+            .fields
+            .peek()
+            .get(&id)
+            .and_then(|field| field.value.as_bool());
+        self.touch(id.clone());
+        self.set_value(id, !value.unwrap_or_default());
+    }
+
+    /// Select an option of a [FieldValue::Selection] field.
+    pub fn select(&self, id: Id, option: impl Into<String>) {
+        self.touch(id.clone());
+        self.set_value(id, FieldValue::Selection(Some(option.into())));
+    }
+
+    /// Whether the given option is selected in a [FieldValue::Selection] field.
+    pub fn is_selected(&self, id: &Id, option: &str) -> bool {
+        self.value(id).as_selection() == Some(option)
+    }
+
+    /// Restore the initial values and forget about touched fields.
+    pub fn reset(&self) {
+        let config = self.config.peek();
+        let mut fields = self.fields;
+        for (id, field) in fields.write().iter_mut() {
+            *field = FieldState {
+                validation: field.validation + 1,
+                ..FieldState::new(
+                    field.initial.clone(),
+                    config
+                        .get(id)
+                        .and_then(|field_config| field_config.validate(&field.initial).err()),
+                )
+            };
+        }
+        let mut submitted = self.submitted;
+        submitted.set(false);
+    }
+
+    /// Validate all the fields and call the submit callback if they are all valid.
+    pub fn try_submit(&self) {
+        let mut submitted = self.submitted;
+        submitted.set(true);
+
+        let mut fields = self.fields;
+        let mut pending = Vec::new();
+        {
+            let config = self.config.peek();
+            let mut fields = fields.write();
+            for (id, field) in fields.iter_mut() {
+                field.touched = true;
+                if let Some(field_config) = config.get(id) {
+                    field.error = field_config.validate(&field.value).err();
+                    if field.error.is_none() && !field_config.async_validators.is_empty() {
+                        pending.push((id.clone(), field_config.async_validators.clone()));
+                    }
+                }
+            }
+
+            if fields.values().any(|field| field.error.is_some()) {
+                return;
+            }
+
+            for (id, _) in &pending {
+                let field = fields.get_mut(id).unwrap();
+                field.validation += 1;
+                field.validating = true;
+            }
+        }
+
+        let form = *self;
+
+        if pending.is_empty() {
+            (self.onsubmit.peek())(&form.data());
+            return;
+        }
+
+        spawn(async move {
+            let mut is_valid = true;
+
+            for (id, validators) in pending {
+                let (value, validation) = {
+                    let fields = fields.peek();
+                    let field = &fields[&id];
+                    (field.value.clone(), field.validation)
+                };
+                let res = validate_async(validators, value).await;
+                let mut fields = fields.write();
+                let field = fields.get_mut(&id).unwrap();
+                if field.validation == validation {
+                    is_valid &= res.is_ok();
+                    field.error = res.err();
+                    field.validating = false;
+                } else {
+                    // The value changed while validating
+                    is_valid = false;
+                }
+            }
+
+            if is_valid {
+                let data = form.data();
+                (form.onsubmit.peek())(&data);
+            }
+        });
+    }
+
+    /// Register an [crate::Input] component, its error is linked as accessibility description.
     pub fn input(&self, id: Id) -> InputProps {
-        let value = self.data.read().get(&id).cloned().unwrap_or_default();
+        let value = self.value(&id).to_string();
         let placeholder = id.to_string();
-        let mut data = self.data;
+        let a11y_description = self.error(&id);
+        let form = *self;
+        let focus_id = id.clone();
         InputProps {
-            onchange: EventHandler::new(move |txt| {
-                data.write().insert(id.clone(), txt);
+            onchange: EventHandler::new(move |txt: String| {
+                form.set_value(id.clone(), txt);
             }),
             theme: None,
             mode: InputMode::default(),
@@ -43,18 +491,85 @@ impl<Id: Clone + Hash + Eq + Display> UseForm<Id> {
             auto_focus: false,
             onvalidate: None,
             width: "150".to_string(),
-            onfocuschange: None,
+            onfocuschange: Some(EventHandler::new(move |focused: bool| {
+                let mut fields = form.fields;
+                let mut fields = fields.write();
+                let field = fields.entry(focus_id.clone()).or_default();
+                // Touched once the user leaves the field
+                if field.focused && !focused {
+                    field.touched = true;
+                }
+                field.focused = focused;
+            })),
+            a11y_description,
+        }
+    }
+
+    /// Register a [crate::Switch] component for a [FieldValue::Bool] field.
+    pub fn switch(&self, id: Id) -> SwitchProps {
+        let enabled = self.value(&id).as_bool().unwrap_or_default();
+        let form = *self;
+        SwitchProps {
+            theme: None,
+            enabled,
+            ontoggled: EventHandler::new(move |_| form.toggle(id.clone())),
+        }
+    }
+
+    /// Register a [crate::Slider] component for a [FieldValue::Number] field.
+    pub fn slider(&self, id: Id) -> SliderProps {
+        let value = self.value(&id).as_number().unwrap_or_default();
+        let form = *self;
+        SliderProps {
+            theme: None,
+            onmoved: EventHandler::new(move |value: f64| {
+                form.touch(id.clone());
+                form.set_value(id.clone(), value);
+            }),
+            size: "100%".to_string(),
+            value,
+            direction: "horizontal".to_string(),
         }
     }
 
+    /// Register a [crate::DropdownItem] component as an option of a [FieldValue::Selection] field.
+    pub fn dropdown_item(&self, id: Id, option: impl Into<String>) -> DropdownItemProps {
+        let option = option.into();
+        let selected = self.is_selected(&id, &option);
+        let form = *self;
+        DropdownItemProps {
+            theme: None,
+            children: Ok(VNode::placeholder()),
+            onpress: Some(EventHandler::new(move |_| {
+                form.select(id.clone(), option.clone())
+            })),
+            selected,
+        }
+    }
+
+    /// Render the error of a field, if any.
+    pub fn error_label(&self, id: &Id) -> Element {
+        let Some(error) = self.error(id) else {
+            return rsx!();
+        };
+
+        rsx!(
+            label {
+                a11y_role: "alert",
+                color: "rgb(220, 60, 60)",
+                font_size: "13",
+                "{error}"
+            }
+        )
+    }
+
     /// Register a [crate::Button] component.
     pub fn submit(&self) -> ButtonProps {
-        let submit = self.onsubmit;
-        let data = self.data;
+        let form = *self;
         ButtonProps {
             theme: None,
             onpress: Some(EventHandler::new(move |_| {
-                (submit.peek())(&data.read());
+                form.try_submit();
             })),
             children: Ok(VNode::placeholder()),
             onclick: None,
@@ -63,12 +578,364 @@ impl<Id: Clone + Hash + Eq + Display> UseForm<Id> {
     }
 }
 
-/// Create a Form controller with a submit callback.
-pub fn use_form<Id: Hash + Eq + Clone>(
-    onsubmit: impl Fn(&HashMap<Id, String>) + 'static,
+/// Create a Form controller with the given fields and a submit callback.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// #[derive(Hash, PartialEq, Eq, Clone, Debug)]
+/// enum Field {
+///     Name,
+///     Newsletter,
+/// }
+///
+/// # impl std::fmt::Display for Field {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         write!(f, "{self:?}")
+/// #     }
+/// # }
+/// fn app() -> Element {
+///     let form = use_form(
+///         || {
+///             FormConfig::new()
+///                 .with_field(Field::Name, FormField::text("").required("Name is required"))
+///                 .with_field(Field::Newsletter, FormField::bool(false))
+///         },
+///         |data| println!("Submitting: {data:?}"),
+///     );
+///
+///     rsx!(
+///         Input {
+///             ..form.input(Field::Name)
+///         }
+///         {form.error_label(&Field::Name)}
+///         Switch {
+///             ..form.switch(Field::Newsletter)
+///         }
+///         Button {
+///             children: rsx!(
+///                 label {
+///                     "Submit"
+///                 }
+///             ),
+///             ..form.submit()
+///         }
+///     )
+/// }
+/// ```
+pub fn use_form<Id: Hash + Eq + Clone + 'static>(
+    config: impl FnOnce() -> FormConfig<Id>,
+    onsubmit: impl Fn(&FormData<Id>) + 'static,
 ) -> UseForm<Id> {
-    use_hook(|| UseForm {
-        data: Signal::new(HashMap::default()),
-        onsubmit: Signal::new(Box::new(onsubmit)),
+    use_hook(|| {
+        let config = config();
+        let fields = config
+            .fields
+            .iter()
+            .map(|(id, field)| {
+                let error = field.validate(&field.initial).err();
+                (id.clone(), FieldState::new(field.initial.clone(), error))
+            })
+            .collect();
+
+        UseForm {
+            fields: Signal::new(fields),
+            config: CopyValue::new(config.fields.into_iter().collect()),
+            submitted: Signal::new(false),
+            onsubmit: CopyValue::new(Box::new(onsubmit)),
+        }
     })
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::sleep;
+
+    #[derive(Hash, PartialEq, Eq, Clone, Debug)]
+    enum Field {
+        Name,
+        Newsletter,
+        Username,
+        Terms,
+        Plan,
+        Volume,
+    }
+
+    impl std::fmt::Display for Field {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{self:?}")
+        }
+    }
+
+    #[tokio::test]
+    pub async fn form_validation() {
+        fn form_app() -> Element {
+            let submissions = use_signal(|| 0);
+            let form = use_form(
+                || {
+                    FormConfig::new()
+                        .with_field(
+                            Field::Name,
+                            FormField::text("").required("Name is required"),
+                        )
+                        .with_field(Field::Newsletter, FormField::bool(false))
+                },
+                move |data| {
+                    assert_eq!(data[&Field::Name].as_text(), Some("Marc"));
+                    assert_eq!(data[&Field::Newsletter].as_bool(), Some(true));
+                    let mut submissions = submissions;
+                    submissions += 1;
+                },
+            );
+            let is_dirty = form.is_dirty();
+
+            rsx!(
+                Input {
+                    ..form.input(Field::Name)
+                }
+                Switch {
+                    ..form.switch(Field::Newsletter)
+                }
+                Button {
+                    children: rsx!(
+                        label {
+                            "Submit"
+                        }
+                    ),
+                    ..form.submit()
+                }
+                label {
+                    "{submissions} {is_dirty}"
+                }
+                Button {
+                    onpress: move |_| form.reset(),
+                    label {
+                        "Reset"
+                    }
+                }
+                {form.error_label(&Field::Name)}
+            )
+        }
+
+        let mut utils = launch_test(form_app);
+        utils.wait_for_update().await;
+
+        let status = utils.root().get(3);
+        assert_eq!(status.get(0).text(), Some("0 false"));
+        // Errors are hidden until the field is touched or the form submitted
+        assert!(utils.get_by_role(Role::Alert).is_none());
+
+        // Submitting an invalid form shows the errors but doesn't submit it
+        utils.click_by_role(Role::Button, "Submit").await;
+        assert_eq!(status.get(0).text(), Some("0 false"));
+        let input = utils.get_by_role(Role::TextInput).unwrap();
+        assert_eq!(input.description().as_deref(), Some("Name is required"));
+        assert!(utils.get_by_name("Name is required").is_some());
+
+        utils.type_into(&input, "Marc").await;
+        let switch = utils.get_by_role(Role::Switch).unwrap();
+        utils.click(&switch).await;
+        assert_eq!(status.get(0).text(), Some("0 true"));
+        assert!(utils.get_by_role(Role::Alert).is_none());
+
+        utils.click_by_role(Role::Button, "Submit").await;
+        assert_eq!(status.get(0).text(), Some("1 true"));
+
+        utils.click_by_role(Role::Button, "Reset").await;
+        assert_eq!(status.get(0).text(), Some("1 false"));
+        assert!(utils.get_by_role(Role::Alert).is_none());
+    }
+
+    #[tokio::test]
+    pub async fn async_validation() {
+        fn form_app() -> Element {
+            let submissions = use_signal(|| 0);
+            let form = use_form(
+                || {
+                    FormConfig::new().with_field(
+                        Field::Username,
+                        FormField::text("").with_async_validator(|value| async move {
+                            // Taken usernames are rejected faster than free ones are accepted
+                            if value.as_text() == Some("taken") {
+                                sleep(Duration::from_millis(50)).await;
+                                Err("Username is taken".to_string())
+                            } else {
+                                sleep(Duration::from_millis(200)).await;
+                                Ok(())
+                            }
+                        }),
+                    )
+                },
+                move |_| {
+                    let mut submissions = submissions;
+                    submissions += 1;
+                },
+            );
+            let field = form.field(&Field::Username);
+            let error = field.error.as_deref().unwrap_or("none");
+
+            rsx!(
+                label {
+                    "{field.validating} {error} {submissions}"
+                }
+                Button {
+                    onpress: move |_| form.set_value(Field::Username, "taken"),
+                    label {
+                        "Taken"
+                    }
+                }
+                Button {
+                    onpress: move |_| form.set_value(Field::Username, "free"),
+                    label {
+                        "Free"
+                    }
+                }
+                Button {
+                    children: rsx!(
+                        label {
+                            "Submit"
+                        }
+                    ),
+                    ..form.submit()
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            form_app,
+            TestingConfig::<()> {
+                virtual_clock: true,
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+
+        let status = utils.root().get(0);
+        assert_eq!(status.get(0).text(), Some("false none 0"));
+
+        // The result of the previous value arrives later and is discarded
+        utils.click_by_role(Role::Button, "Free").await;
+        utils.click_by_role(Role::Button, "Taken").await;
+        assert_eq!(status.get(0).text(), Some("true none 0"));
+        utils.advance(Duration::from_millis(300)).await;
+        assert_eq!(status.get(0).text(), Some("false Username is taken 0"));
+
+        // Submitting waits for the pending validation
+        utils.click_by_role(Role::Button, "Free").await;
+        utils.click_by_role(Role::Button, "Submit").await;
+        assert_eq!(status.get(0).text(), Some("true none 0"));
+        utils.advance(Duration::from_millis(300)).await;
+        assert_eq!(status.get(0).text(), Some("false none 1"));
+
+        // Changing the value while validating cancels the submission
+        utils.click_by_role(Role::Button, "Submit").await;
+        utils.click_by_role(Role::Button, "Taken").await;
+        utils.advance(Duration::from_millis(300)).await;
+        assert_eq!(status.get(0).text(), Some("false Username is taken 1"));
+
+        // A submission with an invalid value doesn't submit
+        utils.click_by_role(Role::Button, "Submit").await;
+        utils.advance(Duration::from_millis(300)).await;
+        assert_eq!(status.get(0).text(), Some("false Username is taken 1"));
+    }
+
+    #[tokio::test]
+    pub async fn form_bindings() {
+        fn form_app() -> Element {
+            let form = use_form(
+                || {
+                    FormConfig::new()
+                        .with_field(Field::Volume, FormField::number(50.))
+                        .with_field(
+                            Field::Terms,
+                            FormField::bool(false).required("Accept the terms"),
+                        )
+                        .with_field(Field::Plan, FormField::selection(None))
+                },
+                |_| {},
+            );
+            let volume = form.value(&Field::Volume);
+            let terms = form.value(&Field::Terms);
+            let plan = form.value(&Field::Plan);
+
+            rsx!(
+                Slider {
+                    ..form.slider(Field::Volume)
+                }
+                Tile {
+                    onselect: move |_| form.toggle(Field::Terms),
+                    leading: rsx!(
+                        Checkbox {
+                            selected: terms.as_bool().unwrap_or_default(),
+                        }
+                    ),
+                    label { "Terms" }
+                }
+                for option in ["Free", "Pro"] {
+                    Tile {
+                        key: "{option}",
+                        onselect: move |_| form.select(Field::Plan, option),
+                        leading: rsx!(
+                            Radio {
+                                selected: form.is_selected(&Field::Plan, option),
+                            }
+                        ),
+                        label { "{option}" }
+                    }
+                }
+                label {
+                    "{volume} {terms} {plan} {form.is_valid()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(form_app);
+        utils.wait_for_update().await;
+
+        let status = utils.root().get(4);
+        assert_eq!(status.get(0).text(), Some("50 false  false"));
+
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseMove,
+            cursor: (250.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (250.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseMove,
+            cursor: (500.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: (500.0, 7.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(status.get(0).text(), Some("100 false  false"));
+
+        let terms = utils.get_by_name("Terms").unwrap();
+        utils.click(&terms).await;
+        assert_eq!(status.get(0).text(), Some("100 true  true"));
+
+        let pro = utils.get_by_name("Pro").unwrap();
+        utils.click(&pro).await;
+        assert_eq!(status.get(0).text(), Some("100 true Pro true"));
+        assert!(utils.root().get(3).get(0).get(0).get(0).is_element());
+        assert!(utils.root().get(2).get(0).get(0).get(0).is_placeholder());
+
+        let free = utils.get_by_name("Free").unwrap();
+        utils.click(&free).await;
+        assert_eq!(status.get(0).text(), Some("100 true Free true"));
+
+        utils.click(&terms).await;
+        assert_eq!(status.get(0).text(), Some("100 false Free false"));
+    }
+}
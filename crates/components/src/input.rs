@@ -14,6 +14,7 @@ use freya_elements::{
     self as dioxus_elements,
     events::{
         keyboard::Key,
+        CompositionData,
         KeyboardData,
         MouseEvent,
     },
@@ -112,6 +113,8 @@ pub struct InputProps {
     pub width: String,
     /// Handler for the `onfocuschange` function.
     pub onfocuschange: Option<EventHandler<bool>>,
+    /// Accessibility description, e.g. a validation error.
+    pub a11y_description: Option<String>,
 }
 
 /// Small box to edit text.
@@ -167,6 +170,7 @@ pub fn Input(
         onvalidate,
         width,
         onfocuschange,
+        a11y_description,
     }: InputProps,
 ) -> Element {
     let platform = use_platform();
@@ -259,6 +263,21 @@ pub fn Input(
         editable.process_event(&EditableEvent::KeyUp(e.data));
     };
 
+    let oncompositionstart = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionStart);
+    };
+
+    let oncompositionupdate = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionUpdate(e.data));
+    };
+
+    let oncompositionend = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionEnd);
+    };
+
     let oninputmousedown = move |e: MouseEvent| {
         if !display_placeholder {
             editable.process_event(&EditableEvent::MouseDown(e.data, 0));
@@ -324,11 +343,17 @@ pub fn Input(
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
 
+    // Text being composed with an IME is only shown for visible text
+    let show_composition = mode == InputMode::Shown && !display_placeholder;
+
     let (background, cursor_char) = if focus.is_focused() {
-        (
-            hover_background,
-            editable.editor().read().cursor_pos().to_string(),
-        )
+        let editor = editable.editor().read();
+        let cursor_pos = if show_composition {
+            editor.visible_cursor_pos()
+        } else {
+            editor.cursor_pos()
+        };
+        (hover_background, cursor_pos.to_string())
     } else {
         (background, "none".to_string())
     };
@@ -350,6 +375,11 @@ pub fn Input(
         (InputMode::Shown, _) => Cow::Borrowed(value.as_str()),
     };
 
+    // The displayed text might not be the value, e.g a hidden mask, but it has the same chars
+    let composition_text = editable
+        .split_composition(&text, 0)
+        .filter(|_| show_composition);
+
     rsx!(
         rect {
             width,
@@ -365,8 +395,12 @@ pub fn Input(
             a11y_role: "text-input",
             a11y_auto_focus: "{auto_focus}",
             a11y_value: "{text}",
+            a11y_description,
             onkeydown,
             onkeyup,
+            oncompositionstart,
+            oncompositionupdate,
+            oncompositionend,
             overflow: "clip",
             onmousedown: oninputmousedown,
             onmouseenter,
@@ -388,8 +422,21 @@ pub fn Input(
                     cursor_color: "{color}",
                     max_lines: "1",
                     highlights,
-                    text {
-                        "{text}"
+                    if let Some((before, composition, after)) = composition_text {
+                        text {
+                            "{before}"
+                        }
+                        text {
+                            decoration: "underline",
+                            "{composition}"
+                        }
+                        text {
+                            "{after}"
+                        }
+                    } else {
+                        text {
+                            "{text}"
+                        }
                     }
                 }
             }
@@ -450,6 +497,72 @@ mod test {
         assert_eq!(text.get(0).text(), Some("Hello, World"));
     }
 
+    #[tokio::test]
+    pub async fn input_composition() {
+        fn input_app() -> Element {
+            let mut value = use_signal(|| "Hello, Worl".to_string());
+
+            rsx!(Input {
+                value,
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            })
+        }
+
+        let mut utils = launch_test(input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0).get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Focus the input in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Compose "にほ"
+        utils.push_event(TestEvent::Composition {
+            name: CompositionEventName::CompositionStart,
+            text: String::new(),
+            cursor: None,
+        });
+        utils.push_event(TestEvent::Composition {
+            name: CompositionEventName::CompositionUpdate,
+            text: "にほ".to_string(),
+            cursor: Some((6, 6)),
+        });
+        utils.wait_for_update().await;
+
+        // The composed text is shown underlined after the cursor
+        assert_eq!(text.get(0).text(), Some("Hello, Worl"));
+        assert_eq!(text.get(1).text(), Some("にほ"));
+        assert_eq!(
+            text.get(1).state().font_style.text_decoration,
+            TextDecoration::UNDERLINE
+        );
+
+        // Commit "日"
+        utils.push_event(TestEvent::Composition {
+            name: CompositionEventName::CompositionEnd,
+            text: String::new(),
+            cursor: None,
+        });
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("日".to_string()),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(text.get(0).text(), Some("Hello, Worl日"));
+    }
+
     #[tokio::test]
     pub async fn validate() {
         fn input_app() -> Element {
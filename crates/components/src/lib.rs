@@ -66,6 +66,7 @@ mod popup;
 mod progress_bar;
 mod radio;
 mod resizable_container;
+mod rich_text_editor;
 mod scroll_views;
 mod segmented_button;
 mod selectable_text;
@@ -109,6 +110,7 @@ pub use popup::*;
 pub use progress_bar::*;
 pub use radio::*;
 pub use resizable_container::*;
+pub use rich_text_editor::*;
 pub use scroll_views::*;
 pub use segmented_button::*;
 pub use selectable_text::*;
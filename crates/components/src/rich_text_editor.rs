@@ -0,0 +1,325 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        CompositionData,
+        KeyboardData,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_focus,
+    use_platform,
+    EditableEvent,
+    RichRopeEditor,
+    SpanStyle,
+    TextEditor,
+    TextSpan,
+    UseEditable,
+};
+
+/// Properties for the [`RichTextEditor`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RichTextEditorProps {
+    /// Editable rich text, created with [`use_rich_editable`](freya_hooks::use_rich_editable).
+    pub editable: UseEditable<RichRopeEditor>,
+    #[props(default = "100%".to_string())]
+    pub width: String,
+    #[props(default = "auto".to_string())]
+    pub height: String,
+    /// Color of the cursor.
+    #[props(default = "black".to_string())]
+    pub cursor_color: String,
+    /// Handler for the `onchange` event, called with the new spans when the text or its styles change.
+    pub onchange: Option<EventHandler<Vec<TextSpan>>>,
+}
+
+/// Editor of text with styled spans (bold, italic, colors, font sizes and links).
+///
+/// Use `Ctrl+B` and `Ctrl+I` (`Cmd` in macOS) to toggle bold and italic in the selected text,
+/// or the formatting methods of [`RichRopeEditor`] for anything else.
+/// The styles are kept when copying and pasting between rich text editors.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut editable = use_rich_editable(|| {
+///         EditableConfig::new_rich(&[
+///             TextSpan::new("Hello, ", SpanStyle::default()),
+///             TextSpan::new("World", SpanStyle::default().with_bold(true)),
+///         ])
+///     });
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| editable.editor_mut().write().set_color(Some("red".to_string())),
+///             label { "Red" }
+///         }
+///         RichTextEditor {
+///             editable
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn RichTextEditor(
+    RichTextEditorProps {
+        mut editable,
+        width,
+        height,
+        cursor_color,
+        onchange,
+    }: RichTextEditorProps,
+) -> Element {
+    let platform = use_platform();
+    let mut hovering = use_signal(|| false);
+    let mut focus = use_focus();
+    let mut drag_origin = use_signal(|| None);
+
+    use_drop(move || {
+        if *hovering.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onkeydown = move |e: Event<KeyboardData>| {
+        e.stop_propagation();
+        let rope = editable.editor().peek().rope().clone();
+        let styles = editable.editor().peek().styles().clone();
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+        if let Some(onchange) = onchange {
+            let editor = editable.editor().peek();
+            if *editor.rope() != rope || *editor.styles() != styles {
+                onchange.call(editor.spans());
+            }
+        }
+    };
+
+    let onkeyup = move |e: Event<KeyboardData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    let oncompositionstart = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionStart);
+    };
+
+    let oncompositionupdate = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionUpdate(e.data));
+    };
+
+    let oncompositionend = move |e: Event<CompositionData>| {
+        e.stop_propagation();
+        editable.process_event(&EditableEvent::CompositionEnd);
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+        drag_origin.set(Some(e.get_screen_coordinates() - e.element_coordinates));
+        editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        focus.request_focus();
+    };
+
+    let onglobalmousemove = move |mut e: MouseEvent| {
+        if focus.is_focused() {
+            if let Some(drag_origin) = drag_origin() {
+                let data = Rc::get_mut(&mut e.data).unwrap();
+                data.element_coordinates.x -= drag_origin.x;
+                data.element_coordinates.y -= drag_origin.y;
+                editable.process_event(&EditableEvent::MouseMove(e.data, 0));
+            }
+        }
+    };
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::Text);
+        hovering.set(true);
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        hovering.set(false);
+    };
+
+    let onglobalclick = move |_| {
+        if *hovering.read() || focus.is_focused() {
+            editable.process_event(&EditableEvent::Click);
+        }
+
+        if drag_origin.read().is_some() {
+            // Finish the drag
+            drag_origin.set(None);
+        } else if focus.is_focused() && !*hovering.read() {
+            // Clicked outside the editor
+            focus.request_unfocus();
+        }
+    };
+
+    let a11y_id = focus.attribute();
+    let cursor_reference = editable.cursor_attr();
+    let highlights = editable.highlights_attr(0);
+
+    let (cursor_char, spans, text) = {
+        let editor = editable.editor().read();
+        let cursor_char = if focus.is_focused() {
+            editor.visible_cursor_pos().to_string()
+        } else {
+            "none".to_string()
+        };
+        (cursor_char, editor.spans(), editor.to_string())
+    };
+
+    // Show the text being composed with an IME inside the span where it will be inserted
+    let mut span_start = 0;
+    let mut is_composition_shown = false;
+    let mut parts = Vec::with_capacity(spans.len() + 2);
+    for span in spans {
+        let span_len = span.text.chars().count();
+        let composition = editable
+            .split_composition(&span.text, span_start)
+            .filter(|_| !is_composition_shown);
+        span_start += span_len;
+        match composition {
+            Some((before, composition, after)) => {
+                is_composition_shown = true;
+                parts.push((TextSpan::new(before, span.style.clone()), false));
+                parts.push((TextSpan::new(composition, span.style.clone()), true));
+                parts.push((TextSpan::new(after, span.style), false));
+            }
+            None => parts.push((span, false)),
+        }
+    }
+    if !is_composition_shown {
+        // There are no spans in an empty text
+        if let Some((_, composition, _)) = editable.split_composition("", span_start) {
+            parts.push((TextSpan::new(composition, SpanStyle::default()), true));
+        }
+    }
+
+    rsx!(
+        paragraph {
+            width,
+            height,
+            a11y_id,
+            a11y_role: "text-input",
+            a11y_value: "{text}",
+            cursor_reference,
+            cursor_id: "0",
+            cursor_index: "{cursor_char}",
+            cursor_mode: "editable",
+            cursor_color: "{cursor_color}",
+            highlights,
+            onkeydown,
+            onkeyup,
+            oncompositionstart,
+            oncompositionupdate,
+            oncompositionend,
+            onmousedown,
+            onglobalmousemove,
+            onmouseenter,
+            onmouseleave,
+            onglobalclick,
+            for (i, (span, is_composition)) in parts.into_iter().enumerate() {
+                text {
+                    key: "{i}",
+                    font_weight: if span.style.bold { "bold" } else { "normal" },
+                    font_style: if span.style.italic { "italic" } else { "upright" },
+                    color: span.style.color.unwrap_or_else(|| "inherit".to_string()),
+                    font_size: span.style.font_size.map(|size| size.to_string()).unwrap_or_else(|| "inherit".to_string()),
+                    decoration: if is_composition || span.style.link.is_some() { "underline" } else { "none" },
+                    "{span.text}"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn rich_text_editor() {
+        fn rich_text_editor_app() -> Element {
+            let editable = use_rich_editable(|| {
+                EditableConfig::new_rich(&[
+                    TextSpan::new("Hello, ", SpanStyle::default()),
+                    TextSpan::new("World", SpanStyle::default().with_italic(true)),
+                ])
+            });
+
+            rsx!(RichTextEditor { editable })
+        }
+
+        let mut utils = launch_test(rich_text_editor_app);
+        let paragraph = utils.root().get(0);
+        utils.wait_for_update().await;
+
+        // Initial spans
+        assert_eq!(paragraph.get(0).text(), Some("Hello, "));
+        assert_eq!(paragraph.get(1).text(), Some("World"));
+        assert_eq!(
+            paragraph.get(1).state().font_style.font_slant,
+            FontSlant::Italic
+        );
+
+        // Focus the editor in the end of the text
+        utils.push_event(TestEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (300., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Toggle bold and write "!"
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("b".to_string()),
+            code: Code::KeyB,
+            modifiers: if cfg!(target_os = "macos") {
+                Modifiers::META
+            } else {
+                Modifiers::CONTROL
+            },
+        });
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("!".to_string()),
+            code: Code::Digit1,
+            modifiers: Modifiers::SHIFT,
+        });
+        utils.wait_for_update().await;
+
+        // The new text is bold and italic
+        assert_eq!(paragraph.get(2).text(), Some("!"));
+        let font_style = &paragraph.get(2).state().font_style;
+        assert_eq!(font_style.font_weight, FontWeight::BOLD);
+        assert_eq!(font_style.font_slant, FontSlant::Italic);
+
+        // Undo the writing
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character("z".to_string()),
+            code: Code::KeyZ,
+            modifiers: if cfg!(target_os = "macos") {
+                Modifiers::META
+            } else {
+                Modifiers::CONTROL
+            },
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(paragraph.children_ids().len(), 2);
+        assert_eq!(paragraph.get(1).text(), Some("World"));
+    }
+}
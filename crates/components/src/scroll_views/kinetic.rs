@@ -0,0 +1,284 @@
+use std::{
+    collections::VecDeque,
+    time::Duration,
+};
+
+use freya_hooks::{
+    apply_value,
+    Ease,
+    Function,
+};
+use tokio::time::Instant;
+
+use super::use_scroll_controller::ScrollFrame;
+
+/// Only the movements within this window are used to measure the velocity of a gesture.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+
+/// How fast the kinetic scroll slows down, in milliseconds.
+const KINETIC_TIME_CONSTANT: f32 = 325.0;
+
+/// How fast the content stops once it kinetically scrolls past its edges, in milliseconds.
+const OVERSHOOT_TIME_CONSTANT: f32 = 40.0;
+
+/// The kinetic scroll ends once it is slower than this, in pixels per millisecond.
+const MIN_KINETIC_VELOCITY: f32 = 0.02;
+
+/// Gestures faster than this keep scrolling once released, in pixels per millisecond.
+#[doc(hidden)]
+pub const KINETIC_SCROLL_THRESHOLD: f32 = 0.15;
+
+/// How long it takes the content to go back to its edges after being overscrolled.
+const BOUNCE_DURATION: Duration = Duration::from_millis(300);
+
+/// Maximum overscroll, relative to the viewport size.
+const MAX_OVERSCROLL_RATIO: f32 = 0.25;
+
+/// Measures the velocity of a gesture from its latest movements.
+/// Movements are timestamped with the Tokio clock, so they follow the virtual clock of `freya-testing`.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct VelocityTracker {
+    samples: VecDeque<(Instant, f32, f32)>,
+}
+
+impl VelocityTracker {
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn push(&mut self, delta_x: f32, delta_y: f32) {
+        let now = Instant::now();
+        self.samples.push_back((now, delta_x, delta_y));
+        while let Some((time, ..)) = self.samples.front() {
+            if now.duration_since(*time) > VELOCITY_WINDOW {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Velocity in pixels per millisecond.
+    /// Gestures that stopped moving before being released have no velocity.
+    pub fn velocity(&self) -> (f32, f32) {
+        let now = Instant::now();
+        let mut samples = self
+            .samples
+            .iter()
+            .filter(|(time, ..)| now.duration_since(*time) <= VELOCITY_WINDOW);
+
+        let Some((first, ..)) = samples.next() else {
+            return (0., 0.);
+        };
+
+        // The movement of the first sample happened before it was measured
+        let (mut x, mut y, mut last) = (0., 0., *first);
+        for (time, delta_x, delta_y) in samples {
+            x += delta_x;
+            y += delta_y;
+            last = *time;
+        }
+
+        let elapsed = last.duration_since(*first).as_secs_f32() * 1000.;
+        if elapsed > 0. {
+            (x / elapsed, y / elapsed)
+        } else {
+            (0., 0.)
+        }
+    }
+}
+
+/// Maximum overscroll for the given viewport size.
+#[doc(hidden)]
+pub fn get_max_overscroll(viewport_size: f32) -> f32 {
+    viewport_size * MAX_OVERSCROLL_RATIO
+}
+
+/// Apply a movement to the content while letting it go past its edges with increasing resistance, like a rubber band.
+/// Returns the new position and overscroll.
+#[doc(hidden)]
+pub fn get_rubber_band_position(
+    movement: f32,
+    inner_size: f32,
+    viewport_size: f32,
+    position: f32,
+    overscroll: f32,
+) -> (f32, f32) {
+    let min_position = -(inner_size - viewport_size).max(0.);
+    let target = position + overscroll + movement;
+    let new_position = target.clamp(min_position, 0.);
+    let new_overscroll = target - new_position;
+
+    // Pulling further away from the edges gets harder the more overscrolled the content is
+    if new_overscroll.abs() > overscroll.abs() && new_overscroll.signum() == overscroll.signum() {
+        let resistance = (1. - overscroll.abs() / get_max_overscroll(viewport_size)).max(0.);
+        (
+            new_position,
+            overscroll + (new_overscroll - overscroll) * resistance,
+        )
+    } else {
+        (new_position, new_overscroll)
+    }
+}
+
+enum KineticPhase {
+    /// Scroll with a decaying velocity.
+    Momentum { origin: f32, velocity: f32 },
+    /// Quickly stop past the edge.
+    Overshoot {
+        start: f32,
+        edge: f32,
+        velocity: f32,
+    },
+    /// Go back to the edge.
+    Bounce { start: f32, from: f32, edge: f32 },
+}
+
+/// Scroll that keeps going after a gesture is released, slowing down until it stops.
+/// With overscroll enabled, the content bounces back when it goes past its edges.
+#[doc(hidden)]
+pub struct KineticScroll {
+    phase: KineticPhase,
+    min_position: f32,
+    max_overscroll: f32,
+}
+
+impl KineticScroll {
+    pub fn new(
+        position: f32,
+        overscroll: f32,
+        velocity: f32,
+        inner_size: f32,
+        viewport_size: f32,
+        allow_overscroll: bool,
+    ) -> Self {
+        let min_position = -(inner_size - viewport_size).max(0.);
+        let max_overscroll = if allow_overscroll {
+            get_max_overscroll(viewport_size)
+        } else {
+            0.
+        };
+
+        let phase = if overscroll != 0. {
+            KineticPhase::Bounce {
+                start: 0.,
+                from: position + overscroll,
+                edge: position,
+            }
+        } else {
+            KineticPhase::Momentum {
+                origin: position,
+                velocity,
+            }
+        };
+
+        Self {
+            phase,
+            min_position,
+            max_overscroll,
+        }
+    }
+
+    pub(crate) fn frame(&mut self, elapsed: Duration) -> ScrollFrame {
+        let time = elapsed.as_secs_f32() * 1000.;
+
+        loop {
+            match self.phase {
+                KineticPhase::Momentum { origin, velocity } => {
+                    let decay = (-time / KINETIC_TIME_CONSTANT).exp();
+                    let position = origin + velocity * KINETIC_TIME_CONSTANT * (1. - decay);
+                    let velocity = velocity * decay;
+                    let edge = position.clamp(self.min_position, 0.);
+
+                    if position != edge && self.max_overscroll > 0. {
+                        self.phase = KineticPhase::Overshoot {
+                            start: time,
+                            edge,
+                            velocity,
+                        };
+                        continue;
+                    }
+
+                    return ScrollFrame {
+                        position: edge,
+                        overscroll: 0.,
+                        finished: position != edge || velocity.abs() < MIN_KINETIC_VELOCITY,
+                    };
+                }
+                KineticPhase::Overshoot {
+                    start,
+                    edge,
+                    velocity,
+                } => {
+                    let decay = (-(time - start) / OVERSHOOT_TIME_CONSTANT).exp();
+                    let overscroll = (velocity * OVERSHOOT_TIME_CONSTANT * (1. - decay))
+                        .clamp(-self.max_overscroll, self.max_overscroll);
+
+                    if (velocity * decay).abs() < MIN_KINETIC_VELOCITY
+                        || overscroll.abs() >= self.max_overscroll
+                    {
+                        self.phase = KineticPhase::Bounce {
+                            start: time,
+                            from: edge + overscroll,
+                            edge,
+                        };
+                        continue;
+                    }
+
+                    return ScrollFrame {
+                        position: edge,
+                        overscroll,
+                        finished: false,
+                    };
+                }
+                KineticPhase::Bounce { start, from, edge } => {
+                    let index = (time - start).max(0.) as u128;
+                    let position = apply_value(
+                        from,
+                        edge,
+                        index,
+                        BOUNCE_DURATION,
+                        Ease::Out,
+                        Function::Cubic,
+                    );
+
+                    return ScrollFrame {
+                        position: edge,
+                        overscroll: position - edge,
+                        finished: index >= BOUNCE_DURATION.as_millis(),
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time;
+
+    use super::VelocityTracker;
+
+    #[tokio::test]
+    pub async fn velocity_follows_the_tokio_clock() {
+        time::pause();
+
+        let mut tracker = VelocityTracker::default();
+        tracker.push(0., 0.);
+        time::advance(Duration::from_millis(10)).await;
+        tracker.push(10., -5.);
+        time::advance(Duration::from_millis(10)).await;
+        tracker.push(10., -5.);
+
+        let (velocity_x, velocity_y) = tracker.velocity();
+        assert!((velocity_x - 1.).abs() < 0.001);
+        assert!((velocity_y + 0.5).abs() < 0.001);
+
+        // Gestures that stop moving have no velocity
+        time::advance(Duration::from_millis(200)).await;
+        assert_eq!(tracker.velocity(), (0., 0.));
+    }
+}
@@ -1,3 +1,4 @@
+mod kinetic;
 mod scroll_bar;
 mod scroll_thumb;
 mod scroll_view;
@@ -8,6 +9,7 @@ use freya_elements::events::{
     keyboard::Key,
     KeyboardEvent,
 };
+pub use kinetic::*;
 pub use scroll_bar::*;
 pub use scroll_thumb::*;
 pub use scroll_view::*;
@@ -3,8 +3,11 @@ use freya_elements::{
     self as dioxus_elements,
     events::{
         keyboard::Key,
+        touch::TouchPhase,
         KeyboardEvent,
         MouseEvent,
+        ScrollEvent,
+        TouchEvent,
         WheelEvent,
     },
     WheelSource,
@@ -15,8 +18,21 @@ use freya_hooks::{
     use_node_from_signal,
     ScrollBarThemeWith,
 };
-
-use super::use_scroll_controller::ScrollController;
+use torin::prelude::CursorPoint;
+
+use super::{
+    kinetic::{
+        get_rubber_band_position,
+        KineticScroll,
+        VelocityTracker,
+        KINETIC_SCROLL_THRESHOLD,
+    },
+    use_scroll_controller::{
+        use_provide_scroll_controller,
+        ScrollController,
+        ScrollDirection,
+    },
+};
 use crate::{
     get_container_sizes,
     get_corrected_scroll_position,
@@ -77,6 +93,14 @@ pub struct ScrollViewProps {
     /// If `true`, wheel scroll with no shift will scroll horizontally.
     #[props(default = false)]
     pub invert_scroll_wheel: bool,
+    /// Keep scrolling with momentum after touch and touchpad gestures, enabled by default.
+    #[props(default = true)]
+    pub kinetic_scroll: bool,
+    /// Let touch and touchpad gestures pull the content past its edges, after which it bounces back.
+    #[props(default = false)]
+    pub overscroll: bool,
+    /// Handler for the `onscroll` event, fired after the content is scrolled.
+    pub onscroll: Option<EventHandler<ScrollEvent>>,
 }
 
 /// Scrollable area with bidirectional support and scrollbars.
@@ -150,6 +174,14 @@ pub struct ScrollViewProps {
 /// # }, (250., 250.).into(), "./images/gallery_scroll_view.png");
 /// ```
 ///
+/// # Kinetic scrolling
+///
+/// Touch and touchpad gestures keep scrolling with momentum once released, unless `kinetic_scroll` is disabled.
+/// Enable `overscroll` to let these gestures pull the content past its edges.
+///
+/// Use [`ScrollController::animate_to`] to smoothly scroll to a position, or [`scroll_into_view`](crate::scroll_into_view)
+/// to reveal an element, even across nested scroll views.
+///
 /// # Preview
 /// ![ScrollView Preview][scroll_view]
 #[cfg_attr(feature = "docs",
@@ -173,15 +205,22 @@ pub fn ScrollView(
         scroll_with_arrows,
         scroll_controller,
         invert_scroll_wheel,
+        kinetic_scroll,
+        overscroll,
+        onscroll,
     }: ScrollViewProps,
 ) -> Element {
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
     let mut clicking_shift = use_signal(|| false);
     let mut clicking_alt = use_signal(|| false);
+    let mut touchpad_gesture = use_signal(|| false);
+    let mut touch = use_signal::<Option<(u64, CursorPoint)>>(|| None);
+    let mut velocity_tracker = use_signal(VelocityTracker::default);
     let mut scroll_controller =
         scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
     let (node_ref, size) = use_node_from_signal(|| scroll_controller.layout());
+    use_provide_scroll_controller(scroll_controller);
 
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
@@ -218,8 +257,108 @@ pub fn ScrollView(
     let (scrollbar_x, scrollbar_width) =
         get_scrollbar_pos_and_size(size.inner.width, size.area.width(), corrected_scrolled_x);
 
+    let offset_x = corrected_scrolled_x + *scroll_controller.overscroll_x().read();
+    let offset_y = corrected_scrolled_y + *scroll_controller.overscroll_y().read();
+
+    let is_scrollable =
+        size.inner.width > size.area.width() || size.inner.height > size.area.height();
+
+    // Moves the content by the given movement, returns whether it actually moved
+    let move_content = move |movement_x: f32, movement_y: f32| {
+        let mut moved = false;
+
+        for (direction, movement) in [
+            (ScrollDirection::Horizontal, movement_x),
+            (ScrollDirection::Vertical, movement_y),
+        ] {
+            let (inner_size, viewport_size) = scroll_controller.sizes(direction);
+            if movement == 0. || viewport_size >= inner_size {
+                continue;
+            }
+
+            let mut scrolled = scroll_controller.axis(direction);
+            let mut overscrolled = scroll_controller.overscroll(direction);
+            let position =
+                get_corrected_scroll_position(inner_size, viewport_size, *scrolled.peek() as f32);
+
+            let (new_position, new_overscroll) = if overscroll {
+                get_rubber_band_position(
+                    movement,
+                    inner_size,
+                    viewport_size,
+                    position,
+                    *overscrolled.peek(),
+                )
+            } else {
+                let new_position =
+                    get_scroll_position_from_wheel(movement, inner_size, viewport_size, position);
+                (new_position as f32, 0.)
+            };
+
+            if new_position.round() as i32 != *scrolled.peek() {
+                moved = true;
+                scrolled.set(new_position.round() as i32);
+            }
+            if new_overscroll != *overscrolled.peek() {
+                moved = true;
+                overscrolled.set(new_overscroll);
+            }
+        }
+
+        moved
+    };
+
+    // Keeps scrolling once a gesture is released, or bounces back if it left the content overscrolled
+    let mut release_gesture = move || {
+        let (velocity_x, velocity_y) = velocity_tracker.peek().velocity();
+        velocity_tracker.write().reset();
+
+        for (direction, velocity) in [
+            (ScrollDirection::Horizontal, velocity_x),
+            (ScrollDirection::Vertical, velocity_y),
+        ] {
+            let (inner_size, viewport_size) = scroll_controller.sizes(direction);
+            let overscrolled = *scroll_controller.overscroll(direction).peek();
+            let is_fast = kinetic_scroll && velocity.abs() >= KINETIC_SCROLL_THRESHOLD;
+
+            if viewport_size < inner_size && (is_fast || overscrolled != 0.) {
+                let position = get_corrected_scroll_position(
+                    inner_size,
+                    viewport_size,
+                    *scroll_controller.axis(direction).peek() as f32,
+                );
+                let mut kinetic = KineticScroll::new(
+                    position,
+                    overscrolled,
+                    velocity,
+                    inner_size,
+                    viewport_size,
+                    overscroll,
+                );
+                scroll_controller.run_animation(direction, move |elapsed| kinetic.frame(elapsed));
+            }
+        }
+    };
+
     // Moves the axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // Touchpads report when the gesture starts and ends, mouse wheels don't
+        match e.get_phase() {
+            TouchPhase::Started => {
+                scroll_controller.stop_animations();
+                velocity_tracker.write().reset();
+                touchpad_gesture.set(true);
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                if *touchpad_gesture.peek() {
+                    touchpad_gesture.set(false);
+                    release_gesture();
+                }
+                return;
+            }
+            TouchPhase::Moved => {}
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
@@ -243,6 +382,16 @@ pub fn ScrollView(
             )
         };
 
+        if *touchpad_gesture.peek() {
+            velocity_tracker.write().push(x_movement, y_movement);
+            if move_content(x_movement, y_movement) {
+                e.stop_propagation();
+            }
+            return;
+        }
+
+        scroll_controller.stop_animations();
+
         let scroll_position_y = get_scroll_position_from_wheel(
             y_movement,
             size.inner.height,
@@ -270,6 +419,45 @@ pub fn ScrollView(
         }
     };
 
+    // Scroll the content along with the finger
+    let ontouchstart = move |e: TouchEvent| {
+        if touch.peek().is_some() {
+            return;
+        }
+        scroll_controller.stop_animations();
+        velocity_tracker.write().reset();
+        touch.set(Some((e.get_finger_id(), e.get_screen_coordinates())));
+    };
+
+    let ontouchmove = move |e: TouchEvent| {
+        let Some((finger_id, last_location)) = *touch.peek() else {
+            return;
+        };
+        if e.get_finger_id() != finger_id {
+            return;
+        }
+
+        let location = e.get_screen_coordinates();
+        let movement_x = (location.x - last_location.x) as f32;
+        let movement_y = (location.y - last_location.y) as f32;
+        touch.set(Some((finger_id, location)));
+        velocity_tracker.write().push(movement_x, movement_y);
+        move_content(movement_x, movement_y);
+
+        // Scroll views that can scroll keep the whole gesture so the outer ones don't scroll as well
+        if is_scrollable {
+            e.stop_propagation();
+        }
+    };
+
+    let ontouchend = move |e: TouchEvent| {
+        let finger_id = (*touch.peek()).map(|(finger_id, _)| finger_id);
+        if finger_id == Some(e.get_finger_id()) {
+            touch.set(None);
+            release_gesture();
+        }
+    };
+
     // Drag the scrollbars
     let oncaptureglobalmousemove = move |e: MouseEvent| {
         let clicking_scrollbar = clicking_scrollbar.peek();
@@ -325,6 +513,8 @@ pub fn ScrollView(
                     return;
                 }
 
+                scroll_controller.stop_animations();
+
                 let x = corrected_scrolled_x;
                 let y = corrected_scrolled_y;
                 let inner_height = size.inner.height;
@@ -357,12 +547,14 @@ pub fn ScrollView(
 
     // Mark the Y axis scrollbar as the one being dragged
     let onmousedown_y = move |e: MouseEvent| {
+        scroll_controller.stop_animations();
         let coordinates = e.get_element_coordinates();
         *clicking_scrollbar.write() = Some((Axis::Y, coordinates.y));
     };
 
     // Mark the X axis scrollbar as the one being dragged
     let onmousedown_x = move |e: MouseEvent| {
+        scroll_controller.stop_animations();
         let coordinates = e.get_element_coordinates();
         *clicking_scrollbar.write() = Some((Axis::X, coordinates.x));
     };
@@ -387,6 +579,12 @@ pub fn ScrollView(
 
     let a11y_id = focus.attribute();
 
+    let onscroll = move |e: ScrollEvent| {
+        if let Some(onscroll) = &onscroll {
+            onscroll.call(e);
+        }
+    };
+
     rsx!(
         rect {
             a11y_role: "scroll-view",
@@ -405,6 +603,10 @@ pub fn ScrollView(
             onglobalkeydown,
             onglobalkeyup,
             onwheel,
+            ontouchstart,
+            ontouchmove,
+            ontouchend,
+            ontouchcancel: ontouchend,
             a11y_id,
             a11y_focusable: "false",
             rect {
@@ -422,9 +624,10 @@ pub fn ScrollView(
                     max_width: max_width.map(|x| x.to_string()),
                     max_height: max_height.map(|x| x.to_string()),
                     direction: direction,
-                    offset_y: "{corrected_scrolled_y}",
-                    offset_x: "{corrected_scrolled_x}",
+                    offset_y: "{offset_y}",
+                    offset_x: "{offset_x}",
                     reference: node_ref,
+                    onscroll,
                     {children}
                 }
                 if show_scrollbar && horizontal_scrollbar_is_visible {
@@ -465,6 +668,8 @@ pub fn ScrollView(
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
@@ -509,6 +714,7 @@ mod test {
             name: WheelEventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            phase: TouchPhase::Moved,
         });
 
         utils.wait_for_update().await;
@@ -621,4 +827,136 @@ mod test {
         assert!(content.get(2).is_visible());
         assert!(content.get(3).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn scroll_view_kinetic() {
+        fn scroll_view_kinetic_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            scroll_view_kinetic_app,
+            TestingConfig::<()> {
+                virtual_clock: true,
+                ..TestingConfig::default()
+            },
+        );
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // Drag the content 150 pixels up
+        utils.push_event(TestEvent::Touch {
+            name: TouchEventName::TouchStart,
+            location: (100., 400.).into(),
+            phase: TouchPhase::Started,
+            finger_id: 0,
+            force: None,
+        });
+        utils.advance(Duration::from_millis(16)).await;
+        for i in 1..=5 {
+            utils.push_event(TestEvent::Touch {
+                name: TouchEventName::TouchMove,
+                location: (100., 400. - 30. * i as f64).into(),
+                phase: TouchPhase::Moved,
+                finger_id: 0,
+                force: None,
+            });
+            utils.advance(Duration::from_millis(16)).await;
+        }
+
+        // The content follows the finger
+        assert!(content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+
+        utils.push_event(TestEvent::Touch {
+            name: TouchEventName::TouchEnd,
+            location: (100., 250.).into(),
+            phase: TouchPhase::Ended,
+            finger_id: 0,
+            force: None,
+        });
+        utils.run_until_idle().await;
+
+        // The content kept scrolling after releasing it, until reaching the end
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+    }
+
+    #[tokio::test]
+    pub async fn scroll_into_view_nested() {
+        #[component]
+        fn Target() -> Element {
+            let (reference, layout) = use_node_signal();
+
+            rsx!(rect {
+                reference,
+                height: "50",
+                width: "100%",
+                onglobalkeydown: move |_| scroll_into_view(&layout.read(), None),
+            })
+        }
+
+        fn scroll_into_view_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "600",
+                        width: "100%",
+                    }
+                    ScrollView {
+                        height: "200",
+                        rect {
+                            height: "300",
+                            width: "100%",
+                        }
+                        Target {}
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_into_view_app);
+        let root = utils.root();
+        let inner_content = root.get(0).get(0).get(0).get(1).get(0).get(0);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The target is hidden in both scroll views
+        assert!(!inner_content.get(1).is_visible());
+
+        utils.push_event(TestEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Enter,
+            code: Code::Enter,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Both scroll views scrolled to reveal the target
+        assert!(inner_content.get(1).is_visible());
+    }
 }
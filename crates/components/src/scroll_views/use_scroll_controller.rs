@@ -1,29 +1,52 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
 
 use ::warnings::Warning;
 use dioxus::prelude::{
+    consume_context,
     current_scope_id,
+    provide_context,
     schedule_update_any,
+    spawn,
+    try_consume_context,
     use_drop,
     use_hook,
     warnings,
     Readable,
     ScopeId,
     Signal,
+    Task,
     Writable,
     WritableVecExt,
 };
-use freya_core::custom_attributes::NodeReferenceLayout;
+use freya_core::{
+    animation_clock::AnimationClock,
+    custom_attributes::NodeReferenceLayout,
+};
+use freya_hooks::{
+    apply_value,
+    Ease,
+    Function,
+    UsePlatform,
+};
+use tokio::time::Instant;
+use torin::geometry::Area;
+
+use crate::get_corrected_scroll_position;
 
 #[derive(Default, PartialEq, Eq)]
 pub enum ScrollPosition {
     #[default]
     Start,
     End,
-    // Specific
+    /// Position of the item at the given index.
+    /// Only supported by [`VirtualScrollView`](crate::VirtualScrollView), use [`scroll_into_view`] to reveal an element of a [`ScrollView`](crate::ScrollView).
+    Item(usize),
 }
 
-#[derive(Default, PartialEq, Eq)]
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
 pub enum ScrollDirection {
     #[default]
     Vertical,
@@ -36,9 +59,28 @@ pub struct ScrollConfig {
     pub default_horizontal_position: ScrollPosition,
 }
 
+/// Easing of an animated scroll, see [`ScrollController::animate_to`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct ScrollAnimation {
+    pub duration: Duration,
+    pub ease: Ease,
+    pub function: Function,
+}
+
+impl Default for ScrollAnimation {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(300),
+            ease: Ease::Out,
+            function: Function::Cubic,
+        }
+    }
+}
+
 pub struct ScrollRequest {
     pub(crate) position: ScrollPosition,
     pub(crate) direction: ScrollDirection,
+    pub(crate) animation: Option<ScrollAnimation>,
     pub(crate) init: bool,
     pub(crate) applied_by: HashSet<ScopeId>,
 }
@@ -48,10 +90,87 @@ impl ScrollRequest {
         ScrollRequest {
             position,
             direction,
+            animation: None,
             init: false,
             applied_by: HashSet::default(),
         }
     }
+
+    /// Animate the scroll instead of jumping to the position.
+    pub fn with_animation(mut self, animation: ScrollAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+}
+
+/// A frame of a scroll animation.
+pub(crate) struct ScrollFrame {
+    /// Scroll position, within the bounds of the scroll view.
+    pub position: f32,
+    /// How far the content is pulled past its edges.
+    pub overscroll: f32,
+    pub finished: bool,
+}
+
+/// The [`ScrollController`]s of the scroll views that contain a component, from the outermost to the innermost.
+#[derive(Clone, Default)]
+pub(crate) struct ScrollControllers(Vec<ScrollController>);
+
+/// Let the descendants of the current component know they are inside a scroll view with the given controller.
+pub(crate) fn use_provide_scroll_controller(scroll_controller: ScrollController) {
+    use_hook(|| {
+        let mut scroll_controllers = try_consume_context::<ScrollControllers>().unwrap_or_default();
+        scroll_controllers.0.push(scroll_controller);
+        provide_context(scroll_controllers);
+    });
+}
+
+/// Scroll the [`ScrollView`](crate::ScrollView)s that contain the current component, from the innermost to the outermost,
+/// so that the given layout, usually obtained with [`use_node_signal`](freya_hooks::use_node_signal), becomes visible.
+///
+/// The scroll is animated if an [`ScrollAnimation`] is given.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ScrollView {
+///             rect {
+///                 height: "1000",
+///             }
+///             Item {}
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Item() -> Element {
+///     let (reference, layout) = use_node_signal();
+///
+///     rsx!(
+///         rect {
+///             reference,
+///             Button {
+///                 onpress: move |_| scroll_into_view(&layout.read(), None),
+///                 label {
+///                     "Show me"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn scroll_into_view(layout: &NodeReferenceLayout, animation: Option<ScrollAnimation>) {
+    let Some(scroll_controllers) = try_consume_context::<ScrollControllers>() else {
+        return;
+    };
+
+    let mut area = layout.area;
+    for mut scroll_controller in scroll_controllers.0.into_iter().rev() {
+        let (delta_x, delta_y) = scroll_controller.reveal(area, animation);
+        // Once scrolled, the area will have moved inside the outer scroll views as well
+        area = area.translate((delta_x, delta_y).into());
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -60,6 +179,10 @@ pub struct ScrollController {
     requests: Signal<Vec<ScrollRequest>>,
     x: Signal<i32>,
     y: Signal<i32>,
+    overscroll_x: Signal<f32>,
+    overscroll_y: Signal<f32>,
+    animation_x: Signal<Option<Task>>,
+    animation_y: Signal<Option<Task>>,
     layout: Signal<NodeReferenceLayout>,
 }
 
@@ -76,6 +199,10 @@ impl ScrollController {
             y: Signal::new(y),
             requests_subscribers: Signal::new(HashSet::new()),
             requests: Signal::new(initial_requests),
+            overscroll_x: Signal::new(0.),
+            overscroll_y: Signal::new(0.),
+            animation_x: Signal::new(None),
+            animation_y: Signal::new(None),
             layout: Signal::default(),
         }
     }
@@ -92,7 +219,28 @@ impl ScrollController {
         self.layout
     }
 
+    /// How far the content is pulled past its horizontal edges.
+    pub fn overscroll_x(&self) -> Signal<f32> {
+        self.overscroll_x
+    }
+
+    /// How far the content is pulled past its vertical edges.
+    pub fn overscroll_y(&self) -> Signal<f32> {
+        self.overscroll_y
+    }
+
     pub fn use_apply(&mut self, width: f32, height: f32) {
+        self.use_apply_with_items(width, height, |_| None);
+    }
+
+    /// Same as [`ScrollController::use_apply`] but `item_offset` resolves the position of
+    /// the requested item indexes, see [`ScrollPosition::Item`].
+    pub fn use_apply_with_items(
+        &mut self,
+        width: f32,
+        height: f32,
+        item_offset: impl Fn(usize) -> Option<f32>,
+    ) {
         let scope_id = current_scope_id().unwrap();
 
         if !self.requests_subscribers.peek().contains(&scope_id) {
@@ -107,28 +255,50 @@ impl ScrollController {
         let _allow_write_in_component_body =
             ::warnings::Allow::new(warnings::signal_write_in_component_body::ID);
 
+        let mut controller = *self;
         self.requests.write().retain_mut(|request| {
             if request.applied_by.contains(&scope_id) {
                 return true;
             }
 
+            let animation = request.animation;
+            let mut scroll = |direction: ScrollDirection, to: f32| match animation {
+                Some(animation) => controller.animate_axis_to(direction, to, animation),
+                None => controller.set_axis(direction, to as i32),
+            };
+
             match request {
                 ScrollRequest {
                     position: ScrollPosition::Start,
+                    direction,
+                    ..
+                } => {
+                    scroll(*direction, 0.);
+                }
+                ScrollRequest {
+                    position: ScrollPosition::End,
                     direction: ScrollDirection::Vertical,
+                    init,
                     ..
                 } => {
-                    *self.y.write() = 0;
+                    if *init && height == 0. {
+                        return true;
+                    }
+                    scroll(ScrollDirection::Vertical, -height);
                 }
                 ScrollRequest {
-                    position: ScrollPosition::Start,
+                    position: ScrollPosition::End,
                     direction: ScrollDirection::Horizontal,
+                    init,
                     ..
                 } => {
-                    *self.x.write() = 0;
+                    if *init && width == 0. {
+                        return true;
+                    }
+                    scroll(ScrollDirection::Horizontal, -width);
                 }
                 ScrollRequest {
-                    position: ScrollPosition::End,
+                    position: ScrollPosition::Item(index),
                     direction: ScrollDirection::Vertical,
                     init,
                     ..
@@ -136,10 +306,15 @@ impl ScrollController {
                     if *init && height == 0. {
                         return true;
                     }
-                    *self.y.write() = -height as i32;
+                    match item_offset(*index) {
+                        Some(offset) => scroll(ScrollDirection::Vertical, -offset),
+                        None => tracing::warn!(
+                            "ScrollPosition::Item is only supported by VirtualScrollView"
+                        ),
+                    }
                 }
                 ScrollRequest {
-                    position: ScrollPosition::End,
+                    position: ScrollPosition::Item(index),
                     direction: ScrollDirection::Horizontal,
                     init,
                     ..
@@ -147,7 +322,12 @@ impl ScrollController {
                     if *init && width == 0. {
                         return true;
                     }
-                    *self.x.write() = -width as i32;
+                    match item_offset(*index) {
+                        Some(offset) => scroll(ScrollDirection::Horizontal, -offset),
+                        None => tracing::warn!(
+                            "ScrollPosition::Item is only supported by VirtualScrollView"
+                        ),
+                    }
                 }
             }
 
@@ -158,11 +338,11 @@ impl ScrollController {
     }
 
     pub fn scroll_to_x(&mut self, to: i32) {
-        self.x.set(to);
+        self.set_axis(ScrollDirection::Horizontal, to);
     }
 
     pub fn scroll_to_y(&mut self, to: i32) {
-        self.y.set(to);
+        self.set_axis(ScrollDirection::Vertical, to);
     }
 
     pub fn scroll_to(
@@ -170,13 +350,235 @@ impl ScrollController {
         scroll_position: ScrollPosition,
         scroll_direction: ScrollDirection,
     ) {
-        self.requests
-            .push(ScrollRequest::new(scroll_position, scroll_direction));
+        self.request(ScrollRequest::new(scroll_position, scroll_direction));
+    }
+
+    /// Same as [`ScrollController::scroll_to`] but the scroll is animated with the given [`ScrollAnimation`].
+    pub fn animate_to(
+        &mut self,
+        scroll_position: ScrollPosition,
+        scroll_direction: ScrollDirection,
+        animation: ScrollAnimation,
+    ) {
+        self.request(
+            ScrollRequest::new(scroll_position, scroll_direction).with_animation(animation),
+        );
+    }
+
+    /// Animate the horizontal scroll to the given offset.
+    pub fn animate_to_x(&mut self, to: i32, animation: ScrollAnimation) {
+        self.animate_axis_to(ScrollDirection::Horizontal, to as f32, animation);
+    }
+
+    /// Animate the vertical scroll to the given offset.
+    pub fn animate_to_y(&mut self, to: i32, animation: ScrollAnimation) {
+        self.animate_axis_to(ScrollDirection::Vertical, to as f32, animation);
+    }
+
+    /// Whether an animation or a kinetic scroll is running.
+    pub fn is_animating(&self) -> bool {
+        self.animation_x.read().is_some() || self.animation_y.read().is_some()
+    }
+
+    /// Stop the running animations and kinetic scrolls, leaving the content where it currently is.
+    pub fn stop_animations(&mut self) {
+        self.stop_animation(ScrollDirection::Horizontal);
+        self.stop_animation(ScrollDirection::Vertical);
+    }
+
+    fn stop_animation(&mut self, direction: ScrollDirection) {
+        let mut animation = self.animation(direction);
+        if animation.peek().is_some() {
+            if let Some(task) = animation.write().take() {
+                task.cancel();
+            }
+        }
+    }
+
+    fn request(&mut self, request: ScrollRequest) {
+        self.requests.push(request);
         let schedule = schedule_update_any();
         for scope_id in self.requests_subscribers.read().iter() {
             schedule(*scope_id);
         }
     }
+
+    pub(crate) fn axis(&self, direction: ScrollDirection) -> Signal<i32> {
+        match direction {
+            ScrollDirection::Horizontal => self.x,
+            ScrollDirection::Vertical => self.y,
+        }
+    }
+
+    pub(crate) fn overscroll(&self, direction: ScrollDirection) -> Signal<f32> {
+        match direction {
+            ScrollDirection::Horizontal => self.overscroll_x,
+            ScrollDirection::Vertical => self.overscroll_y,
+        }
+    }
+
+    fn animation(&self, direction: ScrollDirection) -> Signal<Option<Task>> {
+        match direction {
+            ScrollDirection::Horizontal => self.animation_x,
+            ScrollDirection::Vertical => self.animation_y,
+        }
+    }
+
+    /// Inner and viewport sizes of the scroll view in the given direction.
+    pub(crate) fn sizes(&self, direction: ScrollDirection) -> (f32, f32) {
+        let layout = self.layout.peek();
+        match direction {
+            ScrollDirection::Horizontal => (layout.inner.width, layout.area.width()),
+            ScrollDirection::Vertical => (layout.inner.height, layout.area.height()),
+        }
+    }
+
+    /// Limit the given position to the bounds of the scroll view, if its layout is known.
+    fn corrected(&self, direction: ScrollDirection, position: f32) -> f32 {
+        let (inner_size, viewport_size) = self.sizes(direction);
+        if inner_size == 0. {
+            position
+        } else {
+            get_corrected_scroll_position(inner_size, viewport_size, position)
+        }
+    }
+
+    /// Position of the content, including the overscroll.
+    fn visual_position(&self, direction: ScrollDirection) -> f32 {
+        self.corrected(direction, *self.axis(direction).peek() as f32)
+            + *self.overscroll(direction).peek()
+    }
+
+    fn set_axis(&mut self, direction: ScrollDirection, to: i32) {
+        self.stop_animation(direction);
+        self.axis(direction).set(to);
+        if *self.overscroll(direction).peek() != 0. {
+            self.overscroll(direction).set(0.);
+        }
+    }
+
+    fn animate_axis_to(&mut self, direction: ScrollDirection, to: f32, animation: ScrollAnimation) {
+        let from = self.visual_position(direction);
+        let to = self.corrected(direction, to);
+        self.run_animation(direction, move |elapsed| {
+            let index = elapsed.as_millis();
+            ScrollFrame {
+                position: apply_value(
+                    from,
+                    to,
+                    index,
+                    animation.duration,
+                    animation.ease,
+                    animation.function,
+                ),
+                overscroll: 0.,
+                finished: index >= animation.duration.as_millis(),
+            }
+        });
+    }
+
+    /// Drive the given axis with `frame` on every frame, until it is finished.
+    /// The elapsed time is corrected by the [`AnimationClock`].
+    pub(crate) fn run_animation(
+        &mut self,
+        direction: ScrollDirection,
+        mut frame: impl FnMut(Duration) -> ScrollFrame + 'static,
+    ) {
+        self.stop_animation(direction);
+
+        let mut task = self.animation(direction);
+        let mut position = self.axis(direction);
+        let mut overscroll = self.overscroll(direction);
+        let platform = UsePlatform::current();
+        let animation_clock = consume_context::<AnimationClock>();
+        let mut ticker = platform.new_ticker();
+
+        let animation_task = spawn(async move {
+            platform.request_animation_frame();
+
+            let mut elapsed = Duration::ZERO;
+            let mut prev_frame = Instant::now();
+
+            loop {
+                // Wait for the event loop to tick
+                ticker.tick().await;
+
+                elapsed += animation_clock.correct_elapsed_duration(prev_frame.elapsed());
+                prev_frame = Instant::now();
+
+                let ScrollFrame {
+                    position: new_position,
+                    overscroll: new_overscroll,
+                    finished,
+                } = frame(elapsed);
+
+                // Its okay to stop if the controller has been dropped
+                let Ok(mut scroll_position) = position.try_write() else {
+                    break;
+                };
+                *scroll_position = new_position.round() as i32;
+                drop(scroll_position);
+                overscroll.set(new_overscroll);
+
+                if finished {
+                    break;
+                }
+
+                platform.request_animation_frame();
+            }
+
+            task.write().take();
+        });
+
+        task.set(Some(animation_task));
+    }
+
+    /// Scroll the minimum so that the given area is visible, returns how much the content moved.
+    fn reveal(&mut self, area: Area, animation: Option<ScrollAnimation>) -> (f32, f32) {
+        let viewport = self.layout.peek().area;
+        let mut delta = (0., 0.);
+
+        for (direction, start, end, viewport_start, viewport_end) in [
+            (
+                ScrollDirection::Horizontal,
+                area.min_x(),
+                area.max_x(),
+                viewport.min_x(),
+                viewport.max_x(),
+            ),
+            (
+                ScrollDirection::Vertical,
+                area.min_y(),
+                area.max_y(),
+                viewport.min_y(),
+                viewport.max_y(),
+            ),
+        ] {
+            // Areas bigger than the viewport are aligned to its start
+            let offset = if start < viewport_start || end - start > viewport_end - viewport_start {
+                viewport_start - start
+            } else if end > viewport_end {
+                viewport_end - end
+            } else {
+                continue;
+            };
+
+            let current = self.corrected(direction, *self.axis(direction).peek() as f32);
+            let target = self.corrected(direction, current + offset);
+
+            match animation {
+                Some(animation) => self.animate_axis_to(direction, target, animation),
+                None => self.set_axis(direction, target as i32),
+            }
+
+            match direction {
+                ScrollDirection::Horizontal => delta.0 = target - current,
+                ScrollDirection::Vertical => delta.1 = target - current,
+            }
+        }
+
+        delta
+    }
 }
 
 pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollController {
@@ -189,12 +591,14 @@ pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollContr
                 ScrollRequest {
                     position: config.default_vertical_position,
                     direction: ScrollDirection::Vertical,
+                    animation: None,
                     init: true,
                     applied_by: HashSet::default(),
                 },
                 ScrollRequest {
                     position: config.default_horizontal_position,
                     direction: ScrollDirection::Horizontal,
+                    animation: None,
                     init: true,
                     applied_by: HashSet::default(),
                 },
@@ -205,6 +609,8 @@ pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollContr
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
@@ -284,4 +690,69 @@ mod test {
         assert!(content.get(3).is_visible());
         assert!(content.get(4).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn animated_scroll_view() {
+        fn animated_scroll_view_app() -> Element {
+            let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+
+            rsx!(
+                ScrollView {
+                    scroll_controller,
+                    Button {
+                        onpress: move |_| {
+                            scroll_controller.animate_to(
+                                ScrollPosition::End,
+                                ScrollDirection::Vertical,
+                                ScrollAnimation::default(),
+                            );
+                        },
+                        label {
+                            "Scroll Down"
+                        }
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            animated_scroll_view_app,
+            TestingConfig::<()> {
+                virtual_clock: true,
+                ..TestingConfig::default()
+            },
+        );
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.click_cursor((15., 15.)).await;
+        utils.advance(Duration::from_millis(32)).await;
+
+        // The scroll has only started
+        assert!(content.get(1).is_visible());
+        assert!(!content.get(4).is_visible());
+
+        utils.run_until_idle().await;
+
+        // And eventually reaches the end
+        assert!(!content.get(1).is_visible());
+        assert!(content.get(4).is_visible());
+    }
 }
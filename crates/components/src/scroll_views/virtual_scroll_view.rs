@@ -16,6 +16,7 @@ use freya_hooks::{
     use_applied_theme,
     use_focus,
     use_node,
+    use_node_signal,
     ScrollBarThemeWith,
 };
 
@@ -56,7 +57,12 @@ pub struct VirtualScrollViewProps<
     /// Quantity of items in the VirtualScrollView.
     pub length: usize,
     /// Size of the items, height for vertical direction and width for horizontal.
+    /// When `dynamic_item_size` is enabled this is only the estimated size of the items not measured yet.
     pub item_size: f32,
+    /// Measure the real size of every rendered item instead of assuming all of them have the same size.
+    /// Default is `false`.
+    #[props(default = false)]
+    pub dynamic_item_size: bool,
     /// The item builder function.
     pub builder: Builder,
     /// The values for the item builder function.
@@ -94,6 +100,7 @@ impl<
             && self.padding == other.padding
             && self.length == other.length
             && self.item_size == other.item_size
+            && self.dynamic_item_size == other.dynamic_item_size
             && self.direction == other.direction
             && self.show_scrollbar == other.show_scrollbar
             && self.scroll_with_arrows == other.scroll_with_arrows
@@ -122,6 +129,192 @@ fn get_render_range(
     render_index_start as usize..(render_index_end as usize)
 }
 
+/// Measured sizes of the items of a [`VirtualScrollView`] with `dynamic_item_size`.
+/// Items not measured yet fallback to the estimated size.
+///
+/// The measured sizes are also kept in a Fenwick tree, so the position of any item can be found without summing all the previous ones.
+#[derive(Default)]
+struct ItemSizes {
+    sizes: Vec<Option<f32>>,
+    /// Fenwick tree of the sum and the number of the measured sizes.
+    tree: Vec<(f32, usize)>,
+}
+
+impl ItemSizes {
+    fn get(&self, index: usize, estimated_size: f32) -> f32 {
+        self.sizes
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(estimated_size)
+    }
+
+    fn set(&mut self, index: usize, size: f32) {
+        if self.sizes.len() <= index {
+            self.sizes.resize((index + 1).next_power_of_two(), None);
+            self.rebuild();
+        }
+
+        let (size_difference, count_difference) = match self.sizes[index].replace(size) {
+            Some(previous_size) => (size - previous_size, 0),
+            None => (size, 1),
+        };
+
+        let mut i = index + 1;
+        while i <= self.tree.len() {
+            let (sum, count) = &mut self.tree[i - 1];
+            *sum += size_difference;
+            *count += count_difference;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Check if any item past the given length was measured.
+    fn is_measured_past(&self, length: usize) -> bool {
+        self.sizes.iter().skip(length).any(Option::is_some)
+    }
+
+    /// Check if no item was measured.
+    fn is_empty(&self) -> bool {
+        self.sizes.iter().all(Option::is_none)
+    }
+
+    /// Forget the sizes of the items past the given length.
+    fn truncate(&mut self, length: usize) {
+        if self.sizes.len() > length {
+            self.sizes.truncate(length);
+            self.rebuild();
+        }
+    }
+
+    /// Forget all the measured sizes.
+    fn clear(&mut self) {
+        self.sizes.clear();
+        self.tree.clear();
+    }
+
+    fn rebuild(&mut self) {
+        self.tree = self
+            .sizes
+            .iter()
+            .map(|size| size.map_or((0., 0), |size| (size, 1)))
+            .collect();
+
+        for i in 1..=self.tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= self.tree.len() {
+                let (sum, count) = self.tree[i - 1];
+                self.tree[parent - 1].0 += sum;
+                self.tree[parent - 1].1 += count;
+            }
+        }
+    }
+
+    /// Position of the item at the given index.
+    fn offset(&self, index: usize, estimated_size: f32) -> f32 {
+        let mut i = index.min(self.tree.len());
+        let (mut measured_sum, mut measured_count) = (0., 0);
+        while i > 0 {
+            let (sum, count) = self.tree[i - 1];
+            measured_sum += sum;
+            measured_count += count;
+            i &= i - 1;
+        }
+
+        measured_sum + (index - measured_count) as f32 * estimated_size
+    }
+
+    /// Get the first index up to `item_length` whose position doesn't match the predicate,
+    /// or `item_length + 1` if all of them do.
+    fn partition_point(
+        &self,
+        estimated_size: f32,
+        item_length: usize,
+        predicate: impl Fn(f32) -> bool,
+    ) -> usize {
+        let (mut start, mut end) = (0, item_length + 1);
+        while start < end {
+            let middle = start + (end - start) / 2;
+            if predicate(self.offset(middle, estimated_size)) {
+                start = middle + 1;
+            } else {
+                end = middle;
+            }
+        }
+        start
+    }
+
+    /// Get the range of visible items and the position of the first one.
+    fn render_range(
+        &self,
+        viewport_size: f32,
+        scroll_position: f32,
+        estimated_size: f32,
+        item_length: usize,
+    ) -> (Range<usize>, f32) {
+        let render_index_start = self
+            .partition_point(estimated_size, item_length, |offset| {
+                offset <= -scroll_position
+            })
+            .saturating_sub(1);
+
+        let render_index_end = self
+            .partition_point(estimated_size, item_length, |offset| {
+                offset < -scroll_position + viewport_size
+            })
+            .clamp(render_index_start, item_length);
+
+        (
+            render_index_start..render_index_end,
+            self.offset(render_index_start, estimated_size),
+        )
+    }
+}
+
+/// Reports the size of an item of a [`VirtualScrollView`] with `dynamic_item_size`.
+#[allow(non_snake_case)]
+#[component]
+fn VirtualItem(
+    index: usize,
+    is_vertical: bool,
+    onmeasure: EventHandler<(usize, f32)>,
+    children: Element,
+) -> Element {
+    let (reference, layout) = use_node_signal();
+
+    use_effect(use_reactive(
+        &(index, is_vertical),
+        move |(index, is_vertical)| {
+            let layout = layout.read();
+            let size = if is_vertical {
+                layout.area.height()
+            } else {
+                layout.area.width()
+            };
+
+            // Not measured yet
+            if size > 0. {
+                onmeasure.call((index, size));
+            }
+        },
+    ));
+
+    let (width, height) = if is_vertical {
+        ("100%", "auto")
+    } else {
+        ("auto", "100%")
+    };
+
+    rsx!(
+        rect {
+            reference,
+            width,
+            height,
+            {children}
+        }
+    )
+}
+
 /// One-direction scrollable area that dynamically builds and renders items based in their size and current available size,
 /// this is intended for apps using large sets of data that need good performance.
 ///
@@ -157,6 +350,30 @@ fn get_render_range(
 /// # }, (250., 250.).into(), "./images/gallery_virtual_scroll_view.png");
 /// ```
 ///
+/// # With items of different sizes
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(VirtualScrollView {
+///         length: 35,
+///         item_size: 20.0,
+///         dynamic_item_size: true,
+///         direction: "vertical",
+///         builder: move |i, _other_args: &Option<()>| {
+///             let height = if i % 2 == 0 { 20 } else { 40 };
+///             rsx! {
+///                 label {
+///                     key: "{i}",
+///                     height: "{height}",
+///                     "Number {i}"
+///                 }
+///             }
+///         }
+///     })
+/// }
+/// ```
+///
 /// # With a Scroll Controller
 ///
 /// ```no_run
@@ -175,7 +392,7 @@ fn get_render_range(
 ///                     key: "{i}",
 ///                     height: "20",
 ///                     onclick: move |_| {
-///                          scroll_controller.scroll_to(ScrollPosition::Start, ScrollDirection::Vertical);
+///                          scroll_controller.scroll_to(ScrollPosition::Item(20), ScrollDirection::Vertical);
 ///                     },
 ///                     "Number {i}"
 ///                 }
@@ -202,6 +419,7 @@ pub fn VirtualScrollView<
         scrollbar_theme,
         length,
         item_size,
+        dynamic_item_size,
         builder,
         builder_args,
         direction,
@@ -219,15 +437,42 @@ pub fn VirtualScrollView<
         scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
     let (node_ref, size) = use_node();
+    let mut item_sizes = use_signal(ItemSizes::default);
+
+    // Forget the sizes of the items that no longer exist, or of all of them if the items changed
+    use_memo(use_reactive(&length, move |length| {
+        if item_sizes.peek().is_measured_past(length) {
+            item_sizes.write().truncate(length);
+        }
+    }))();
+    use_memo(use_reactive(&builder_args, move |_| {
+        if !item_sizes.peek().is_empty() {
+            item_sizes.write().clear();
+        }
+    }))();
+    // Fraction of the scroll corrections not applied yet, as the scroll position is in whole pixels
+    let mut pending_correction = use_signal(|| 0f32);
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
 
+    let item_offset = move |index: usize| {
+        if dynamic_item_size {
+            item_sizes.read().offset(index, item_size)
+        } else {
+            item_size * index as f32
+        }
+    };
+
+    let items_length = item_offset(length);
+
     let (inner_width, inner_height) = match direction.as_str() {
-        "vertical" => (size.inner.width, item_size * length as f32),
-        _ => (item_size * length as f32, size.inner.height),
+        "vertical" => (size.inner.width, items_length),
+        _ => (items_length, size.inner.height),
     };
 
-    scroll_controller.use_apply(inner_width, inner_height);
+    scroll_controller.use_apply_with_items(inner_width, inner_height, |index| {
+        Some(item_offset(index.min(length)))
+    });
 
     let vertical_scrollbar_is_visible =
         is_scrollbar_visible(show_scrollbar, inner_height, size.area.height());
@@ -401,11 +646,49 @@ pub fn VirtualScrollView<
     };
 
     // Calculate from what to what items must be rendered
-    let render_range = get_render_range(viewport_size, scroll_position, item_size, length as f32);
+    let (render_range, render_offset) = if dynamic_item_size {
+        item_sizes
+            .read()
+            .render_range(viewport_size, scroll_position, item_size, length)
+    } else {
+        let render_range =
+            get_render_range(viewport_size, scroll_position, item_size, length as f32);
+        let render_offset = render_range.start as f32 * item_size;
+        (render_range, render_offset)
+    };
+
+    // Items before this one can't change their size without moving the visible items
+    let anchor_index = if render_offset < -scroll_position {
+        render_range.start + 1
+    } else {
+        render_range.start
+    };
+    let is_vertical = direction == "vertical";
+
+    // Cache the measured size and keep the visible items in place
+    let onmeasure = move |(index, size): (usize, f32)| {
+        let previous_size = item_sizes.peek().get(index, item_size);
+        if previous_size == size {
+            return;
+        }
+
+        item_sizes.write().set(index, size);
+
+        if index < anchor_index {
+            let correction = *pending_correction.peek() + size - previous_size;
+            let difference = correction.round();
+            pending_correction.set(correction - difference);
+            if is_vertical {
+                *scrolled_y.write() -= difference as i32;
+            } else {
+                *scrolled_x.write() -= difference as i32;
+            }
+        }
+    };
 
     let children = if cache_elements {
         let children = use_memo(use_reactive(
-            &(render_range, builder_args),
+            &(render_range.clone(), builder_args),
             move |(render_range, builder_args)| {
                 render_range
                     .clone()
@@ -413,9 +696,37 @@ pub fn VirtualScrollView<
                     .collect::<Vec<Element>>()
             },
         ));
-        rsx!({ children.read().iter() })
+        if dynamic_item_size {
+            let children = render_range.clone().zip(children.read().clone());
+            rsx!({
+                children.map(|(index, child)| {
+                    rsx!(VirtualItem {
+                        key: "{index}",
+                        index,
+                        is_vertical,
+                        onmeasure,
+                        {child}
+                    })
+                })
+            })
+        } else {
+            rsx!({ children.read().iter() })
+        }
     } else {
-        let children = render_range.map(|i| (builder)(i, &builder_args));
+        let children = render_range.clone().map(|i| {
+            let child = (builder)(i, &builder_args);
+            if dynamic_item_size {
+                rsx!(VirtualItem {
+                    key: "{i}",
+                    index: i,
+                    is_vertical,
+                    onmeasure,
+                    {child}
+                })
+            } else {
+                child
+            }
+        });
         rsx!({ children })
     };
 
@@ -432,14 +743,12 @@ pub fn VirtualScrollView<
 
     let (offset_x, offset_y) = match direction.as_str() {
         "vertical" => {
-            let offset_y_min = (-corrected_scrolled_y / item_size).floor() * item_size;
-            let offset_y = -(-corrected_scrolled_y - offset_y_min);
+            let offset_y = -(-corrected_scrolled_y - render_offset);
 
             (corrected_scrolled_x, offset_y)
         }
         _ => {
-            let offset_x_min = (-corrected_scrolled_x / item_size).floor() * item_size;
-            let offset_x = -(-corrected_scrolled_x - offset_x_min);
+            let offset_x = -(-corrected_scrolled_x - render_offset);
 
             (offset_x, corrected_scrolled_y)
         }
@@ -561,6 +870,7 @@ mod test {
             name: WheelEventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            phase: TouchPhase::Moved,
         });
 
         utils.wait_for_update().await;
@@ -704,4 +1014,167 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_dynamic_item_size() {
+        fn virtual_scroll_view_dynamic_item_size_app() -> Element {
+            let values = use_signal(|| ["Hello, World!"].repeat(30));
+
+            rsx!(VirtualScrollView {
+                length: values.read().len(),
+                item_size: 50.0,
+                dynamic_item_size: true,
+                direction: "vertical",
+                builder: move |index, _: &Option<()>| {
+                    let value = values.read()[index];
+                    let height = if index % 2 == 0 { 50 } else { 100 };
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "{height}",
+                            "{index} {value}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_dynamic_item_size_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Once measured, only the items from 0 to 6 fit in the 500 pixels: 4 * 50 + 3 * 100 = 500.
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+
+        for (n, i) in (0..7).enumerate() {
+            let child = content.get(n);
+            assert_eq!(
+                child.get(0).get(0).text(),
+                Some(format!("{i} Hello, World!").as_str())
+            );
+        }
+
+        utils.push_event(TestEvent::Wheel {
+            name: WheelEventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            phase: TouchPhase::Moved,
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The items from 0 to 3 sum 300 pixels, so the visible items go from 4 to 10.
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+
+        for (n, i) in (4..11).enumerate() {
+            let child = content.get(n);
+            assert_eq!(
+                child.get(0).get(0).text(),
+                Some(format!("{i} Hello, World!").as_str())
+            );
+        }
+    }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_scroll_to_item() {
+        fn virtual_scroll_view_scroll_to_item_app() -> Element {
+            let values = use_signal(|| ["Hello, World!"].repeat(30));
+            let scroll_controller = use_scroll_controller(|| ScrollConfig {
+                default_vertical_position: ScrollPosition::Item(10),
+                ..Default::default()
+            });
+
+            rsx!(VirtualScrollView {
+                scroll_controller,
+                length: values.read().len(),
+                item_size: 50.0,
+                dynamic_item_size: true,
+                direction: "vertical",
+                builder: move |index, _: &Option<()>| {
+                    let value = values.read()[index];
+                    let height = if index % 2 == 0 { 50 } else { 100 };
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "{height}",
+                            "{index} {value}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_scroll_to_item_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The first visible item is the 10th.
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(
+            content.get(0).get(0).get(0).text(),
+            Some("10 Hello, World!")
+        );
+        assert_eq!(content.get(0).area().unwrap().min_y(), 0.);
+
+        utils.push_event(TestEvent::Wheel {
+            name: WheelEventName::Wheel,
+            scroll: (0., 30.).into(),
+            cursor: (5., 5.).into(),
+            phase: TouchPhase::Moved,
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The 9th item is now measured as 100 pixels instead of the estimated 50,
+        // but the 10th item stays 30 pixels below the top.
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("9 Hello, World!"));
+        assert_eq!(
+            content.get(1).get(0).get(0).text(),
+            Some("10 Hello, World!")
+        );
+        assert_eq!(content.get(1).area().unwrap().min_y(), 30.);
+    }
+
+    #[test]
+    pub fn item_sizes() {
+        use super::ItemSizes;
+
+        let mut item_sizes = ItemSizes::default();
+        item_sizes.set(1, 50.);
+        item_sizes.set(3, 10.);
+
+        // Unmeasured items use the estimated size
+        assert_eq!(item_sizes.offset(0, 20.), 0.);
+        assert_eq!(item_sizes.offset(2, 20.), 70.);
+        assert_eq!(item_sizes.offset(4, 20.), 100.);
+        assert_eq!(item_sizes.offset(10, 20.), 220.);
+
+        // Measuring an item again replaces its size
+        item_sizes.set(1, 30.);
+        assert_eq!(item_sizes.offset(4, 20.), 80.);
+
+        // Items 2 to 4 are visible between 60 and 100
+        assert_eq!(item_sizes.render_range(40., -60., 20., 10), (2..5, 50.));
+
+        // Forgotten items use the estimated size again
+        assert!(item_sizes.is_measured_past(2));
+        item_sizes.truncate(2);
+        assert!(!item_sizes.is_measured_past(2));
+        assert_eq!(item_sizes.offset(4, 20.), 90.);
+
+        item_sizes.clear();
+        assert!(item_sizes.is_empty());
+    }
 }
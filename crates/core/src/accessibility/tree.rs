@@ -15,6 +15,9 @@ use accesskit::{
     TreeUpdate,
 };
 use freya_elements::{
+    FocusData,
+    FocusOrigin,
+    TouchPhase,
     WheelData,
     WheelSource,
 };
@@ -25,7 +28,10 @@ use freya_native_core::{
     tags::TagName,
     NodeId,
 };
-use ragnarok::ProcessedEvents;
+use ragnarok::{
+    NameOfEvent,
+    ProcessedEvents,
+};
 use rustc_hash::{
     FxHashMap,
     FxHashSet,
@@ -61,6 +67,7 @@ use crate::{
         TextAlign,
         TextDecoration,
         TextDecorationStyle,
+        TransformOperation,
     },
 };
 
@@ -75,15 +82,35 @@ pub enum AccessibilityFocusStrategy {
 #[derive(Default)]
 pub struct AccessibilityDirtyNodes {
     pub requested_focus: Option<AccessibilityFocusStrategy>,
+    /// What caused the requested focus.
+    pub requested_focus_origin: FocusOrigin,
+    /// Whether the last processed events were pointer presses or releases.
+    pub pointer_interaction: bool,
     pub added_or_updated: FxHashSet<NodeId>,
     pub removed: FxHashMap<NodeId, NodeId>,
 }
 
 impl AccessibilityDirtyNodes {
     pub fn request_focus(&mut self, strategy: AccessibilityFocusStrategy) {
+        self.requested_focus_origin = match strategy {
+            AccessibilityFocusStrategy::Forward | AccessibilityFocusStrategy::Backward => {
+                FocusOrigin::Keyboard
+            }
+            // Nodes focused by the handlers of a click or press are focused with the pointer
+            AccessibilityFocusStrategy::Node(_) if self.pointer_interaction => FocusOrigin::Pointer,
+            AccessibilityFocusStrategy::Node(_) => FocusOrigin::Programmatic,
+        };
         self.requested_focus = Some(strategy);
     }
 
+    /// Track whether the given events are pointer presses or releases,
+    /// so the focus requested by their handlers is attributed to the pointer.
+    pub fn track_pointer_interaction(&mut self, events: &[DomEvent]) {
+        self.pointer_interaction = events
+            .iter()
+            .any(|event| event.name.is_pressed() || event.name.is_released());
+    }
+
     pub fn add_or_update(&mut self, node_id: NodeId) {
         self.added_or_updated.insert(node_id);
     }
@@ -94,6 +121,7 @@ impl AccessibilityDirtyNodes {
 
     pub fn clear(&mut self) {
         self.requested_focus.take();
+        self.requested_focus_origin = FocusOrigin::default();
         self.added_or_updated.clear();
         self.removed.clear();
     }
@@ -195,6 +223,8 @@ impl AccessibilityTree {
         event_emitter: &EventEmitter,
     ) -> (TreeUpdate, NodeId) {
         let requested_focus = dirty_nodes.requested_focus.take();
+        let focus_origin = std::mem::take(&mut dirty_nodes.requested_focus_origin);
+        let previous_focused_id = self.focused_id;
         let removed_ids = dirty_nodes.removed.drain().collect::<FxHashMap<_, _>>();
         let mut added_or_updated_ids = dirty_nodes
             .added_or_updated
@@ -264,6 +294,10 @@ impl AccessibilityTree {
 
         let node_id = self.map.get(&self.focused_id).cloned().unwrap();
 
+        if previous_focused_id != self.focused_id {
+            self.emit_focus_events(previous_focused_id, focus_origin, event_emitter);
+        }
+
         if has_request_focus {
             self.scroll_to(node_id, rdom, layout, event_emitter);
         }
@@ -278,6 +312,51 @@ impl AccessibilityTree {
         )
     }
 
+    /// Send the blur and focus events to the previously and currently focused nodes.
+    /// The root node is skipped as it is not a real focusable element.
+    fn emit_focus_events(
+        &self,
+        previous_focused_id: AccessibilityId,
+        origin: FocusOrigin,
+        event_emitter: &EventEmitter,
+    ) {
+        let mut emmitable_events = Vec::new();
+
+        let mut push_event = |node_id: NodeId, name: EventName, bubbles: bool| {
+            emmitable_events.push(DomEvent {
+                name,
+                source_event: name,
+                node_id,
+                data: DomEventData::Focus(FocusData::new(origin)),
+                bubbles,
+            });
+        };
+
+        // The previous node might have been removed
+        if previous_focused_id != ACCESSIBILITY_ROOT_ID {
+            if let Some(node_id) = self.map.get(&previous_focused_id) {
+                push_event(*node_id, EventName::Blur, false);
+                push_event(*node_id, EventName::FocusOut, true);
+            }
+        }
+
+        if self.focused_id != ACCESSIBILITY_ROOT_ID {
+            if let Some(node_id) = self.map.get(&self.focused_id) {
+                push_event(*node_id, EventName::Focus, false);
+                push_event(*node_id, EventName::FocusIn, true);
+            }
+        }
+
+        if !emmitable_events.is_empty() {
+            event_emitter
+                .send(ProcessedEvents {
+                    emmitable_events,
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+    }
+
     /// Send the necessary wheel events to scroll views so that the given focused [NodeId] is visible on screen.
     fn scroll_to(
         &self,
@@ -329,6 +408,7 @@ impl AccessibilityTree {
                         WheelSource::Custom,
                         delta_x as f64,
                         delta_y as f64,
+                        TouchPhase::Moved,
                     )),
                     bubbles: false,
                 });
@@ -470,10 +550,13 @@ impl AccessibilityTree {
         }
 
         // Rotation transform
-        if let Some((_, rotation)) = transform_state
-            .rotations
+        if let Some(rotation) = transform_state
+            .transforms
             .iter()
-            .find(|(id, _)| id == &node_ref.id())
+            .find_map(|(id, operation)| match operation {
+                TransformOperation::Rotate(rotation) if *id == node_ref.id() => Some(rotation),
+                _ => None,
+            })
         {
             let rotation = rotation.to_radians() as f64;
             let (s, c) = rotation.sin_cos();
@@ -11,6 +11,10 @@ use std::{
 
 use tracing::info;
 
+/// Controls the speed of the animations.
+///
+/// Animations measure the elapsed time with the Tokio clock, so when it is paused
+/// (e.g. with the virtual clock of `freya-testing`) they only advance as much as the clock does.
 #[derive(Clone)]
 pub struct AnimationClock(Arc<AtomicU32>); // Stores f32 as bits
 
@@ -66,12 +66,16 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             cross_alignment: layout.cross_alignment,
             offset_x: layout.offset_x,
             offset_y: layout.offset_y,
-            has_layout_references: layout.node_ref.is_some(),
+            has_layout_references: layout.node_ref.is_some() || layout.has_layout_listeners,
             position: layout.position,
             content: layout.content,
             wrap_content: layout.wrap_content,
             contains_text,
             spacing: layout.spacing,
+            grid_columns: layout.grid_columns,
+            grid_rows: layout.grid_rows,
+            grid_areas: layout.grid_areas,
+            grid_placement: layout.grid_placement,
         };
 
         node.scale_if_needed(self.scale_factor);
@@ -15,14 +15,18 @@ use std::{
 
 use dioxus_core::VirtualDom;
 use freya_native_core::{
+    attributes::AttributeName,
+    node::OwnedAttributeValue,
     prelude::{
         DioxusState,
         State,
     },
     real_dom::{
         NodeRef,
+        NodeTypeMut,
         RealDom,
     },
+    tree::TreeRef,
     NodeId,
     SendAnyMap,
 };
@@ -43,6 +47,7 @@ use crate::{
     custom_attributes::CustomAttributeValues,
     elements::ParagraphElement,
     event_loop_messages::TextGroupMeasurement,
+    events::LayoutObservers,
     layers::Layers,
     render::{
         CompositorCache,
@@ -51,6 +56,7 @@ use crate::{
     states::{
         AccessibilityState,
         CanvasState,
+        ClipState,
         CursorState,
         FontStyleState,
         ImageState,
@@ -143,6 +149,7 @@ pub struct FreyaDOM {
     accessibility_dirty_nodes: Arc<Mutex<AccessibilityDirtyNodes>>,
     accessibility_generator: Arc<AccessibilityGenerator>,
     images_cache: Arc<Mutex<ImagesCache>>,
+    layout_observers: Arc<Mutex<LayoutObservers>>,
     animation_clock: AnimationClock,
 }
 
@@ -155,6 +162,7 @@ impl Default for FreyaDOM {
             LayoutState::to_type_erased(),
             StyleState::to_type_erased(),
             TransformState::to_type_erased(),
+            ClipState::to_type_erased(),
             AccessibilityState::to_type_erased(),
             ViewportState::to_type_erased(),
             LayerState::to_type_erased(),
@@ -175,6 +183,7 @@ impl Default for FreyaDOM {
             accessibility_dirty_nodes: Arc::default(),
             accessibility_generator: Arc::default(),
             images_cache: Arc::default(),
+            layout_observers: Arc::default(),
             animation_clock: AnimationClock::default(),
         }
     }
@@ -217,6 +226,10 @@ impl FreyaDOM {
         self.images_cache.lock().unwrap()
     }
 
+    pub fn layout_observers(&self) -> MutexGuard<LayoutObservers> {
+        self.layout_observers.lock().unwrap()
+    }
+
     pub fn animation_clock(&self) -> &AnimationClock {
         &self.animation_clock
     }
@@ -254,7 +267,12 @@ impl FreyaDOM {
 
     /// Process the given mutations from the [`VirtualDOM`](dioxus_core::VirtualDom).
     pub fn render_mutations(&mut self, vdom: &mut VirtualDom, scale_factor: f32) -> (bool, bool) {
-        // Update the RealDOM
+        self.apply_mutations(vdom, scale_factor);
+        self.update_states()
+    }
+
+    /// Apply the given mutations from the [`VirtualDOM`](dioxus_core::VirtualDom) to the RealDOM, without updating the Nodes states.
+    pub fn apply_mutations(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         vdom.render_immediate(&mut MutationsWriter {
             native_writer: self
                 .dioxus_integration_state
@@ -269,8 +287,27 @@ impl FreyaDOM {
             accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
             images_cache: &mut self.images_cache.lock().unwrap(),
         });
+    }
 
-        // Update the Nodes states
+    /// Override the attribute of an element, e.g from the devtools.
+    /// The override lasts until the [`VirtualDOM`](dioxus_core::VirtualDom) changes this attribute again.
+    pub fn set_attribute(
+        &mut self,
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: String,
+    ) -> (bool, bool) {
+        if let Some(mut node) = self.rdom.get_mut(node_id) {
+            if let NodeTypeMut::Element(mut element) = node.node_type_mut() {
+                element.set_attribute(attribute, OwnedAttributeValue::Text(value));
+            }
+        }
+
+        self.update_states()
+    }
+
+    /// Update the Nodes states after changing the RealDOM.
+    pub fn update_states(&mut self) -> (bool, bool) {
         let mut ctx = SendAnyMap::new();
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
@@ -334,4 +371,28 @@ impl FreyaDOM {
             }
         }
     }
+
+    /// Get the area of the visible text cursor inside the given node, e.g the focused node.
+    pub fn cursor_area(&self, node_id: NodeId) -> Option<Area> {
+        let paragraphs = self.paragraphs.lock().unwrap();
+        let layout = self.layout();
+        let rdom = self.rdom();
+
+        paragraphs.values().flatten().find_map(|paragraph_id| {
+            // Only the paragraphs inside the given node
+            let tree = rdom.tree_ref();
+            let mut head = Some(*paragraph_id);
+            while let Some(id) = head {
+                if id == node_id {
+                    break;
+                }
+                head = tree.parent_id(id);
+            }
+            head?;
+
+            let node = rdom.get(*paragraph_id)?;
+            let layout_node = layout.get(*paragraph_id)?;
+            ParagraphElement::cursor_area(&node, layout_node)
+        })
+    }
 }
@@ -122,6 +122,48 @@ impl ParagraphElement {
             }
         }
     }
+
+    /// Get the area of the cursor of the paragraph, if it has any.
+    pub fn cursor_area(node: &DioxusNode, layout_node: &LayoutNode) -> Option<Area> {
+        let cursor_state = node.get::<CursorState>().unwrap();
+        let cursor_position = cursor_state.position? as usize;
+
+        let paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+
+        let area = layout_node.visible_area();
+        let y = area.min_y() + align_main_align_paragraph(node, &area, paragraph);
+
+        let cursor_rect = paragraph
+            .get_rects_for_range(
+                cursor_position..cursor_position + 1,
+                RectHeightStyle::Tight,
+                RectWidthStyle::Tight,
+            )
+            .first()
+            .map(|text_box| text_box.rect);
+
+        let (left, top, bottom) = if let Some(rect) = cursor_rect {
+            (rect.left, rect.top, rect.bottom)
+        } else {
+            // The cached paragraph has no room after the last character,
+            // so place the cursor at the end of the previous one
+            let rect = paragraph
+                .get_rects_for_range(
+                    cursor_position.saturating_sub(1)..cursor_position,
+                    RectHeightStyle::Tight,
+                    RectWidthStyle::Tight,
+                )
+                .first()
+                .map(|text_box| text_box.rect);
+            rect.map(|rect| (rect.right, rect.top, rect.bottom))
+                .unwrap_or((0., 0., paragraph.height()))
+        };
+
+        Some(Area::new(
+            (area.min_x() + left, y + top).into(),
+            (1., bottom - top).into(),
+        ))
+    }
 }
 
 impl ElementUtils for ParagraphElement {
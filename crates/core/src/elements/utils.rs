@@ -2,6 +2,7 @@ use freya_engine::prelude::{
     Canvas,
     FontCollection,
     FontMgr,
+    Rect,
 };
 use freya_native_core::{
     tags::TagName,
@@ -13,6 +14,8 @@ use torin::{
         AreaModel,
         CursorPoint,
         LayoutNode,
+        Point2D,
+        Size2D,
     },
     torin::Torin,
 };
@@ -125,23 +128,28 @@ impl ElementWithUtils {
         let mut drawing_area =
             self.element_drawing_area(layout_node, node_ref, scale_factor, node_style);
 
-        // Apply scale effect
-        for (id, scale_x, scale_y) in &transform_state.scales {
-            let layout_node = layout.get(*id).unwrap();
-            let center = layout_node.area.center();
-            drawing_area = drawing_area.translate(-center.to_vector());
-            drawing_area = drawing_area.scale(*scale_x, *scale_y);
-            drawing_area = drawing_area.translate(center.to_vector());
-            drawing_area = drawing_area.inflate(1.0, 1.0);
+        // Filters like blurs or drop shadows can draw outside of the element
+        let filters_outset = transform_state.filters_outset * scale_factor;
+        if filters_outset > 0. {
+            drawing_area = drawing_area.inflate(filters_outset, filters_outset);
         }
 
-        if !transform_state.rotations.is_empty() {
-            // Apply rotation effect
-            let area = layout_node.visible_area();
-            drawing_area.max_area_when_rotated(area.center())
-        } else {
-            drawing_area
+        // Apply the transformations
+        if let Some(matrix) = transform_state.matrix(layout, scale_factor) {
+            let (rect, _) = matrix.map_rect(Rect::new(
+                drawing_area.min_x(),
+                drawing_area.min_y(),
+                drawing_area.max_x(),
+                drawing_area.max_y(),
+            ));
+            drawing_area = Area::new(
+                Point2D::new(rect.left, rect.top),
+                Size2D::new(rect.width(), rect.height()),
+            )
+            .inflate(1.0, 1.0);
         }
+
+        drawing_area
     }
 
     /// Just like [Self::get_drawing_area] but only if all the viewports allow the element to be visible.
@@ -188,10 +196,10 @@ impl ElementWithUtils {
     ) -> bool {
         let element_check = self.element_needs_cached_area(node_ref, style_state);
 
-        let rotate_effect = !transform_state.rotations.is_empty();
-        let scales_effect = !transform_state.scales.is_empty();
+        let transform_effect =
+            !transform_state.transforms.is_empty() || !transform_state.filtered.is_empty();
 
-        element_check || rotate_effect || scales_effect
+        element_check || transform_effect
     }
 
     /// Some elements such as `rect` might always need to rerender as Skia doesnt work well with clipped canvases with applied blur.
@@ -1,4 +1,9 @@
 use cursor_icon::CursorIcon;
+use freya_elements::events::DragPayload;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
 use torin::prelude::{
     Area,
     CursorPoint,
@@ -38,6 +43,18 @@ pub enum EventLoopMessageAction {
     FocusAccessibilityNode(AccessibilityFocusStrategy),
     /// Close the window
     CloseWindow,
+    /// Start a drag session carrying the given payload.
+    /// The given area of the window, if any, is used as the drag preview when the cursor is over other windows.
+    StartDrag {
+        payload: DragPayload,
+        preview: Option<Area>,
+    },
+    /// Override the attribute of an element until the VirtualDOM changes it again.
+    SetAttribute {
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: String,
+    },
     /// Raw platform event, this are low level events.
     PlatformEvent(PlatformEvent),
     /// Accessibility Window Event
@@ -6,11 +6,17 @@ use std::{
 use freya_elements::{
     events::{
         pointer::PointerType,
+        CompositionData,
+        DragData,
         ErasedEventData,
         FileData,
+        FocusData,
+        GestureData,
         KeyboardData,
+        LayoutData,
         MouseData,
         PointerData,
+        ScrollData,
         TouchData,
         WheelData,
     },
@@ -70,6 +76,7 @@ impl DomEvent {
         name: EventName,
         platform_event: PlatformEvent,
         node_area: Option<Area>,
+        click_count: u32,
         scale_factor: f64,
     ) -> Self {
         let bubbles = name.does_bubble();
@@ -94,12 +101,14 @@ impl DomEvent {
                         PointerType::Mouse {
                             trigger_button: button,
                         },
+                        click_count,
                     ))
                 } else {
                     DomEventData::Mouse(MouseData::new(
                         screen_coordinates,
                         (element_x, element_y).into(),
                         button,
+                        click_count,
                     ))
                 };
 
@@ -114,14 +123,45 @@ impl DomEvent {
             PlatformEvent::Wheel {
                 name: platform_event_name,
                 scroll,
+                phase,
                 ..
             } => Self {
                 node_id,
                 name,
                 source_event: platform_event_name.into(),
-                data: DomEventData::Wheel(WheelData::new(WheelSource::Device, scroll.x, scroll.y)),
+                data: DomEventData::Wheel(WheelData::new(
+                    WheelSource::Device,
+                    scroll.x,
+                    scroll.y,
+                    phase,
+                )),
                 bubbles,
             },
+            PlatformEvent::Gesture {
+                name: platform_event_name,
+                cursor,
+                delta,
+                phase,
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: DomEventData::Gesture(GestureData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        delta,
+                        phase,
+                    )),
+                    bubbles,
+                }
+            }
             PlatformEvent::Keyboard {
                 name: platform_event_name,
                 ref key,
@@ -136,6 +176,17 @@ impl DomEvent {
                 data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
                 bubbles,
             },
+            PlatformEvent::Composition {
+                name: platform_event_name,
+                text,
+                cursor,
+            } => Self {
+                node_id,
+                name,
+                source_event: platform_event_name.into(),
+                data: DomEventData::Composition(CompositionData::new(text, cursor)),
+                bubbles,
+            },
             PlatformEvent::Touch {
                 name: platform_event_name,
                 location,
@@ -156,6 +207,7 @@ impl DomEvent {
                             phase,
                             force,
                         },
+                        click_count,
                     ))
                 } else {
                     DomEventData::Touch(TouchData::new(
@@ -190,6 +242,29 @@ impl DomEvent {
                     bubbles,
                 }
             }
+            PlatformEvent::Drag {
+                name: platform_event_name,
+                cursor,
+                payload,
+            } => {
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: DomEventData::Drag(DragData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        payload,
+                    )),
+                    bubbles,
+                }
+            }
         }
     }
 }
@@ -199,10 +274,16 @@ impl DomEvent {
 pub enum DomEventData {
     Mouse(MouseData),
     Keyboard(KeyboardData),
+    Composition(CompositionData),
     Wheel(WheelData),
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Focus(FocusData),
+    Drag(DragData),
+    Layout(LayoutData),
+    Scroll(ScrollData),
+    Gesture(GestureData),
 }
 
 impl DomEventData {
@@ -210,10 +291,16 @@ impl DomEventData {
         match self {
             DomEventData::Mouse(m) => Rc::new(ErasedEventData::new(Box::new(m))),
             DomEventData::Keyboard(k) => Rc::new(ErasedEventData::new(Box::new(k))),
+            DomEventData::Composition(c) => Rc::new(ErasedEventData::new(Box::new(c))),
             DomEventData::Wheel(w) => Rc::new(ErasedEventData::new(Box::new(w))),
             DomEventData::Touch(t) => Rc::new(ErasedEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(ErasedEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(ErasedEventData::new(Box::new(fd))),
+            DomEventData::Focus(f) => Rc::new(ErasedEventData::new(Box::new(f))),
+            DomEventData::Drag(d) => Rc::new(ErasedEventData::new(Box::new(d))),
+            DomEventData::Layout(l) => Rc::new(ErasedEventData::new(Box::new(l))),
+            DomEventData::Scroll(s) => Rc::new(ErasedEventData::new(Box::new(s))),
+            DomEventData::Gesture(g) => Rc::new(ErasedEventData::new(Box::new(g))),
         }
     }
 }
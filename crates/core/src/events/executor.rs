@@ -5,6 +5,7 @@ use dioxus_core::{
 use freya_native_core::{
     dioxus::NodeImmutableDioxusExt,
     events::EventName,
+    real_dom::NodeImmutable,
     NodeId,
 };
 
@@ -16,9 +17,52 @@ use crate::{
     },
 };
 
+/// Record of a [DomEvent] that was emitted to the DOM.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DispatchedEvent {
+    pub name: EventName,
+    /// Node that received the event.
+    pub node_id: NodeId,
+    /// Nodes listening for this event that it bubbled through, starting from the target node.
+    pub path: Vec<NodeId>,
+    /// Whether a handler prevented the default action, cancelling the dependant events.
+    pub cancelled: bool,
+}
+
 pub struct EventsExecutorAdapter<'a> {
     pub rdom: &'a DioxusDOM,
     pub vdom: &'a mut VirtualDom,
+    /// Where to record the emitted events, if any.
+    pub dispatched_events: Option<&'a mut Vec<DispatchedEvent>>,
+}
+
+impl EventsExecutorAdapter<'_> {
+    /// Get the listeners of the given event from the target node to the root.
+    fn bubbling_path(&self, event: &DomEvent) -> Vec<NodeId> {
+        if !event.bubbles {
+            return vec![event.node_id];
+        }
+
+        let listeners = self
+            .rdom
+            .get_listeners(&event.name)
+            .into_iter()
+            .map(|node| node.id())
+            .collect::<Vec<_>>();
+
+        let mut path = Vec::new();
+        let mut head = Some(event.node_id);
+        while let Some(node_id) = head {
+            let Some(node) = self.rdom.get(node_id) else {
+                break;
+            };
+            if listeners.contains(&node_id) {
+                path.push(node_id);
+            }
+            head = node.parent_id();
+        }
+        path
+    }
 }
 
 impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
@@ -35,6 +79,11 @@ impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
         else {
             return false;
         };
+        let path = self
+            .dispatched_events
+            .is_some()
+            .then(|| self.bubbling_path(&event));
+        let node_id = event.node_id;
         let event_name = event.name;
         let event = Event::new(event.data.clone().any(), event.bubbles);
         let event_clone = event.clone();
@@ -47,7 +96,18 @@ impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
             .runtime()
             .handle_event(event_name.into(), event, element_id);
 
-        event_clone.default_action_enabled()
+        let default_action_enabled = event_clone.default_action_enabled();
+
+        if let Some((dispatched_events, path)) = self.dispatched_events.as_mut().zip(path) {
+            dispatched_events.push(DispatchedEvent {
+                name: event_name,
+                node_id,
+                path,
+                cancelled: !default_action_enabled,
+            });
+        }
+
+        default_action_enabled
     }
 
     fn emitted_events(&mut self) {
@@ -0,0 +1,123 @@
+use freya_elements::events::{
+    LayoutData,
+    ScrollData,
+};
+use freya_native_core::{
+    events::EventName,
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use ragnarok::ProcessedEvents;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
+use torin::prelude::{
+    Area,
+    Size2D,
+};
+
+use super::{
+    DomEvent,
+    DomEventData,
+};
+use crate::{
+    dom::DioxusDOM,
+    states::LayoutState,
+    types::EventEmitter,
+};
+
+/// Layout of an element as of its last measurement.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ObservedLayout {
+    pub area: Area,
+    pub inner_sizes: Size2D,
+    pub offset_x: f32,
+    pub offset_y: f32,
+}
+
+/// Keeps track of the layout of the elements listening for `layout`, `resize` or `scroll` events,
+/// and queues these events when their layout changes.
+#[derive(Default)]
+pub struct LayoutObservers {
+    layouts: FxHashMap<NodeId, ObservedLayout>,
+    pending_events: Vec<DomEvent>,
+}
+
+impl LayoutObservers {
+    /// Compare the new layout of an element with the previous one and queue the events it listens to.
+    pub fn observe(
+        &mut self,
+        node_id: NodeId,
+        listeners: &FxHashSet<EventName>,
+        layout: ObservedLayout,
+    ) {
+        let previous = self.layouts.insert(node_id, layout);
+
+        let mut push_event = |name: EventName, data: DomEventData| {
+            self.pending_events.push(DomEvent {
+                name,
+                source_event: name,
+                node_id,
+                data,
+                bubbles: false,
+            });
+        };
+
+        let area_changed = previous.is_none_or(|previous| {
+            previous.area != layout.area || previous.inner_sizes != layout.inner_sizes
+        });
+        if area_changed && listeners.contains(&EventName::Layout) {
+            push_event(
+                EventName::Layout,
+                DomEventData::Layout(LayoutData::new(layout.area, layout.inner_sizes)),
+            );
+        }
+
+        let size_changed = previous.is_none_or(|previous| previous.area.size != layout.area.size);
+        if size_changed && listeners.contains(&EventName::Resize) {
+            push_event(
+                EventName::Resize,
+                DomEventData::Layout(LayoutData::new(layout.area, layout.inner_sizes)),
+            );
+        }
+
+        // Elements are not scrolled when they first appear
+        let scroll_changed = previous.is_some_and(|previous| {
+            previous.offset_x != layout.offset_x || previous.offset_y != layout.offset_y
+        });
+        if scroll_changed && listeners.contains(&EventName::Scroll) {
+            push_event(
+                EventName::Scroll,
+                DomEventData::Scroll(ScrollData::new(
+                    -layout.offset_x,
+                    -layout.offset_y,
+                    layout.inner_sizes,
+                    layout.area.size,
+                )),
+            );
+        }
+    }
+
+    /// Forget the elements that were removed or no longer listen for layout changes.
+    pub fn retain_observed(&mut self, rdom: &DioxusDOM) {
+        self.layouts.retain(|node_id, _| {
+            rdom.get(*node_id).is_some_and(|node| {
+                node.get::<LayoutState>()
+                    .is_some_and(|layout| layout.has_layout_listeners)
+            })
+        });
+    }
+
+    /// Send the queued events to the elements.
+    pub fn emit_events(&mut self, event_emitter: &EventEmitter) {
+        if !self.pending_events.is_empty() {
+            event_emitter
+                .send(ProcessedEvents {
+                    emmitable_events: self.pending_events.drain(..).collect(),
+                    ..Default::default()
+                })
+                .unwrap();
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::hash_map::Iter;
 
 use dioxus_core::VirtualDom;
+use freya_engine::prelude::Matrix;
 use freya_native_core::{
     events::EventName,
     prelude::NodeImmutable,
@@ -10,7 +11,10 @@ use freya_native_core::{
 use torin::torin::Torin;
 
 use crate::{
-    dom::DioxusDOM,
+    dom::{
+        DioxusDOM,
+        DioxusNode,
+    },
     elements::{
         ElementUtils,
         ElementUtilsResolver,
@@ -21,7 +25,9 @@ use crate::{
     },
     layers::Layers,
     states::{
+        ClipState,
         StyleState,
+        TransformState,
         ViewportState,
     },
     values::{
@@ -38,6 +44,25 @@ pub struct EventsMeasurerAdapter<'a> {
     pub scale_factor: f64,
 }
 
+impl EventsMeasurerAdapter<'_> {
+    /// Map the cursor to the coordinates of the node before applying its transformations,
+    /// so it can be checked against the layout area. Returns `None` if they can't be reverted.
+    fn untransformed_cursor(
+        &self,
+        node_ref: &DioxusNode,
+        cursor: ragnarok::CursorPoint,
+    ) -> Option<ragnarok::CursorPoint> {
+        let node_transform = node_ref.get::<TransformState>().unwrap();
+        let Some(matrix) = node_transform.matrix(self.layout, self.scale_factor as f32) else {
+            return Some(cursor);
+        };
+        let point = matrix
+            .invert()?
+            .map_point((cursor.x as f32, cursor.y as f32));
+        Some(ragnarok::CursorPoint::new(point.x as f64, point.y as f64))
+    }
+}
+
 impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
     type Key = NodeId;
     type Name = EventName;
@@ -74,9 +99,13 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
             return false;
         };
 
+        let Some(node_cursor) = self.untransformed_cursor(&node_ref, cursor) else {
+            return false;
+        };
+
         // Make sure the cursor is inside the node area
         if !element_utils.is_point_inside_area(
-            &cursor,
+            &node_cursor,
             &node_ref,
             layout_node,
             self.scale_factor as f32,
@@ -94,8 +123,11 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
                 continue;
             };
             let layout_node = self.layout.get(*node_id).unwrap();
+            let Some(viewport_cursor) = self.untransformed_cursor(&node_ref, cursor) else {
+                return false;
+            };
             if !element_utils.is_point_inside_area(
-                &cursor,
+                &viewport_cursor,
                 &node_ref,
                 layout_node,
                 self.scale_factor as f32,
@@ -104,6 +136,29 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
             }
         }
 
+        let node_clip = node_ref.get::<ClipState>().unwrap();
+
+        // Make sure the cursor is inside all the inherited clip paths of the node
+        for node_id in &node_clip.clips {
+            let node_ref = self.rdom.get(*node_id).unwrap();
+            let node_clip = node_ref.get::<ClipState>().unwrap();
+            let matrix = node_ref
+                .get::<TransformState>()
+                .unwrap()
+                .matrix(self.layout, self.scale_factor as f32)
+                .unwrap_or_else(Matrix::new_identity);
+            let layout_node = self.layout.get(*node_id).unwrap();
+            for path in node_clip.clip_paths(
+                &layout_node.visible_area(),
+                &matrix,
+                self.scale_factor as f32,
+            ) {
+                if !path.contains((cursor.x as f32, cursor.y as f32)) {
+                    return false;
+                }
+            }
+        }
+
         true
     }
 
@@ -141,7 +196,8 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
         name: Self::Name,
         source: Self::Source,
         area: Option<ragnarok::Area>,
+        click_count: u32,
     ) -> Self::Emmitable {
-        DomEvent::new(key, name, source, area, self.scale_factor)
+        DomEvent::new(key, name, source, area, click_count, self.scale_factor)
     }
 }
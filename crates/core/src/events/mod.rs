@@ -1,10 +1,17 @@
 pub mod dom_event;
 pub mod executor;
+pub mod layout_observers;
 pub mod measurer;
 pub mod platform_event;
+#[cfg(feature = "serde")]
+pub mod recording;
 
 pub use dom_event::*;
 pub use executor::*;
 pub use freya_native_core::events::*;
+pub use layout_observers::*;
 pub use measurer::*;
 pub use platform_event::*;
+pub use ragnarok::ClickCountConfig;
+#[cfg(feature = "serde")]
+pub use recording::*;
@@ -1,10 +1,13 @@
 use std::path::PathBuf;
 
 use freya_elements::{
-    events::keyboard::{
-        Code,
-        Key,
-        Modifiers,
+    events::{
+        keyboard::{
+            Code,
+            Key,
+            Modifiers,
+        },
+        DragPayload,
     },
     Force,
     MouseButton,
@@ -14,6 +17,7 @@ use freya_native_core::events::EventName;
 use torin::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MouseEventName {
     MouseMove,
     MouseDown,
@@ -38,6 +42,7 @@ impl From<MouseEventName> for EventName {
 }
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WheelEventName {
     Wheel,
 }
@@ -51,6 +56,23 @@ impl From<WheelEventName> for EventName {
 }
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GestureEventName {
+    Pinch,
+    Rotate,
+}
+
+impl From<GestureEventName> for EventName {
+    fn from(value: GestureEventName) -> Self {
+        match value {
+            GestureEventName::Pinch => EventName::Pinch,
+            GestureEventName::Rotate => EventName::Rotate,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KeyboardEventName {
     KeyDown,
     KeyUp,
@@ -66,6 +88,25 @@ impl From<KeyboardEventName> for EventName {
 }
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompositionEventName {
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
+}
+
+impl From<CompositionEventName> for EventName {
+    fn from(value: CompositionEventName) -> Self {
+        match value {
+            CompositionEventName::CompositionStart => EventName::CompositionStart,
+            CompositionEventName::CompositionUpdate => EventName::CompositionUpdate,
+            CompositionEventName::CompositionEnd => EventName::CompositionEnd,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TouchEventName {
     TouchStart,
     TouchMove,
@@ -85,6 +126,7 @@ impl From<TouchEventName> for EventName {
 }
 
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FileEventName {
     FileDrop,
     FileHover,
@@ -100,8 +142,27 @@ impl From<FileEventName> for EventName {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragEventName {
+    DragOver,
+    Drop,
+    DragEnd,
+}
+
+impl From<DragEventName> for EventName {
+    fn from(value: DragEventName) -> Self {
+        match value {
+            DragEventName::DragOver => EventName::DragOver,
+            DragEventName::Drop => EventName::Drop,
+            DragEventName::DragEnd => EventName::GlobalDragEnd,
+        }
+    }
+}
+
 /// Data for [PlatformEvent].
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PlatformEvent {
     /// A Mouse Event.
     Mouse {
@@ -114,6 +175,14 @@ pub enum PlatformEvent {
         name: WheelEventName,
         scroll: CursorPoint,
         cursor: CursorPoint,
+        phase: TouchPhase,
+    },
+    /// A touchpad Gesture event.
+    Gesture {
+        name: GestureEventName,
+        cursor: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
     },
     /// A Keyboard event.
     Keyboard {
@@ -122,6 +191,12 @@ pub enum PlatformEvent {
         code: Code,
         modifiers: Modifiers,
     },
+    /// An IME Composition event.
+    Composition {
+        name: CompositionEventName,
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
     /// A Touch event.
     Touch {
         name: TouchEventName,
@@ -136,6 +211,28 @@ pub enum PlatformEvent {
         cursor: CursorPoint,
         file_path: Option<PathBuf>,
     },
+    /// A Drag event, part of a drag session that might have started in another window.
+    Drag {
+        name: DragEventName,
+        cursor: CursorPoint,
+        payload: DragPayload,
+    },
+}
+
+impl PlatformEvent {
+    /// Multiply the cursor location of this event by the given scale factor.
+    pub fn scaled(mut self, scale_factor: f64) -> Self {
+        match &mut self {
+            Self::Mouse { cursor, .. }
+            | Self::Wheel { cursor, .. }
+            | Self::Gesture { cursor, .. }
+            | Self::File { cursor, .. }
+            | Self::Drag { cursor, .. } => *cursor *= scale_factor,
+            Self::Touch { location, .. } => *location *= scale_factor,
+            Self::Keyboard { .. } | Self::Composition { .. } => {}
+        }
+        self
+    }
 }
 
 impl ragnarok::SourceEvent for PlatformEvent {
@@ -148,6 +245,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
             Self::Mouse {
                 name: MouseEventName::MouseMove,
                 ..
+            } | Self::Drag {
+                name: DragEventName::DragOver,
+                ..
             }
         )
     }
@@ -170,9 +270,29 @@ impl ragnarok::SourceEvent for PlatformEvent {
         match self {
             Self::Mouse { name, .. } => (*name).into(),
             Self::Wheel { name, .. } => (*name).into(),
+            Self::Gesture { name, .. } => (*name).into(),
             Self::Keyboard { name, .. } => (*name).into(),
+            Self::Composition { name, .. } => (*name).into(),
             Self::Touch { name, .. } => (*name).into(),
             Self::File { name, .. } => (*name).into(),
+            Self::Drag { name, .. } => (*name).into(),
+        }
+    }
+
+    fn try_button(&self) -> Option<u16> {
+        match self {
+            Self::Mouse {
+                button: Some(button),
+                ..
+            } => Some(match button {
+                MouseButton::Left => 0,
+                MouseButton::Right => 1,
+                MouseButton::Middle => 2,
+                MouseButton::Back => 3,
+                MouseButton::Forward => 4,
+                MouseButton::Other(button) => button.saturating_add(5),
+            }),
+            _ => None,
         }
     }
 
@@ -181,8 +301,11 @@ impl ragnarok::SourceEvent for PlatformEvent {
             PlatformEvent::File { cursor, .. } => Some(*cursor),
             PlatformEvent::Mouse { cursor, .. } => Some(*cursor),
             PlatformEvent::Wheel { cursor, .. } => Some(*cursor),
+            PlatformEvent::Gesture { cursor, .. } => Some(*cursor),
             PlatformEvent::Keyboard { .. } => None,
+            PlatformEvent::Composition { .. } => None,
             PlatformEvent::Touch { location, .. } => Some(*location),
+            PlatformEvent::Drag { cursor, .. } => Some(*cursor),
         }
     }
 }
@@ -0,0 +1,99 @@
+use std::{
+    fs,
+    io,
+    path::Path,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use torin::geometry::Size2D;
+
+use crate::events::PlatformEvent;
+
+/// An input captured while recording a session.
+///
+/// Sizes and cursor locations are logical, so the recording can be replayed with any scale factor.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum RecordedInput {
+    /// The window was resized.
+    Resize(Size2D),
+    /// The window received an event.
+    Event(PlatformEvent),
+    /// A named point of the session, useful to take snapshots when replaying it.
+    Marker(String),
+}
+
+/// A [RecordedInput] and the moment it happened.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordedEntry {
+    /// Milliseconds since the recording started.
+    pub time: u64,
+    pub input: RecordedInput,
+}
+
+/// A recorded input session, stored as one JSON [RecordedEntry] per line.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct InputRecording {
+    pub entries: Vec<RecordedEntry>,
+}
+
+impl InputRecording {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a recording from the given file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Self::parse(&content).map_err(io::Error::from)
+    }
+
+    /// Parse a recording from its JSON lines.
+    pub fn parse(content: &str) -> Result<Self, serde_json::Error> {
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    /// Write this recording to the given file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json_lines()?)
+    }
+
+    /// Serialize this recording as JSON lines.
+    pub fn to_json_lines(&self) -> Result<String, serde_json::Error> {
+        let mut content = String::new();
+        for entry in &self.entries {
+            content.push_str(&entry.to_json_line()?);
+        }
+        Ok(content)
+    }
+
+    /// Add an input to the end of this recording.
+    pub fn push(&mut self, time: u64, input: RecordedInput) {
+        self.entries.push(RecordedEntry { time, input });
+    }
+
+    /// Names of the markers in this recording, in order.
+    pub fn markers(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().filter_map(|entry| match &entry.input {
+            RecordedInput::Marker(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+}
+
+impl RecordedEntry {
+    /// Serialize this entry as a single JSON line.
+    pub fn to_json_line(&self) -> Result<String, serde_json::Error> {
+        let mut line = serde_json::to_string(self)?;
+        line.push('\n');
+        Ok(line)
+    }
+}
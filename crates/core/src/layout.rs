@@ -15,6 +15,7 @@ use crate::{
         NodeAccessibility,
     },
     dom::*,
+    events::LayoutObservers,
     render::{
         Compositor,
         CompositorDirtyArea,
@@ -31,6 +32,7 @@ pub fn process_layout(
     dirty_accessibility_tree: &mut AccessibilityDirtyNodes,
     compositor_dirty_nodes: &mut CompositorDirtyNodes,
     compositor_dirty_area: &mut CompositorDirtyArea,
+    layout_observers: &mut LayoutObservers,
     area: Area,
     font_collection: &mut FontCollection,
     scale_factor: f32,
@@ -43,6 +45,7 @@ pub fn process_layout(
         fallback_fonts,
         scale_factor,
         images_cache,
+        layout_observers,
     );
 
     // Finds the best Node from where to start measuring
@@ -71,4 +74,7 @@ pub fn process_layout(
 
     // Measure the layout
     layout.measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
+
+    // Forget the layout of removed elements
+    layout_observers.retain_observed(rdom);
 }
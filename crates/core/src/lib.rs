@@ -8,6 +8,7 @@ pub mod events;
 pub mod layers;
 pub mod layout;
 pub mod node_state_snapshot;
+pub mod offscreen;
 pub mod parsing;
 pub mod platform;
 pub mod platform_state;
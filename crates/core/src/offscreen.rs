@@ -0,0 +1,484 @@
+use std::{
+    io,
+    path::Path,
+    sync::Arc,
+    time::Duration,
+};
+
+use accesskit::{
+    Node,
+    Role,
+};
+use dioxus_core::{
+    Element,
+    VirtualDom,
+};
+use freya_engine::prelude::{
+    raster_n32_premul,
+    AlphaType,
+    ColorType,
+    Data,
+    DirectContext,
+    EncodedImageFormat,
+    FontCollection,
+    FontMgr,
+    ImageInfo,
+    Surface,
+    TypefaceFontProvider,
+};
+use tokio::{
+    runtime::Builder,
+    sync::{
+        broadcast,
+        mpsc::{
+            unbounded_channel,
+            UnboundedReceiver,
+        },
+        watch,
+    },
+    time::timeout,
+};
+use torin::geometry::{
+    Area,
+    Size2D,
+};
+
+use crate::{
+    accessibility::ACCESSIBILITY_ROOT_ID,
+    dom::FreyaDOM,
+    event_loop_messages::{
+        EventLoopMessage,
+        EventLoopMessageAction,
+    },
+    events::LayoutObservers,
+    layout::process_layout,
+    platform_state::{
+        NativePlatformState,
+        NavigationMode,
+        PlatformInformation,
+        PreferredTheme,
+    },
+    render::{
+        Compositor,
+        RenderPipeline,
+    },
+    style::fallback_fonts,
+    types::NativePlatformSender,
+    values::Color,
+};
+
+/// Configuration for the [`OffscreenRenderer`].
+pub struct OffscreenConfig<'a> {
+    /// Logical size of the rendered image.
+    pub size: Size2D,
+    /// Scale factor used to render, the size of the image in pixels is `size * scale_factor`.
+    pub scale_factor: f64,
+    /// Color used to clear the image before rendering.
+    pub background: Color,
+    /// How long the VirtualDOM can stay without work before being considered idle.
+    pub vdom_timeout: Duration,
+    /// Maximum number of VirtualDOM updates to process before rendering, useful to stop endless animations.
+    pub max_updates: usize,
+    pub embedded_fonts: Vec<(&'a str, &'a [u8])>,
+    pub fallback_fonts: Vec<String>,
+}
+
+impl Default for OffscreenConfig<'_> {
+    fn default() -> Self {
+        Self {
+            size: Size2D::new(500.0, 500.0),
+            scale_factor: 1.0,
+            background: Color::WHITE,
+            vdom_timeout: Duration::from_millis(16),
+            max_updates: 100,
+            embedded_fonts: Vec::default(),
+            fallback_fonts: fallback_fonts(),
+        }
+    }
+}
+
+impl<'a> OffscreenConfig<'a> {
+    pub fn new() -> Self {
+        OffscreenConfig::default()
+    }
+
+    /// Specify the logical size of the image.
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Size2D::new(width, height);
+        self
+    }
+
+    /// Specify the scale factor.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Specify the background color.
+    pub fn with_background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Specify how long the VirtualDOM can stay without work before being considered idle.
+    pub fn with_vdom_timeout(mut self, vdom_timeout: Duration) -> Self {
+        self.vdom_timeout = vdom_timeout;
+        self
+    }
+
+    /// Specify the maximum number of VirtualDOM updates to process before rendering.
+    pub fn with_max_updates(mut self, max_updates: usize) -> Self {
+        self.max_updates = max_updates;
+        self
+    }
+
+    /// Embed a font.
+    pub fn with_font(mut self, font_name: &'a str, font: &'a [u8]) -> Self {
+        self.embedded_fonts.push((font_name, font));
+        self
+    }
+
+    /// Register a fallback font. Will be used if the default fonts are not available.
+    pub fn with_fallback_font(mut self, font_name: &str) -> Self {
+        self.fallback_fonts.push(font_name.to_string());
+        self
+    }
+}
+
+/// Encoding formats supported by [`OffscreenImage::encode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffscreenImageFormat {
+    #[default]
+    Png,
+    /// Quality goes from `0` to `100`.
+    Jpeg { quality: u32 },
+    /// Quality goes from `0` to `100`, `100` being lossless.
+    Webp { quality: u32 },
+}
+
+/// Image rendered by the [`OffscreenRenderer`].
+pub struct OffscreenImage {
+    surface: Surface,
+    width: i32,
+    height: i32,
+}
+
+impl OffscreenImage {
+    /// Width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width as u32
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height as u32
+    }
+
+    /// Encode the image in the given format.
+    pub fn encode(&mut self, format: OffscreenImageFormat) -> Option<Data> {
+        let (format, quality) = match format {
+            OffscreenImageFormat::Png => (EncodedImageFormat::PNG, None),
+            OffscreenImageFormat::Jpeg { quality } => (EncodedImageFormat::JPEG, Some(quality)),
+            OffscreenImageFormat::Webp { quality } => (EncodedImageFormat::WEBP, Some(quality)),
+        };
+        self.surface
+            .image_snapshot()
+            .encode(None::<&mut DirectContext>, format, quality)
+    }
+
+    /// Get the raw pixels of the image, as unpremultiplied RGBA with 8 bits per channel.
+    pub fn to_rgba(&mut self) -> Vec<u8> {
+        let image_info = ImageInfo::new(
+            (self.width, self.height),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = self.width as usize * 4;
+        let mut pixels = vec![0; row_bytes * self.height as usize];
+        self.surface
+            .read_pixels(&image_info, &mut pixels, row_bytes, (0, 0));
+        pixels
+    }
+
+    /// Encode the image in the given format and save it into a file.
+    pub fn save(&mut self, path: impl AsRef<Path>, format: OffscreenImageFormat) -> io::Result<()> {
+        let data = self
+            .encode(format)
+            .ok_or_else(|| io::Error::other("Failed to encode the image."))?;
+        std::fs::write(path, &*data)
+    }
+}
+
+/// Renders a VirtualDOM into images without the need of a window.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # use freya::offscreen::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             width: "fill",
+///             height: "fill",
+///             background: "rgb(0, 119, 182)",
+///             label { "Hello, World!" }
+///         }
+///     )
+/// }
+///
+/// # async fn thumbnail() {
+/// let mut renderer = OffscreenRenderer::new(
+///     VirtualDom::new(app),
+///     OffscreenConfig::new().with_size(300., 200.).with_scale_factor(2.),
+/// );
+/// renderer.wait_until_idle().await;
+/// renderer
+///     .render()
+///     .save("./thumbnail.png", OffscreenImageFormat::Png)
+///     .unwrap();
+/// # }
+/// ```
+pub struct OffscreenRenderer {
+    vdom: VirtualDom,
+    fdom: FreyaDOM,
+    font_collection: FontCollection,
+    font_mgr: FontMgr,
+    size: Size2D,
+    scale_factor: f64,
+    background: Color,
+    vdom_timeout: Duration,
+    max_updates: usize,
+    fallback_fonts: Vec<String>,
+    platform_sender: NativePlatformSender,
+    platform_event_receiver: UnboundedReceiver<EventLoopMessage>,
+    ticker_sender: broadcast::Sender<()>,
+}
+
+impl OffscreenRenderer {
+    /// Mount the given VirtualDOM.
+    pub fn new(mut vdom: VirtualDom, config: OffscreenConfig) -> Self {
+        let mut fdom = FreyaDOM::default();
+
+        let (platform_event_emitter, platform_event_receiver) =
+            unbounded_channel::<EventLoopMessage>();
+        let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
+            focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
+            focused_accessibility_node: Node::new(Role::Window),
+            preferred_theme: PreferredTheme::default(),
+            navigation_mode: NavigationMode::default(),
+            information: PlatformInformation::new(
+                config.size * config.scale_factor as f32,
+                false,
+                false,
+                false,
+            ),
+            scale_factor: config.scale_factor,
+        });
+        let ticker_sender = broadcast::channel(5).0;
+
+        vdom.insert_any_root_context(Box::new(platform_event_emitter));
+        vdom.insert_any_root_context(Box::new(platform_receiver));
+        vdom.insert_any_root_context(Box::new(Arc::new(ticker_sender.subscribe())));
+        vdom.insert_any_root_context(Box::new(fdom.accessibility_generator().clone()));
+        vdom.insert_any_root_context(Box::new(fdom.animation_clock().clone()));
+
+        fdom.init_dom(&mut vdom, config.scale_factor as f32);
+
+        let mut font_collection = FontCollection::new();
+        let def_mgr = FontMgr::default();
+
+        let mut provider = TypefaceFontProvider::new();
+        for (font_name, font_data) in config.embedded_fonts {
+            let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
+            provider.register_typeface(ft_type, Some(font_name));
+        }
+
+        let font_mgr: FontMgr = provider.into();
+        font_collection.set_default_font_manager(def_mgr.clone(), None);
+        font_collection.set_dynamic_font_manager(font_mgr);
+
+        Self {
+            vdom,
+            fdom,
+            font_collection,
+            font_mgr: def_mgr,
+            size: config.size,
+            scale_factor: config.scale_factor,
+            background: config.background,
+            vdom_timeout: config.vdom_timeout,
+            max_updates: config.max_updates,
+            fallback_fonts: config.fallback_fonts,
+            platform_sender,
+            platform_event_receiver,
+            ticker_sender,
+        }
+    }
+
+    /// Get a mutable reference to the [`VirtualDom`].
+    pub fn vdom(&mut self) -> &mut VirtualDom {
+        &mut self.vdom
+    }
+
+    /// Size of the rendered images in pixels.
+    pub fn physical_size(&self) -> Size2D {
+        (self.size * self.scale_factor as f32).round()
+    }
+
+    /// Resize the logical size of the rendered images.
+    pub fn resize(&mut self, size: Size2D) {
+        self.size = size;
+        let physical_size = self.physical_size();
+        self.platform_sender.send_modify(|state| {
+            state.information.viewport_size = physical_size;
+        });
+        self.fdom.layout().reset();
+    }
+
+    /// Process the VirtualDOM and the layout until there is no more pending work
+    /// or the configured maximum number of updates is reached.
+    pub async fn wait_until_idle(&mut self) {
+        for _ in 0..self.max_updates {
+            self.process_layout();
+
+            while let Ok(message) = self.platform_event_receiver.try_recv() {
+                match message.action {
+                    EventLoopMessageAction::RemeasureTextGroup(text_measurement) => {
+                        self.fdom
+                            .measure_paragraphs(text_measurement, self.scale_factor);
+                    }
+                    EventLoopMessageAction::FocusAccessibilityNode(strategy) => {
+                        self.fdom
+                            .accessibility_dirty_nodes()
+                            .request_focus(strategy);
+                    }
+                    _ => {}
+                }
+            }
+
+            // Notify animations and other components subscribed to the event loop ticks
+            self.ticker_sender.send(()).ok();
+
+            let has_work = timeout(self.vdom_timeout, self.vdom.wait_for_work())
+                .await
+                .is_ok();
+            if !has_work {
+                break;
+            }
+
+            self.fdom
+                .render_mutations(&mut self.vdom, self.scale_factor as f32);
+        }
+
+        self.process_layout();
+    }
+
+    /// Render the current state of the DOM into an [`OffscreenImage`].
+    pub fn render(&mut self) -> OffscreenImage {
+        let (width, height) = self.physical_size().to_i32().to_tuple();
+        let surface = render_to_surface(
+            &self.fdom,
+            (width, height),
+            self.background,
+            self.scale_factor as f32,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &self.fallback_fonts,
+        );
+        OffscreenImage {
+            surface,
+            width,
+            height,
+        }
+    }
+
+    fn process_layout(&mut self) {
+        let fdom = &self.fdom;
+        process_layout(
+            fdom.rdom(),
+            &mut fdom.layout(),
+            &mut fdom.images_cache(),
+            &mut fdom.accessibility_dirty_nodes(),
+            &mut fdom.compositor_dirty_nodes(),
+            &mut fdom.compositor_dirty_area(),
+            // Events are not processed offscreen, so there is nothing to notify about layout changes
+            &mut LayoutObservers::default(),
+            Area::from_size(self.physical_size()),
+            &mut self.font_collection,
+            self.scale_factor as f32,
+            &self.fallback_fonts,
+        );
+    }
+}
+
+/// Mount the given component, wait until it's idle and render it into an [`OffscreenImage`].
+///
+/// This creates its own async runtime, so it must not be called from within another one,
+/// use [`OffscreenRenderer`] in that case.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # use freya::offscreen::*;
+/// fn app() -> Element {
+///     rsx!(label { "Hello, World!" })
+/// }
+///
+/// let mut image = render_to_image(app, OffscreenConfig::new().with_size(200., 100.));
+/// let pixels = image.to_rgba();
+/// ```
+pub fn render_to_image(app: fn() -> Element, config: OffscreenConfig) -> OffscreenImage {
+    let rt = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("Failed to create the async runtime.");
+    rt.block_on(async move {
+        let mut renderer = OffscreenRenderer::new(VirtualDom::new(app), config);
+        renderer.wait_until_idle().await;
+        renderer.render()
+    })
+}
+
+/// Render the whole [`FreyaDOM`] into a new raster [`Surface`] of the given size in pixels.
+pub fn render_to_surface(
+    fdom: &FreyaDOM,
+    (width, height): (i32, i32),
+    background: Color,
+    scale_factor: f32,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    fallback_fonts: &[String],
+) -> Surface {
+    // Create the main surface
+    let mut surface = raster_n32_premul((width, height)).expect("Failed to create the surface.");
+    surface.canvas().clear(background);
+
+    // Create the dirty surface
+    let mut dirty_surface = surface
+        .new_surface_with_dimensions((width, height))
+        .expect("Failed to create the dirty surface.");
+    dirty_surface.canvas().clear(background);
+
+    let mut compositor = Compositor::default();
+
+    // Render to the canvas
+    let mut render_pipeline = RenderPipeline {
+        canvas_area: Area::from_size((width as f32, height as f32).into()),
+        rdom: fdom.rdom(),
+        compositor_dirty_area: &mut fdom.compositor_dirty_area(),
+        compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
+        compositor_cache: &mut fdom.compositor_cache(),
+        layers: &mut fdom.layers(),
+        layout: &mut fdom.layout(),
+        background,
+        surface: &mut surface,
+        dirty_surface: &mut dirty_surface,
+        compositor: &mut compositor,
+        scale_factor,
+        font_collection,
+        font_manager,
+        fallback_fonts,
+        images_cache: &mut fdom.images_cache(),
+    };
+    render_pipeline.run();
+
+    surface
+}
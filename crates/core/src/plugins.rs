@@ -3,6 +3,7 @@ use std::{
     rc::Rc,
 };
 
+use accesskit::TreeUpdate;
 use freya_engine::prelude::{
     Canvas,
     FontCollection,
@@ -21,7 +22,10 @@ use crate::{
         EventLoopMessage,
         EventLoopMessageAction,
     },
-    events::PlatformEvent,
+    events::{
+        DispatchedEvent,
+        PlatformEvent,
+    },
 };
 
 #[derive(Clone)]
@@ -63,6 +67,14 @@ impl PluginsManager {
         self.plugins.borrow_mut().push(Box::new(plugin))
     }
 
+    /// Check if any plugin wants to receive [PluginEvent::DispatchedEvents].
+    pub fn wants_dispatched_events(&self) -> bool {
+        self.plugins
+            .borrow()
+            .iter()
+            .any(|plugin| plugin.wants_dispatched_events())
+    }
+
     pub fn send(&mut self, event: PluginEvent, handle: PluginHandle) {
         for plugin in self.plugins.borrow_mut().iter_mut() {
             plugin.on_event(&event, handle.clone())
@@ -126,19 +138,78 @@ pub enum PluginEvent<'a> {
         fdom: &'a FreyaDOM,
     },
 
+    /// A Window received a [PlatformEvent], before it gets queued.
+    /// Cursor locations are physical, divide them by `scale_factor` to get logical ones.
+    ReceivedPlatformEvent {
+        window: &'a Window,
+        event: &'a PlatformEvent,
+        scale_factor: f64,
+    },
+
+    /// After emitting events to the DOM.
+    DispatchedEvents {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+        events: &'a [DispatchedEvent],
+    },
+
+    /// After updating the accessibility tree of a Window.
+    AccessibilityTreeUpdated {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+        tree: &'a TreeUpdate,
+    },
+
+    /// Before polling the VirtualDOM for new changes.
+    StartedPollingVDOM {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+    },
+
+    /// After polling the VirtualDOM for new changes.
+    FinishedPollingVDOM {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+    },
+
     StartedUpdatingDOM {
         window: &'a Window,
         fdom: &'a FreyaDOM,
     },
 
+    /// After applying the mutations to the RealDOM and before running the Nodes states passes.
+    StartedUpdatingStates {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+    },
+
+    /// After running the Nodes states passes.
+    FinishedUpdatingStates {
+        window: &'a Window,
+        fdom: &'a FreyaDOM,
+    },
+
     FinishedUpdatingDOM {
         window: &'a Window,
         fdom: &'a FreyaDOM,
     },
+
+    /// Before finding what nodes need to be rendered.
+    /// The DOM is not available as its resources are locked while rendering.
+    StartedCompositing { window: &'a Window },
+
+    /// After finding what nodes need to be rendered.
+    FinishedCompositing { window: &'a Window },
 }
 
 /// Skeleton for Freya plugins.
 pub trait FreyaPlugin {
     /// React on events emitted by Freya.
     fn on_event(&mut self, event: &PluginEvent, handle: PluginHandle);
+
+    /// Whether this plugin wants to receive [PluginEvent::DispatchedEvents].
+    /// Recording the dispatched events has a cost, so they are only recorded if a plugin wants them.
+    fn wants_dispatched_events(&self) -> bool {
+        false
+    }
 }
@@ -1,4 +1,5 @@
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     FontCollection,
     FontMgr,
@@ -7,7 +8,6 @@ use freya_engine::prelude::{
     Rect,
     SamplingOptions,
     SaveLayerRec,
-    SkPoint,
     Surface,
 };
 use freya_native_core::{
@@ -20,6 +20,7 @@ use freya_native_core::{
     NodeId,
 };
 use itertools::sorted;
+use rustc_hash::FxHashSet;
 use torin::prelude::{
     Area,
     LayoutNode,
@@ -27,6 +28,7 @@ use torin::prelude::{
 };
 
 use super::{
+    render_mask,
     Compositor,
     CompositorCache,
     CompositorDirtyArea,
@@ -44,6 +46,8 @@ use crate::{
     },
     layers::Layers,
     states::{
+        ClipState,
+        LayerState,
         TransformState,
         ViewportState,
     },
@@ -70,22 +74,32 @@ pub struct RenderPipeline<'a> {
     pub fallback_fonts: &'a [String],
 }
 
-impl RenderPipeline<'_> {
+impl<'a> RenderPipeline<'a> {
     pub fn run(&mut self) {
         let mut dirty_layers = Layers::default();
+        let rendering_layers = self.compose(&mut dirty_layers);
+        self.paint(rendering_layers);
+    }
 
-        // Process what nodes need to be rendered
-        let rendering_layers = self.compositor.run(
+    /// Process what nodes need to be rendered.
+    pub fn compose<'b>(&mut self, dirty_layers: &'b mut Layers) -> &'b Layers
+    where
+        'a: 'b,
+    {
+        self.compositor.run(
             self.compositor_dirty_nodes,
             self.compositor_dirty_area,
             self.compositor_cache,
             self.layers,
-            &mut dirty_layers,
+            dirty_layers,
             self.layout,
             self.rdom,
             self.scale_factor,
-        );
+        )
+    }
 
+    /// Paint the given layers into the canvas.
+    pub fn paint(&mut self, rendering_layers: &Layers) {
         #[cfg(feature = "fade-cached-incremental-areas")]
         {
             // Slowly fade into white non-rerendered areas
@@ -136,6 +150,9 @@ impl RenderPipeline<'_> {
         // Counter of painted nodes for debugging purposes
         let mut painted = 0;
 
+        // Filtered nodes are rendered along with their children, so only once
+        let mut rendered_filtered = FxHashSet::default();
+
         // Render the dirty nodes
         for (_, nodes) in sorted(rendering_layers.iter()) {
             'elements: for node_id in sorted(nodes) {
@@ -152,8 +169,20 @@ impl RenderPipeline<'_> {
                         }
                     }
 
-                    // Render the element
-                    self.render(node_ref, layout_node);
+                    // Render the element, or the outermost filtered node that contains it
+                    let filtered_id = node_ref
+                        .get::<TransformState>()
+                        .unwrap()
+                        .filtered
+                        .first()
+                        .copied();
+                    if let Some(filtered_id) = filtered_id {
+                        if rendered_filtered.insert(filtered_id) {
+                            self.render_filtered(filtered_id);
+                        }
+                    } else {
+                        self.render(node_ref, layout_node);
+                    }
 
                     #[cfg(debug_assertions)]
                     {
@@ -198,40 +227,25 @@ impl RenderPipeline<'_> {
 
             let node_transform = &*node_ref.get::<TransformState>().unwrap();
             let node_viewports = node_ref.get::<ViewportState>().unwrap();
+            let node_clip = node_ref.get::<ClipState>().unwrap();
 
-            // Apply viewport clipping
-            for node_id in &node_viewports.viewports {
-                let node_ref = self.rdom.get(*node_id).unwrap();
-                let node_type = node_ref.node_type();
-                let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
-                    continue;
-                };
-                let layout_node = self.layout.get(*node_id).unwrap();
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
-            }
+            clip_node(
+                dirty_canvas,
+                &node_viewports,
+                &node_clip,
+                self.rdom,
+                self.layout,
+                self.scale_factor,
+            );
 
-            // Apply inherited scale effects
-            for (id, scale_x, scale_y) in &node_transform.scales {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let center = area.center();
-                dirty_canvas.translate((center.x, center.y));
-                dirty_canvas.scale((*scale_x, *scale_y));
-                dirty_canvas.translate((-center.x, -center.y));
+            // Render into a separate layer so the inherited masks can be applied afterwards
+            if !node_clip.masks.is_empty() {
+                dirty_canvas.save_layer(&SaveLayerRec::default());
             }
+            let content_layer = dirty_canvas.save();
 
-            // Pass rotate effect to children
-            for (id, rotate_degs) in &node_transform.rotations {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let mut matrix = Matrix::new_identity();
-                matrix.set_rotate(
-                    *rotate_degs,
-                    Some(SkPoint {
-                        x: area.min_x() + area.width() / 2.0,
-                        y: area.min_y() + area.height() / 2.0,
-                    }),
-                );
+            // Apply inherited transformations
+            if let Some(matrix) = node_transform.matrix(self.layout, self.scale_factor) {
                 dirty_canvas.concat(&matrix);
             }
 
@@ -266,7 +280,135 @@ impl RenderPipeline<'_> {
                 self.scale_factor,
             );
 
+            dirty_canvas.restore_to_count(content_layer);
+
+            // Apply inherited masks
+            for node_id in &node_clip.masks {
+                let node_ref = self.rdom.get(*node_id).unwrap();
+                let matrix = node_ref
+                    .get::<TransformState>()
+                    .unwrap()
+                    .matrix(self.layout, self.scale_factor)
+                    .unwrap_or_else(Matrix::new_identity);
+                let layout_node = self.layout.get(*node_id).unwrap();
+                render_mask(
+                    dirty_canvas,
+                    layout_node.visible_area(),
+                    &matrix,
+                    &node_ref.get::<ClipState>().unwrap(),
+                    self.images_cache,
+                );
+            }
+
             dirty_canvas.restore_to_count(initial_layer);
         }
     }
+
+    /// Render a node with a filter together with its children, so the filter is applied once to all of them.
+    /// Nested filtered nodes are rendered the same way.
+    fn render_filtered(&mut self, node_id: NodeId) {
+        let node_ref = self.rdom.get(node_id).unwrap();
+        let node_transform = node_ref.get::<TransformState>().unwrap();
+
+        // Collect the node and its children in the order they are rendered
+        let mut nodes = vec![(layer_of(&node_ref), node_id, false)];
+        let mut stack = node_ref.child_ids();
+        while let Some(child_id) = stack.pop() {
+            let Some(child_ref) = self.rdom.get(child_id) else {
+                continue;
+            };
+            let is_filtered = child_ref
+                .get::<TransformState>()
+                .is_some_and(|transform| transform.filter.is_some());
+            nodes.push((layer_of(&child_ref), child_id, is_filtered));
+            if !is_filtered {
+                stack.extend(child_ref.child_ids());
+            }
+        }
+        nodes.sort();
+
+        let dirty_canvas = self.dirty_surface.canvas();
+        let initial_layer = dirty_canvas.save();
+
+        clip_node(
+            dirty_canvas,
+            &node_ref.get::<ViewportState>().unwrap(),
+            &node_ref.get::<ClipState>().unwrap(),
+            self.rdom,
+            self.layout,
+            self.scale_factor,
+        );
+
+        // Apply the filter in the coordinates of the node
+        if let Some(matrix) = node_transform.matrix(self.layout, self.scale_factor) {
+            dirty_canvas.concat(&matrix);
+        }
+        if let Some(image_filter) = node_transform
+            .filter
+            .as_ref()
+            .and_then(|filter| filter.to_image_filter(self.scale_factor))
+        {
+            let mut paint = Paint::default();
+            paint.set_image_filter(image_filter);
+
+            dirty_canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+        }
+
+        // Every node applies its own transformations
+        dirty_canvas.reset_matrix();
+
+        for (_, node_id, is_filtered) in nodes {
+            if is_filtered {
+                self.render_filtered(node_id);
+            } else if let Some(layout_node) = self.layout.get(node_id) {
+                self.render(self.rdom.get(node_id).unwrap(), layout_node);
+            }
+        }
+
+        self.dirty_surface.canvas().restore_to_count(initial_layer);
+    }
+}
+
+/// Clip the canvas to the viewports and the inherited clip paths of a node.
+fn clip_node(
+    canvas: &Canvas,
+    node_viewports: &ViewportState,
+    node_clip: &ClipState,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    scale_factor: f32,
+) {
+    // Apply viewport clipping
+    for node_id in &node_viewports.viewports {
+        let node_ref = rdom.get(*node_id).unwrap();
+        let node_type = node_ref.node_type();
+        let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+            continue;
+        };
+        let layout_node = layout.get(*node_id).unwrap();
+        element_utils.clip(layout_node, &node_ref, canvas, scale_factor);
+    }
+
+    // Apply inherited clip paths
+    for node_id in &node_clip.clips {
+        let node_ref = rdom.get(*node_id).unwrap();
+        let node_clip = node_ref.get::<ClipState>().unwrap();
+        let matrix = node_ref
+            .get::<TransformState>()
+            .unwrap()
+            .matrix(layout, scale_factor)
+            .unwrap_or_else(Matrix::new_identity);
+        let layout_node = layout.get(*node_id).unwrap();
+        for path in node_clip.clip_paths(&layout_node.visible_area(), &matrix, scale_factor) {
+            canvas.clip_path(&path, ClipOp::Intersect, true);
+        }
+    }
+}
+
+/// Get the layer in which the given node is rendered.
+fn layer_of(node_ref: &DioxusNode) -> i16 {
+    node_ref
+        .get::<LayerState>()
+        .map(|layer_state| layer_state.layer)
+        .unwrap_or_default()
 }
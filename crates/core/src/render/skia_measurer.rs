@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    cell::RefCell,
+    sync::Arc,
+};
 
 use freya_engine::prelude::*;
 use freya_native_core::{
@@ -28,6 +31,10 @@ use crate::{
     custom_attributes::NodeReferenceLayout,
     dom::*,
     elements::CachedParagraph,
+    events::{
+        LayoutObservers,
+        ObservedLayout,
+    },
     render::ParagraphData,
     states::LayoutState,
 };
@@ -39,6 +46,7 @@ pub struct SkiaMeasurer<'a> {
     pub fallback_fonts: &'a [String],
     pub scale_factor: f32,
     pub images_cache: &'a mut ImagesCache,
+    pub layout_observers: RefCell<&'a mut LayoutObservers>,
 }
 
 impl<'a> SkiaMeasurer<'a> {
@@ -48,6 +56,7 @@ impl<'a> SkiaMeasurer<'a> {
         fallback_fonts: &'a [String],
         scale_factor: f32,
         images_cache: &'a mut ImagesCache,
+        layout_observers: &'a mut LayoutObservers,
     ) -> Self {
         Self {
             font_collection,
@@ -55,6 +64,7 @@ impl<'a> SkiaMeasurer<'a> {
             fallback_fonts,
             scale_factor,
             images_cache,
+            layout_observers: RefCell::new(layout_observers),
         }
     }
 }
@@ -132,12 +142,28 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
         let node = self.rdom.get(node_id).unwrap();
         let size_state = &*node.get::<LayoutState>().unwrap();
 
+        let mut node_layout = NodeReferenceLayout {
+            area,
+            inner: inner_sizes,
+        };
+        node_layout.div(self.scale_factor);
+
+        if size_state.has_layout_listeners {
+            if let NodeType::Element(ElementNode { listeners, .. }) = &*node.node_type() {
+                self.layout_observers.borrow_mut().observe(
+                    node_id,
+                    listeners,
+                    ObservedLayout {
+                        area: node_layout.area,
+                        inner_sizes: node_layout.inner,
+                        offset_x: size_state.offset_x.get(),
+                        offset_y: size_state.offset_y.get(),
+                    },
+                );
+            }
+        }
+
         if let Some(reference) = &size_state.node_ref {
-            let mut node_layout = NodeReferenceLayout {
-                area,
-                inner: inner_sizes,
-            };
-            node_layout.div(self.scale_factor);
             reference.0.send(node_layout).ok();
         }
     }
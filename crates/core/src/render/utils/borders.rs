@@ -4,6 +4,7 @@ use torin::prelude::Area;
 use crate::values::{
     Border,
     BorderAlignment,
+    BorderStyle,
     CornerRadius,
 };
 
@@ -26,14 +27,155 @@ pub fn render_border(
 
     border.fill.apply_to_paint(&mut border_paint, area);
 
-    match border_shape(rect, corner_radius, border) {
+    let border_shape = border_shape(rect, corner_radius, border);
+
+    let Some((dash_intervals, cap)) = dash_intervals(border) else {
+        match border_shape {
+            BorderShape::DRRect(outer, inner) => {
+                canvas.draw_drrect(outer, inner, &border_paint);
+            }
+            BorderShape::Path(path) => {
+                canvas.draw_path(&path, &border_paint);
+            }
+        }
+        return;
+    };
+
+    // Dashes and dots are drawn as a stroke along the middle of the border, clipped by the border shape
+    // so every side keeps its own width.
+    canvas.save();
+
+    match border_shape {
         BorderShape::DRRect(outer, inner) => {
-            canvas.draw_drrect(outer, inner, &border_paint);
+            canvas.clip_rrect(outer, ClipOp::Intersect, true);
+            canvas.clip_rrect(inner, ClipOp::Difference, true);
         }
         BorderShape::Path(path) => {
-            canvas.draw_path(&path, &border_paint);
+            canvas.clip_path(&path, ClipOp::Intersect, true);
         }
     }
+
+    border_paint.set_style(PaintStyle::Stroke);
+    border_paint.set_stroke_width(border.width.max());
+    border_paint.set_stroke_cap(cap);
+    border_paint.set_path_effect(PathEffect::dash(&dash_intervals, 0.0));
+
+    canvas.draw_path(
+        &border_center_path(rect, corner_radius, border),
+        &border_paint,
+    );
+
+    canvas.restore();
+}
+
+/// Get the dash intervals and the stroke cap for the style of the given [`Border`], `None` if it is solid.
+fn dash_intervals(border: &Border) -> Option<([f32; 2], PaintCap)> {
+    let width = border.width.max();
+
+    match border.style {
+        BorderStyle::Solid => None,
+        BorderStyle::Dashed { dash, gap } => {
+            let dash = dash.unwrap_or(width * 3.0);
+            Some(([dash, gap.unwrap_or(dash)], PaintCap::Butt))
+        }
+        // Zero-length dashes with round caps are drawn as dots
+        BorderStyle::Dotted { gap } => Some(([0.0, width + gap.unwrap_or(width)], PaintCap::Round)),
+    }
+}
+
+/// Returns a `Path` that goes through the middle of a [`Border`] around a base rectangle.
+pub fn border_center_path(
+    base_rect: Rect,
+    base_corner_radius: &CornerRadius,
+    border: &Border,
+) -> Path {
+    let border_width = border.width;
+
+    // How much the middle of the border is inset from the base rectangle, relative to the border width
+    let inset = match border.alignment {
+        BorderAlignment::Inner => 0.5,
+        BorderAlignment::Center => 0.0,
+        BorderAlignment::Outer => -0.5,
+    };
+
+    let corner_radius = |corner_radius: f32, width_1: f32, width_2: f32| {
+        if corner_radius == 0.0 {
+            return corner_radius;
+        }
+        let offset = if width_1 == 0.0 {
+            width_2
+        } else if width_2 == 0.0 {
+            width_1
+        } else {
+            width_1.min(width_2)
+        };
+        (corner_radius - offset * inset).max(0.0)
+    };
+
+    let center_corner_radius = CornerRadius {
+        top_left: corner_radius(
+            base_corner_radius.top_left,
+            border_width.top,
+            border_width.left,
+        ),
+        top_right: corner_radius(
+            base_corner_radius.top_right,
+            border_width.top,
+            border_width.right,
+        ),
+        bottom_left: corner_radius(
+            base_corner_radius.bottom_left,
+            border_width.bottom,
+            border_width.left,
+        ),
+        bottom_right: corner_radius(
+            base_corner_radius.bottom_right,
+            border_width.bottom,
+            border_width.right,
+        ),
+        smoothing: base_corner_radius.smoothing,
+    };
+
+    let mut rect = base_rect;
+    rect.left += border_width.left * inset;
+    rect.top += border_width.top * inset;
+    rect.right -= border_width.right * inset;
+    rect.bottom -= border_width.bottom * inset;
+
+    let rrect = RRect::new_rect_radii(
+        rect,
+        &[
+            (center_corner_radius.top_left, center_corner_radius.top_left).into(),
+            (
+                center_corner_radius.top_right,
+                center_corner_radius.top_right,
+            )
+                .into(),
+            (
+                center_corner_radius.bottom_right,
+                center_corner_radius.bottom_right,
+            )
+                .into(),
+            (
+                center_corner_radius.bottom_left,
+                center_corner_radius.bottom_left,
+            )
+                .into(),
+        ],
+    );
+
+    let mut path = Path::new();
+    if base_corner_radius.smoothing > 0.0 {
+        path.add_path(
+            &center_corner_radius.smoothed_path(rrect),
+            SkPoint::new(rrect.rect().x(), rrect.rect().y()),
+            None,
+        );
+    } else {
+        path.add_rrect(rrect, None);
+    }
+
+    path
 }
 
 /// Returns a `Path` that will draw a [`Border`] around a base rectangle.
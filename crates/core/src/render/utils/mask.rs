@@ -0,0 +1,64 @@
+use freya_engine::prelude::*;
+use torin::prelude::Area;
+
+use crate::{
+    dom::ImagesCache,
+    states::ClipState,
+    values::{
+        MaskMode,
+        MaskSource,
+    },
+};
+
+/// Keep only the parts of the already drawn content that are visible through the mask of the given node.
+pub fn render_mask(
+    canvas: &Canvas,
+    area: Area,
+    matrix: &Matrix,
+    clip_state: &ClipState,
+    images_cache: &mut ImagesCache,
+) {
+    let Some(mask) = &clip_state.mask else {
+        return;
+    };
+
+    let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_blend_mode(BlendMode::DstIn);
+
+    if mask.mode == MaskMode::Luminance {
+        paint.set_color_filter(luma_color_filter::new());
+    }
+
+    canvas.save();
+    canvas.reset_matrix();
+    canvas.concat(matrix);
+
+    match &mask.source {
+        MaskSource::Fill(fill) => {
+            fill.apply_to_paint(&mut paint, area);
+            canvas.draw_rect(rect, &paint);
+        }
+        MaskSource::Image => {
+            let image_cache_key = clip_state.mask_cache_key();
+            let image = images_cache.get(&image_cache_key).cloned().or_else(|| {
+                let bytes = clip_state.mask_data.as_ref()?;
+                Image::from_encoded(unsafe { Data::new_bytes(bytes.as_slice()) }).inspect(|image| {
+                    images_cache.insert(image_cache_key, image.clone());
+                })
+            });
+
+            // Without a valid image nothing is visible through the mask
+            if let Some(image) = image {
+                canvas.draw_image_rect(image, None, rect, &paint);
+            } else {
+                paint.set_color(SkColor::TRANSPARENT);
+                canvas.draw_rect(rect, &paint);
+            }
+        }
+    }
+
+    canvas.restore();
+}
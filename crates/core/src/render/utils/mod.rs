@@ -1,11 +1,13 @@
 mod borders;
 mod image;
 mod label;
+mod mask;
 mod paragraph;
 mod shadows;
 
 pub use borders::*;
 pub use image::*;
 pub use label::*;
+pub use mask::*;
 pub use paragraph::*;
 pub use shadows::*;
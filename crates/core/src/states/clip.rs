@@ -0,0 +1,185 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_engine::prelude::{
+    Matrix,
+    Path,
+    Rect,
+};
+use freya_native_core::{
+    exports::shipyard::Component,
+    node::OwnedAttributeValue,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        AttributeName,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    NodeId,
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+use torin::prelude::Area;
+
+use crate::{
+    custom_attributes::{
+        AttributesBytes,
+        CustomAttributeValues,
+    },
+    dom::{
+        CompositorDirtyNodes,
+        ImageCacheKey,
+        ImagesCache,
+    },
+    parsing::{
+        Parse,
+        ParseAttribute,
+        ParseError,
+    },
+    values::{
+        ClipPath,
+        Mask,
+    },
+};
+
+#[derive(Default, Clone, Debug, Component, PartialEq)]
+pub struct ClipState {
+    pub node_id: NodeId,
+    pub clip_path: Option<ClipPath>,
+    pub mask: Option<Mask>,
+    pub mask_data: Option<AttributesBytes>,
+    /// Nodes with a clip path or a mask, this node and its ancestors.
+    pub clips: Vec<NodeId>,
+    /// Nodes with a mask, this node and its ancestors.
+    pub masks: Vec<NodeId>,
+}
+
+impl ParseAttribute for ClipState {
+    fn parse_attribute(
+        &mut self,
+        attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
+    ) -> Result<(), ParseError> {
+        match attr.attribute {
+            AttributeName::ClipPath => {
+                self.clip_path = Some(ClipPath::parse(attr.value.as_text().ok_or(ParseError)?)?);
+            }
+            AttributeName::Mask => {
+                self.mask = Some(Mask::parse(attr.value.as_text().ok_or(ParseError)?)?);
+            }
+            AttributeName::MaskData => {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) = attr.value
+                {
+                    self.mask_data = Some(bytes.clone());
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl ClipState {
+    /// Get the key of the decoded `mask_data` image in the [ImagesCache].
+    pub fn mask_cache_key(&self) -> ImageCacheKey {
+        ImageCacheKey(format!("mask-{:?}", self.node_id))
+    }
+
+    /// Get the paths that clip the content of this node, already transformed with the given matrix.
+    /// Masked nodes are also clipped to their own area.
+    pub fn clip_paths(&self, area: &Area, matrix: &Matrix, scale_factor: f32) -> Vec<Path> {
+        let mut paths = Vec::new();
+
+        if let Some(path) = self
+            .clip_path
+            .as_ref()
+            .and_then(|clip_path| clip_path.to_path(area, scale_factor))
+        {
+            paths.push(path.with_transform(matrix));
+        }
+
+        if self.mask.is_some() {
+            let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+            paths.push(Path::rect(rect, None).with_transform(matrix));
+        }
+
+        paths
+    }
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for ClipState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::ClipPath,
+            AttributeName::Mask,
+            AttributeName::MaskData,
+        ]));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let root_id = context.get::<NodeId>().unwrap();
+        let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
+        let inherited_clip = parent.map(|(p,)| p.clone()).unwrap_or_default();
+
+        let mut clip_state = ClipState {
+            node_id: node_view.node_id(),
+            clips: inherited_clip.clips,
+            masks: inherited_clip.masks,
+            ..Default::default()
+        };
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                clip_state.parse_safe(attr);
+            }
+        }
+
+        if clip_state.clip_path.is_some() || clip_state.mask.is_some() {
+            clip_state.clips.push(clip_state.node_id);
+        }
+
+        if clip_state.mask.is_some() {
+            clip_state.masks.push(clip_state.node_id);
+        }
+
+        let changed = clip_state != *self;
+
+        // Decode the mask image again next time it is rendered
+        if clip_state.mask_data != self.mask_data {
+            let images_cache = context.get::<Arc<Mutex<ImagesCache>>>().unwrap();
+            images_cache
+                .lock()
+                .unwrap()
+                .remove(&clip_state.mask_cache_key());
+        }
+
+        let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
+
+        if changed && !is_orphan {
+            compositor_dirty_nodes
+                .lock()
+                .unwrap()
+                .invalidate(node_view.node_id());
+        }
+
+        *self = clip_state;
+        changed
+    }
+}
@@ -169,12 +169,11 @@ impl ParseAttribute for FontStyleState {
                     .collect();
             }
             AttributeName::FontSize => {
-                self.font_size = attr
-                    .value
-                    .as_text()
-                    .ok_or(ParseError)?
-                    .parse()
-                    .map_err(|_| ParseError)?;
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                // Same exception as the color
+                if value != "inherit" {
+                    self.font_size = value.parse().map_err(|_| ParseError)?;
+                }
             }
             AttributeName::LineHeight => {
                 self.line_height = Some(
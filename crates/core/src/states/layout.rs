@@ -6,7 +6,11 @@ use std::sync::{
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
-    node::OwnedAttributeValue,
+    node::{
+        ElementNode,
+        NodeType,
+        OwnedAttributeValue,
+    },
     node_ref::NodeView,
     prelude::{
         AttributeMaskBuilder,
@@ -35,6 +39,10 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    values::{
+        parse_grid_areas,
+        parse_grid_tracks,
+    },
 };
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -60,8 +68,13 @@ pub struct LayoutState {
     pub wrap_content: WrapContent,
     #[cfg_attr(feature = "serde", serde(skip_deserializing, skip_serializing))]
     pub node_ref: Option<NodeReference>,
+    pub has_layout_listeners: bool,
     pub node_id: NodeId,
     pub spacing: Length,
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+    pub grid_areas: Vec<GridArea>,
+    pub grid_placement: GridPlacement,
 }
 
 impl ParseAttribute for LayoutState {
@@ -176,6 +189,52 @@ impl ParseAttribute for LayoutState {
             AttributeName::WrapContent => {
                 self.wrap_content = WrapContent::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::GridColumns => {
+                self.grid_columns = parse_grid_tracks(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridRows => {
+                self.grid_rows = parse_grid_tracks(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridAreas => {
+                self.grid_areas = parse_grid_areas(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridColumn => {
+                self.grid_placement.column = Some(
+                    attr.value
+                        .as_text()
+                        .ok_or(ParseError)?
+                        .parse::<usize>()
+                        .map_err(|_| ParseError)?,
+                );
+            }
+            AttributeName::GridRow => {
+                self.grid_placement.row = Some(
+                    attr.value
+                        .as_text()
+                        .ok_or(ParseError)?
+                        .parse::<usize>()
+                        .map_err(|_| ParseError)?,
+                );
+            }
+            AttributeName::GridColumnSpan => {
+                self.grid_placement.column_span = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse::<usize>()
+                    .map_err(|_| ParseError)?;
+            }
+            AttributeName::GridRowSpan => {
+                self.grid_placement.row_span = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse::<usize>()
+                    .map_err(|_| ParseError)?;
+            }
+            AttributeName::GridArea => {
+                self.grid_placement.area = Some(attr.value.as_text().ok_or(ParseError)?.to_owned());
+            }
             AttributeName::Reference => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(reference)) =
                     attr.value
@@ -206,8 +265,8 @@ impl State<CustomAttributeValues> for LayoutState {
 
     type NodeDependencies = ();
 
-    const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Width,
             AttributeName::Height,
             AttributeName::MinWidth,
@@ -232,7 +291,16 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::Content,
             AttributeName::WrapContent,
             AttributeName::Spacing,
-        ]));
+            AttributeName::GridColumns,
+            AttributeName::GridRows,
+            AttributeName::GridAreas,
+            AttributeName::GridColumn,
+            AttributeName::GridRow,
+            AttributeName::GridColumnSpan,
+            AttributeName::GridRowSpan,
+            AttributeName::GridArea,
+        ]))
+        .with_listeners();
 
     fn update<'a>(
         &mut self,
@@ -251,6 +319,10 @@ impl State<CustomAttributeValues> for LayoutState {
             ..Default::default()
         };
 
+        if let NodeType::Element(ElementNode { listeners, .. }) = node_view.node_type() {
+            layout.has_layout_listeners = listeners.iter().any(|listener| listener.is_layout());
+        }
+
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
                 layout.parse_safe(attr);
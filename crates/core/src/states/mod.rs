@@ -1,5 +1,6 @@
 mod accessibility;
 mod canvas;
+mod clip;
 mod cursor;
 mod font_style;
 mod image;
@@ -13,6 +14,7 @@ mod viewport;
 
 pub use accessibility::*;
 pub use canvas::*;
+pub use clip::*;
 pub use cursor::*;
 pub use font_style::*;
 pub use image::*;
@@ -3,7 +3,10 @@ use std::sync::{
     Mutex,
 };
 
-use freya_engine::prelude::BlendMode;
+use freya_engine::prelude::{
+    BlendMode,
+    Matrix,
+};
 use freya_native_core::{
     exports::shipyard::Component,
     node_ref::NodeView,
@@ -18,6 +21,7 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
+use torin::torin::Torin;
 
 use crate::{
     custom_attributes::CustomAttributeValues,
@@ -27,6 +31,11 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    values::{
+        Filter,
+        TransformOperation,
+        TransformOrigin,
+    },
 };
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -34,8 +43,15 @@ use crate::{
 pub struct TransformState {
     pub node_id: NodeId,
     pub opacities: Vec<f32>,
-    pub rotations: Vec<(NodeId, f32)>,
-    pub scales: Vec<(NodeId, f32, f32)>,
+    /// Filter of this node, applied once to it and its children.
+    pub filter: Option<Filter>,
+    /// Nodes with a filter, this node and its ancestors.
+    pub filtered: Vec<NodeId>,
+    /// How much the filters of this node and its ancestors can make it draw outside of its area, regardless of the scale factor.
+    pub filters_outset: f32,
+    /// Transformations of this node and its ancestors, in the order they must be applied.
+    pub transforms: Vec<(NodeId, TransformOperation)>,
+    pub transform_origins: Vec<(NodeId, TransformOrigin)>,
     #[cfg_attr(feature = "serde", serde(skip_serializing, skip_deserializing))]
     pub blend_mode: Option<BlendMode>,
     pub backdrop_blur: f32,
@@ -54,7 +70,8 @@ impl ParseAttribute for TransformState {
                         .trim_end_matches("deg")
                         .parse::<f32>()
                         .map_err(|_| ParseError)?;
-                    self.rotations.push((self.node_id, rotation));
+                    self.transforms
+                        .push((self.node_id, TransformOperation::Rotate(rotation)));
                 }
             }
             AttributeName::Opacity => {
@@ -62,6 +79,10 @@ impl ParseAttribute for TransformState {
                 let opacity = value.parse::<f32>().map_err(|_| ParseError)?;
                 self.opacities.push(opacity);
             }
+            AttributeName::Filter => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.filter = Some(Filter::parse(value)?);
+            }
             AttributeName::Scale => {
                 let value = attr.value.as_text().ok_or(ParseError)?;
                 let (scale_x, scale_y) = if !value.trim().contains(' ') {
@@ -73,8 +94,30 @@ impl ParseAttribute for TransformState {
                     let scale_y = y.parse::<f32>().map_err(|_| ParseError)?;
                     (scale_x, scale_y)
                 };
-                self.scales
-                    .push((self.node_id, scale_x.max(0.0), scale_y.max(0.0)));
+                self.transforms.push((
+                    self.node_id,
+                    TransformOperation::Scale(scale_x.max(0.0), scale_y.max(0.0)),
+                ));
+            }
+            AttributeName::Translate => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transforms
+                    .push((self.node_id, TransformOperation::parse_translate(value)?));
+            }
+            AttributeName::Skew => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transforms
+                    .push((self.node_id, TransformOperation::parse_skew(value)?));
+            }
+            AttributeName::Matrix => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transforms
+                    .push((self.node_id, TransformOperation::parse_matrix(value)?));
+            }
+            AttributeName::TransformOrigin => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.transform_origins
+                    .push((self.node_id, TransformOrigin::parse(value)?));
             }
 
             AttributeName::BlendMode => {
@@ -95,6 +138,44 @@ impl ParseAttribute for TransformState {
     }
 }
 
+impl TransformState {
+    /// Get the origin of the transformations declared in the given node.
+    pub fn origin_of(&self, node_id: NodeId) -> TransformOrigin {
+        self.transform_origins
+            .iter()
+            .rev()
+            .find(|(id, _)| *id == node_id)
+            .map(|(_, origin)| *origin)
+            .unwrap_or_default()
+    }
+
+    /// Combine the transformations of this node and its ancestors in a single matrix.
+    /// Returns `None` if there are no transformations.
+    pub fn matrix(&self, layout: &Torin<NodeId>, scale_factor: f32) -> Option<Matrix> {
+        let mut matrix = None;
+
+        for (node_id, operation) in &self.transforms {
+            let Some(layout_node) = layout.get(*node_id) else {
+                continue;
+            };
+            let (x, y) = self
+                .origin_of(*node_id)
+                .resolve(&layout_node.visible_area(), scale_factor);
+
+            // Apply the operation around its origin
+            let mut operation_matrix = Matrix::translate((x, y));
+            operation_matrix.pre_concat(&operation.to_matrix(scale_factor));
+            operation_matrix.pre_translate((-x, -y));
+
+            matrix
+                .get_or_insert_with(Matrix::new_identity)
+                .pre_concat(&operation_matrix);
+        }
+
+        matrix
+    }
+}
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for TransformState {
     type ParentDependencies = (Self,);
@@ -107,7 +188,12 @@ impl State<CustomAttributeValues> for TransformState {
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Rotate,
             AttributeName::Opacity,
+            AttributeName::Filter,
             AttributeName::Scale,
+            AttributeName::Translate,
+            AttributeName::Skew,
+            AttributeName::Matrix,
+            AttributeName::TransformOrigin,
             AttributeName::AspectRatio,
             AttributeName::ImageCover,
             AttributeName::BlendMode,
@@ -128,6 +214,7 @@ impl State<CustomAttributeValues> for TransformState {
 
         let mut transform_state = TransformState {
             node_id: node_view.node_id(),
+            filter: None,
             blend_mode: None,
             backdrop_blur: 0.,
             ..inherited_transform
@@ -139,6 +226,11 @@ impl State<CustomAttributeValues> for TransformState {
             }
         }
 
+        if let Some(filter) = &transform_state.filter {
+            transform_state.filtered.push(transform_state.node_id);
+            transform_state.filters_outset += filter.outset(1.);
+        }
+
         let changed = transform_state != *self;
 
         let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
@@ -18,6 +18,7 @@ pub struct Border {
     pub fill: Fill,
     pub width: BorderWidth,
     pub alignment: BorderAlignment,
+    pub style: BorderStyle,
 }
 
 impl Border {
@@ -40,6 +41,13 @@ pub struct BorderWidth {
     pub left: f32,
 }
 
+impl BorderWidth {
+    /// Get the width of the widest side.
+    pub fn max(&self) -> f32 {
+        self.top.max(self.right).max(self.bottom).max(self.left)
+    }
+}
+
 impl Scaled for BorderWidth {
     fn scale(&mut self, scale_factor: f32) {
         self.top *= scale_factor;
@@ -89,132 +97,180 @@ impl fmt::Display for BorderAlignment {
     }
 }
 
-impl Parse for Border {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum BorderStyle {
+    #[default]
+    Solid,
+    /// Dashes of the given length separated by the given gap, 3 times the border width by default.
+    Dashed { dash: Option<f32>, gap: Option<f32> },
+    /// Dots separated by the given gap, the border width by default.
+    Dotted { gap: Option<f32> },
+}
+
+impl BorderStyle {
+    /// Check if the given value is a border style rather than e.g a fill.
+    fn is_style(value: &str) -> bool {
+        ["solid", "dashed", "dotted"]
+            .iter()
+            .any(|style| value.starts_with(style))
+    }
+}
+
+impl Parse for BorderStyle {
     fn parse(value: &str) -> Result<Self, ParseError> {
-        if value == "none" {
-            return Ok(Self::default());
+        let (name, lengths) = match value.split_once('(') {
+            Some((name, lengths)) => (name, lengths.strip_suffix(')').ok_or(ParseError)?),
+            None => (value, ""),
+        };
+        let lengths = lengths
+            .split(',')
+            .map(str::trim)
+            .filter(|length| !length.is_empty())
+            .map(|length| length.parse::<f32>().map_err(|_| ParseError))
+            .collect::<Result<Vec<f32>, ParseError>>()?;
+
+        match (name, &lengths[..]) {
+            ("solid", []) => Ok(Self::Solid),
+            ("dashed", []) => Ok(Self::Dashed {
+                dash: None,
+                gap: None,
+            }),
+            ("dashed", [dash]) => Ok(Self::Dashed {
+                dash: Some(*dash),
+                gap: Some(*dash),
+            }),
+            ("dashed", [dash, gap]) => Ok(Self::Dashed {
+                dash: Some(*dash),
+                gap: Some(*gap),
+            }),
+            ("dotted", []) => Ok(Self::Dotted { gap: None }),
+            ("dotted", [gap]) => Ok(Self::Dotted { gap: Some(*gap) }),
+            _ => Err(ParseError),
         }
+    }
+}
 
-        let mut border_values = value.split_ascii_whitespace_excluding_group('(', ')');
+impl fmt::Display for BorderStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Solid => f.write_str("solid"),
+            Self::Dashed {
+                dash: Some(dash),
+                gap: Some(gap),
+            } => write!(f, "dashed({dash}, {gap})"),
+            Self::Dashed { .. } => f.write_str("dashed"),
+            Self::Dotted { gap: Some(gap) } => write!(f, "dotted({gap})"),
+            Self::Dotted { gap: None } => f.write_str("dotted"),
+        }
+    }
+}
 
-        Ok(match border_values.clone().count() {
-            // <width> <style> <fill>
-            3 => {
-                let width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: width,
-                        left: width,
-                        bottom: width,
-                        right: width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
+impl Scaled for BorderStyle {
+    fn scale(&mut self, scale_factor: f32) {
+        match self {
+            Self::Solid => {}
+            Self::Dashed { dash, gap } => {
+                if let Some(dash) = dash {
+                    *dash *= scale_factor;
                 }
-            }
-
-            // <vertical> <horizontal> <solid> <fill>
-            4 => {
-                let vertical_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let horizontal_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: vertical_width,
-                        left: horizontal_width,
-                        bottom: vertical_width,
-                        right: horizontal_width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
+                if let Some(gap) = gap {
+                    *gap *= scale_factor;
                 }
             }
-            // <top> <horizontal> <bottom> <style> <fill>
-            5 => {
-                let top_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let horizontal_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-                let bottom_width = border_values
-                    .next()
-                    .ok_or(ParseError)?
-                    .parse::<f32>()
-                    .map_err(|_| ParseError)?;
-
-                Border {
-                    width: BorderWidth {
-                        top: top_width,
-                        left: horizontal_width,
-                        bottom: bottom_width,
-                        right: horizontal_width,
-                    },
-                    alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                    fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                        .map_err(|_| ParseError)?,
+            Self::Dotted { gap } => {
+                if let Some(gap) = gap {
+                    *gap *= scale_factor;
                 }
             }
-            // <top> <right> <bottom> <left> <style> <fill>
-            6 => Border {
-                width: BorderWidth {
-                    top: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    right: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    bottom: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                    left: border_values
-                        .next()
-                        .ok_or(ParseError)?
-                        .parse::<f32>()
-                        .map_err(|_| ParseError)?,
-                },
-                alignment: BorderAlignment::parse(border_values.next().ok_or(ParseError)?)?,
-                fill: Fill::parse(&border_values.collect::<Vec<&str>>().join(" "))
-                    .map_err(|_| ParseError)?,
+        }
+    }
+}
+
+impl Parse for Border {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if value == "none" {
+            return Ok(Self::default());
+        }
+
+        let mut border_values = value.split_ascii_whitespace_excluding_group('(', ')');
+
+        // <top> [<right> [<bottom> [<left>]]] <alignment> [<style>] <fill>
+        let mut widths = Vec::with_capacity(4);
+        let mut next_value = border_values.next().ok_or(ParseError)?;
+        while let Ok(width) = next_value.parse::<f32>() {
+            widths.push(width);
+            next_value = border_values.next().ok_or(ParseError)?;
+        }
+
+        let width = match widths[..] {
+            [width] => BorderWidth {
+                top: width,
+                right: width,
+                bottom: width,
+                left: width,
+            },
+            [vertical, horizontal] => BorderWidth {
+                top: vertical,
+                right: horizontal,
+                bottom: vertical,
+                left: horizontal,
+            },
+            [top, horizontal, bottom] => BorderWidth {
+                top,
+                right: horizontal,
+                bottom,
+                left: horizontal,
+            },
+            [top, right, bottom, left] => BorderWidth {
+                top,
+                right,
+                bottom,
+                left,
             },
             _ => return Err(ParseError),
+        };
+
+        let alignment = BorderAlignment::parse(next_value)?;
+
+        let mut fill_values = border_values.collect::<Vec<&str>>();
+        let style = match fill_values.first() {
+            Some(style) if BorderStyle::is_style(style) => {
+                BorderStyle::parse(fill_values.remove(0))?
+            }
+            _ => BorderStyle::default(),
+        };
+
+        if fill_values.is_empty() {
+            return Err(ParseError);
+        }
+
+        Ok(Border {
+            width,
+            alignment,
+            style,
+            fill: Fill::parse(&fill_values.join(" ")).map_err(|_| ParseError)?,
         })
     }
 }
 
 impl fmt::Display for Border {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {} {}", self.width, self.alignment, self.fill,)
+        if self.style == BorderStyle::Solid {
+            write!(f, "{} {} {}", self.width, self.alignment, self.fill)
+        } else {
+            write!(
+                f,
+                "{} {} {} {}",
+                self.width, self.alignment, self.style, self.fill
+            )
+        }
     }
 }
 
 impl Scaled for Border {
     fn scale(&mut self, scale_factor: f32) {
         self.width.scale(scale_factor);
+        self.style.scale(scale_factor);
     }
 }
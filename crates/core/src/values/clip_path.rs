@@ -0,0 +1,243 @@
+use freya_engine::prelude::{
+    Matrix,
+    Path,
+    RRect,
+    Rect,
+};
+use torin::prelude::Area;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// A length of a [ClipPath] shape.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClipLength {
+    /// Percentage of the element size.
+    Percentage(f32),
+    Pixels(f32),
+}
+
+impl ClipLength {
+    fn resolve(&self, size: f32, scale_factor: f32) -> f32 {
+        match self {
+            Self::Percentage(percentage) => size * percentage / 100.,
+            Self::Pixels(pixels) => pixels * scale_factor,
+        }
+    }
+}
+
+impl Parse for ClipLength {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if let Some(percentage) = value.strip_suffix('%') {
+            Ok(Self::Percentage(
+                percentage.parse().map_err(|_| ParseError)?,
+            ))
+        } else {
+            Ok(Self::Pixels(value.parse().map_err(|_| ParseError)?))
+        }
+    }
+}
+
+/// Shape that clips an element and its children.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipPath {
+    /// Circle whose radius percentage is relative to the smallest side of the element.
+    Circle {
+        radius: ClipLength,
+        center: (ClipLength, ClipLength),
+    },
+    Ellipse {
+        radius: (ClipLength, ClipLength),
+        center: (ClipLength, ClipLength),
+    },
+    Polygon(Vec<(ClipLength, ClipLength)>),
+    /// Rectangle inset from the element edges, with optionally rounded corners.
+    Inset {
+        top: ClipLength,
+        right: ClipLength,
+        bottom: ClipLength,
+        left: ClipLength,
+        radius: f32,
+    },
+    /// SVG path data, relative to the top left corner of the element.
+    Path(String),
+}
+
+impl ClipPath {
+    /// Create the [Path] of this shape for the given element area.
+    pub fn to_path(&self, area: &Area, scale_factor: f32) -> Option<Path> {
+        let (width, height) = (area.width(), area.height());
+        let point = |(x, y): &(ClipLength, ClipLength)| {
+            (
+                area.min_x() + x.resolve(width, scale_factor),
+                area.min_y() + y.resolve(height, scale_factor),
+            )
+        };
+
+        let mut path = Path::new();
+
+        match self {
+            Self::Circle { radius, center } => {
+                let radius = radius.resolve(width.min(height), scale_factor);
+                let (x, y) = point(center);
+                path.add_oval(
+                    Rect::new(x - radius, y - radius, x + radius, y + radius),
+                    None,
+                );
+            }
+            Self::Ellipse { radius, center } => {
+                let (radius_x, radius_y) = (
+                    radius.0.resolve(width, scale_factor),
+                    radius.1.resolve(height, scale_factor),
+                );
+                let (x, y) = point(center);
+                path.add_oval(
+                    Rect::new(x - radius_x, y - radius_y, x + radius_x, y + radius_y),
+                    None,
+                );
+            }
+            Self::Polygon(points) => {
+                let mut points = points.iter().map(point);
+                path.move_to(points.next()?);
+                for point in points {
+                    path.line_to(point);
+                }
+                path.close();
+            }
+            Self::Inset {
+                top,
+                right,
+                bottom,
+                left,
+                radius,
+            } => {
+                let rect = Rect::new(
+                    area.min_x() + left.resolve(width, scale_factor),
+                    area.min_y() + top.resolve(height, scale_factor),
+                    area.max_x() - right.resolve(width, scale_factor),
+                    area.max_y() - bottom.resolve(height, scale_factor),
+                );
+                let radius = radius * scale_factor;
+                path.add_rrect(
+                    RRect::new_rect_radii(rect, &[(radius, radius).into(); 4]),
+                    None,
+                );
+            }
+            Self::Path(data) => {
+                let mut matrix = Matrix::translate((area.min_x(), area.min_y()));
+                matrix.pre_concat(&Matrix::scale((scale_factor, scale_factor)));
+                path = Path::from_svg(data)?.with_transform(&matrix);
+            }
+        }
+
+        Some(path)
+    }
+}
+
+/// Split the arguments of a shape function, e.g `circle(50% at 10 10)`.
+fn function_args<'a>(value: &'a str, name: &str) -> Option<&'a str> {
+    value
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+        .map(str::trim)
+}
+
+/// Parse the optional `at <x> <y>` part of circles and ellipses, the center by default.
+fn parse_center(value: Option<&str>) -> Result<(ClipLength, ClipLength), ParseError> {
+    let Some(value) = value else {
+        return Ok((ClipLength::Percentage(50.), ClipLength::Percentage(50.)));
+    };
+    let (x, y) = value.trim().split_once(' ').ok_or(ParseError)?;
+    Ok((ClipLength::parse(x.trim())?, ClipLength::parse(y.trim())?))
+}
+
+impl Parse for ClipPath {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let value = value.trim();
+
+        if let Some(args) = function_args(value, "circle") {
+            let (radius, center) = match args.split_once("at") {
+                Some((radius, center)) => (radius.trim(), Some(center)),
+                None => (args, None),
+            };
+            let radius = if radius.is_empty() {
+                ClipLength::Percentage(50.)
+            } else {
+                ClipLength::parse(radius)?
+            };
+
+            Ok(Self::Circle {
+                radius,
+                center: parse_center(center)?,
+            })
+        } else if let Some(args) = function_args(value, "ellipse") {
+            let (radius, center) = match args.split_once("at") {
+                Some((radius, center)) => (radius.trim(), Some(center)),
+                None => (args, None),
+            };
+            let radius = if radius.is_empty() {
+                (ClipLength::Percentage(50.), ClipLength::Percentage(50.))
+            } else {
+                let (x, y) = radius.split_once(' ').ok_or(ParseError)?;
+                (ClipLength::parse(x.trim())?, ClipLength::parse(y.trim())?)
+            };
+
+            Ok(Self::Ellipse {
+                radius,
+                center: parse_center(center)?,
+            })
+        } else if let Some(args) = function_args(value, "polygon") {
+            let points = args
+                .split(',')
+                .map(|point| {
+                    let (x, y) = point.trim().split_once(' ').ok_or(ParseError)?;
+                    Ok((ClipLength::parse(x.trim())?, ClipLength::parse(y.trim())?))
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+
+            if points.len() < 3 {
+                return Err(ParseError);
+            }
+
+            Ok(Self::Polygon(points))
+        } else if let Some(args) = function_args(value, "inset") {
+            let (insets, radius) = match args.split_once("round") {
+                Some((insets, radius)) => (
+                    insets,
+                    radius.trim().parse::<f32>().map_err(|_| ParseError)?,
+                ),
+                None => (args, 0.),
+            };
+            let insets = insets
+                .split_ascii_whitespace()
+                .map(ClipLength::parse)
+                .collect::<Result<Vec<_>, ParseError>>()?;
+
+            let (top, right, bottom, left) = match insets[..] {
+                [all] => (all, all, all, all),
+                [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+                [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+                [top, right, bottom, left] => (top, right, bottom, left),
+                _ => return Err(ParseError),
+            };
+
+            Ok(Self::Inset {
+                top,
+                right,
+                bottom,
+                left,
+                radius,
+            })
+        } else if let Some(args) = function_args(value, "path") {
+            Ok(Self::Path(args.trim_matches(['"', '\'']).to_string()))
+        } else {
+            Err(ParseError)
+        }
+    }
+}
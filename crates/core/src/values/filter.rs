@@ -0,0 +1,291 @@
+use freya_engine::prelude::{
+    color_filters,
+    image_filters,
+    ImageFilter,
+};
+
+use crate::{
+    parsing::{
+        ExtSplit,
+        Parse,
+        ParseError,
+    },
+    values::Color,
+};
+
+/// A single function of a [Filter].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FilterFunction {
+    /// Gaussian blur with the given standard deviation.
+    Blur(f32),
+    Brightness(f32),
+    Contrast(f32),
+    Grayscale(f32),
+    /// Hue rotation in degrees.
+    HueRotate(f32),
+    Invert(f32),
+    Saturate(f32),
+    Sepia(f32),
+    DropShadow {
+        x: f32,
+        y: f32,
+        blur: f32,
+        color: Color,
+    },
+}
+
+impl FilterFunction {
+    /// Create an [ImageFilter] that applies this function on top of the given input.
+    pub fn to_image_filter(
+        &self,
+        input: Option<ImageFilter>,
+        scale_factor: f32,
+    ) -> Option<ImageFilter> {
+        match *self {
+            Self::Blur(sigma) => {
+                let sigma = sigma * scale_factor;
+                image_filters::blur(
+                    (sigma, sigma),
+                    None,
+                    input,
+                    image_filters::CropRect::NO_CROP_RECT,
+                )
+            }
+            Self::DropShadow { x, y, blur, color } => {
+                // Just like shadows, the blur radius is twice the standard deviation
+                let sigma = blur / 2. * scale_factor;
+                image_filters::drop_shadow(
+                    (x * scale_factor, y * scale_factor),
+                    (sigma, sigma),
+                    color,
+                    None,
+                    input,
+                    image_filters::CropRect::NO_CROP_RECT,
+                )
+            }
+            _ => image_filters::color_filter(
+                color_filters::matrix_row_major(&self.color_matrix(), None),
+                input,
+                image_filters::CropRect::NO_CROP_RECT,
+            ),
+        }
+    }
+
+    /// Get the row-major color matrix of this function, or the identity if it is not a color function.
+    /// See <https://www.w3.org/TR/filter-effects-1/#ShorthandEquivalents>.
+    pub fn color_matrix(&self) -> [f32; 20] {
+        match *self {
+            Self::Brightness(amount) => {
+                rgb_matrix([amount, 0., 0., 0., amount, 0., 0., 0., amount], 0.)
+            }
+            Self::Contrast(amount) => rgb_matrix(
+                [amount, 0., 0., 0., amount, 0., 0., 0., amount],
+                (1. - amount) / 2.,
+            ),
+            Self::Grayscale(amount) => {
+                let s = 1. - amount.min(1.);
+                rgb_matrix(
+                    [
+                        0.2126 + 0.7874 * s,
+                        0.7152 - 0.7152 * s,
+                        0.0722 - 0.0722 * s,
+                        0.2126 - 0.2126 * s,
+                        0.7152 + 0.2848 * s,
+                        0.0722 - 0.0722 * s,
+                        0.2126 - 0.2126 * s,
+                        0.7152 - 0.7152 * s,
+                        0.0722 + 0.9278 * s,
+                    ],
+                    0.,
+                )
+            }
+            Self::HueRotate(degrees) => {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                rgb_matrix(
+                    [
+                        0.213 + cos * 0.787 - sin * 0.213,
+                        0.715 - cos * 0.715 - sin * 0.715,
+                        0.072 - cos * 0.072 + sin * 0.928,
+                        0.213 - cos * 0.213 + sin * 0.143,
+                        0.715 + cos * 0.285 + sin * 0.140,
+                        0.072 - cos * 0.072 - sin * 0.283,
+                        0.213 - cos * 0.213 - sin * 0.787,
+                        0.715 - cos * 0.715 + sin * 0.715,
+                        0.072 + cos * 0.928 + sin * 0.072,
+                    ],
+                    0.,
+                )
+            }
+            Self::Invert(amount) => {
+                let amount = amount.min(1.);
+                let slope = 1. - 2. * amount;
+                rgb_matrix([slope, 0., 0., 0., slope, 0., 0., 0., slope], amount)
+            }
+            Self::Saturate(s) => rgb_matrix(
+                [
+                    0.213 + 0.787 * s,
+                    0.715 - 0.715 * s,
+                    0.072 - 0.072 * s,
+                    0.213 - 0.213 * s,
+                    0.715 + 0.285 * s,
+                    0.072 - 0.072 * s,
+                    0.213 - 0.213 * s,
+                    0.715 - 0.715 * s,
+                    0.072 + 0.928 * s,
+                ],
+                0.,
+            ),
+            Self::Sepia(amount) => {
+                let s = 1. - amount.min(1.);
+                rgb_matrix(
+                    [
+                        0.393 + 0.607 * s,
+                        0.769 - 0.769 * s,
+                        0.189 - 0.189 * s,
+                        0.349 - 0.349 * s,
+                        0.686 + 0.314 * s,
+                        0.168 - 0.168 * s,
+                        0.272 - 0.272 * s,
+                        0.534 - 0.534 * s,
+                        0.131 + 0.869 * s,
+                    ],
+                    0.,
+                )
+            }
+            Self::Blur(_) | Self::DropShadow { .. } => {
+                rgb_matrix([1., 0., 0., 0., 1., 0., 0., 0., 1.], 0.)
+            }
+        }
+    }
+
+    /// How much this function can draw outside of the element.
+    pub fn outset(&self, scale_factor: f32) -> f32 {
+        match *self {
+            Self::Blur(sigma) => sigma * 3. * scale_factor,
+            Self::DropShadow { x, y, blur, .. } => {
+                (x.abs().max(y.abs()) + blur * 1.5) * scale_factor
+            }
+            _ => 0.,
+        }
+    }
+}
+
+/// Expand a 3x3 RGB matrix into a 4x5 color matrix that keeps the alpha.
+fn rgb_matrix(m: [f32; 9], offset: f32) -> [f32; 20] {
+    [
+        m[0], m[1], m[2], 0., offset, //
+        m[3], m[4], m[5], 0., offset, //
+        m[6], m[7], m[8], 0., offset, //
+        0., 0., 0., 1., 0.,
+    ]
+}
+
+/// Parse an amount like `50%` or `0.5`, `1` by default.
+fn parse_amount(value: &str) -> Result<f32, ParseError> {
+    let amount = if value.is_empty() {
+        1.
+    } else if let Some(percentage) = value.strip_suffix('%') {
+        percentage.parse::<f32>().map_err(|_| ParseError)? / 100.
+    } else {
+        value.parse::<f32>().map_err(|_| ParseError)?
+    };
+
+    if amount < 0. {
+        return Err(ParseError);
+    }
+
+    Ok(amount)
+}
+
+impl Parse for FilterFunction {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let (name, args) = value.split_once('(').ok_or(ParseError)?;
+        let args = args.strip_suffix(')').ok_or(ParseError)?.trim();
+
+        match name.trim() {
+            "blur" if args.is_empty() => Ok(Self::Blur(0.)),
+            "blur" => Ok(Self::Blur(args.parse().map_err(|_| ParseError)?)),
+            "brightness" => Ok(Self::Brightness(parse_amount(args)?)),
+            "contrast" => Ok(Self::Contrast(parse_amount(args)?)),
+            "grayscale" => Ok(Self::Grayscale(parse_amount(args)?)),
+            "hue-rotate" if args.is_empty() => Ok(Self::HueRotate(0.)),
+            "hue-rotate" => Ok(Self::HueRotate(
+                args.strip_suffix("deg")
+                    .ok_or(ParseError)?
+                    .parse()
+                    .map_err(|_| ParseError)?,
+            )),
+            "invert" => Ok(Self::Invert(parse_amount(args)?)),
+            "saturate" => Ok(Self::Saturate(parse_amount(args)?)),
+            "sepia" => Ok(Self::Sepia(parse_amount(args)?)),
+            "drop-shadow" => {
+                let mut values = args.split_ascii_whitespace_excluding_group('(', ')');
+                let mut next_number = || -> Result<f32, ParseError> {
+                    values
+                        .next()
+                        .ok_or(ParseError)?
+                        .parse::<f32>()
+                        .map_err(|_| ParseError)
+                };
+                let x = next_number()?;
+                let y = next_number()?;
+
+                let mut blur = 0.;
+                let mut color = Color::BLACK;
+                for value in values {
+                    if let Ok(value) = value.parse::<f32>() {
+                        blur = value;
+                    } else {
+                        color = Color::parse(value)?;
+                    }
+                }
+
+                Ok(Self::DropShadow { x, y, blur, color })
+            }
+            _ => Err(ParseError),
+        }
+    }
+}
+
+/// Chain of [FilterFunction]s applied in order to an element and its children, e.g `grayscale(100%) blur(4)`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Filter {
+    pub functions: Vec<FilterFunction>,
+}
+
+impl Filter {
+    /// Combine all the functions in a single [ImageFilter].
+    pub fn to_image_filter(&self, scale_factor: f32) -> Option<ImageFilter> {
+        self.functions
+            .iter()
+            .try_fold(None, |input, function| {
+                function.to_image_filter(input, scale_factor).map(Some)
+            })
+            .flatten()
+    }
+
+    /// How much this filter can draw outside of the element.
+    pub fn outset(&self, scale_factor: f32) -> f32 {
+        self.functions
+            .iter()
+            .map(|function| function.outset(scale_factor))
+            .sum()
+    }
+}
+
+impl Parse for Filter {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let functions = value
+            .split_ascii_whitespace_excluding_group('(', ')')
+            .map(FilterFunction::parse)
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        if functions.is_empty() {
+            return Err(ParseError);
+        }
+
+        Ok(Self { functions })
+    }
+}
@@ -0,0 +1,80 @@
+use torin::{
+    geometry::Length,
+    grid::{
+        GridArea,
+        GridTrack,
+    },
+};
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for GridTrack {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if value == "auto" {
+            Ok(GridTrack::Auto)
+        } else if value == "flex" {
+            Ok(GridTrack::Flex(Length::new(1.0)))
+        } else if value.contains("flex") {
+            Ok(GridTrack::Flex(Length::new(
+                value
+                    .replace("flex(", "")
+                    .replace(')', "")
+                    .parse::<f32>()
+                    .map_err(|_| ParseError)?,
+            )))
+        } else if value.contains('%') {
+            Ok(GridTrack::Percentage(Length::new(
+                value
+                    .replace('%', "")
+                    .parse::<f32>()
+                    .map_err(|_| ParseError)?,
+            )))
+        } else {
+            Ok(GridTrack::Pixels(Length::new(
+                value.parse::<f32>().map_err(|_| ParseError)?,
+            )))
+        }
+    }
+}
+
+/// Parse a list of grid tracks separated by whitespaces, e.g `100 flex(2) auto 25%`.
+pub fn parse_grid_tracks(value: &str) -> Result<Vec<GridTrack>, ParseError> {
+    value
+        .split_ascii_whitespace()
+        .map(GridTrack::parse)
+        .collect()
+}
+
+/// Parse a grid areas template where rows are separated by commas and cells by whitespaces,
+/// e.g `header header, sidebar main`. Every area must have a rectangular shape.
+pub fn parse_grid_areas(value: &str) -> Result<Vec<GridArea>, ParseError> {
+    let template = value
+        .split(',')
+        .map(|row| row.split_ascii_whitespace().collect::<Vec<&str>>())
+        .collect::<Vec<_>>();
+
+    let columns = template.first().map(Vec::len).unwrap_or_default();
+    if columns == 0 || template.iter().any(|row| row.len() != columns) {
+        return Err(ParseError);
+    }
+
+    let areas = GridArea::from_template(&template);
+
+    for area in &areas {
+        let is_rectangle = template[area.row..area.row + area.row_span]
+            .iter()
+            .all(|row| {
+                row[area.column..area.column + area.column_span]
+                    .iter()
+                    .all(|name| *name == area.name)
+            });
+        if !is_rectangle {
+            return Err(ParseError);
+        }
+    }
+
+    Ok(areas)
+}
@@ -0,0 +1,62 @@
+use crate::{
+    parsing::{
+        ExtSplit,
+        Parse,
+        ParseError,
+    },
+    values::Fill,
+};
+
+/// What channel of a [Mask] is used to decide the visibility.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum MaskMode {
+    #[default]
+    Alpha,
+    Luminance,
+}
+
+/// Source of a [Mask].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MaskSource {
+    Fill(Fill),
+    /// The image passed with the `mask_data` attribute.
+    Image,
+}
+
+/// Hide parts of an element and its children using the alpha or luminance of a fill or an image.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mask {
+    pub source: MaskSource,
+    pub mode: MaskMode,
+}
+
+impl Parse for Mask {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut values = value
+            .split_ascii_whitespace_excluding_group('(', ')')
+            .collect::<Vec<_>>();
+
+        let mode = match values.last() {
+            Some(&"alpha") => {
+                values.pop();
+                MaskMode::Alpha
+            }
+            Some(&"luminance") => {
+                values.pop();
+                MaskMode::Luminance
+            }
+            _ => MaskMode::default(),
+        };
+
+        let source = match values[..] {
+            [] => return Err(ParseError),
+            ["image"] => MaskSource::Image,
+            _ => MaskSource::Fill(Fill::parse(&values.join(" "))?),
+        };
+
+        Ok(Self { source, mode })
+    }
+}
@@ -3,21 +3,25 @@ mod alignment;
 mod aspect_ratio;
 mod blend_mode;
 mod border;
+mod clip_path;
 mod color;
 mod content;
 mod corner_radius;
 mod cursor;
 mod decoration;
 mod fill;
+mod filter;
 mod focusable;
 mod font_slant;
 mod font_weight;
 mod font_width;
 mod gaps;
 mod gradient;
+mod grid;
 mod highlight;
 mod image_cover;
 mod layer;
+mod mask;
 mod overflow;
 mod paint;
 mod position;
@@ -28,24 +32,29 @@ mod text_align;
 mod text_height;
 mod text_overflow;
 mod text_shadow;
+mod transform;
 mod visible_size;
 mod wrap_content;
 
 pub use aspect_ratio::*;
 pub use border::*;
+pub use clip_path::*;
 pub use color::*;
 pub use corner_radius::*;
 pub use cursor::*;
 pub use decoration::*;
 pub use fill::*;
+pub use filter::*;
 pub use focusable::*;
 pub use font_slant::*;
 pub use font_weight::*;
 pub use font_width::*;
 pub use gradient::*;
+pub use grid::*;
 pub use highlight::*;
 pub use image_cover::*;
 pub use layer::*;
+pub use mask::*;
 pub use overflow::*;
 pub use paint::*;
 pub use sampling::*;
@@ -55,3 +64,4 @@ pub use text_align::*;
 pub use text_height::*;
 pub use text_overflow::*;
 pub use text_shadow::*;
+pub use transform::*;
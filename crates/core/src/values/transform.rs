@@ -0,0 +1,169 @@
+use freya_engine::prelude::Matrix;
+use torin::prelude::Area;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// A 2D transformation of an element.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransformOperation {
+    /// Rotation in degrees.
+    Rotate(f32),
+    Scale(f32, f32),
+    Translate(f32, f32),
+    /// Skew in degrees for the x and y axis.
+    Skew(f32, f32),
+    /// Affine matrix in the `a, b, c, d, e, f` form.
+    Matrix([f32; 6]),
+}
+
+impl TransformOperation {
+    /// Get the matrix of this operation, without considering the origin.
+    pub fn to_matrix(&self, scale_factor: f32) -> Matrix {
+        match *self {
+            Self::Rotate(degrees) => Matrix::rotate_deg(degrees),
+            Self::Scale(x, y) => Matrix::scale((x, y)),
+            Self::Translate(x, y) => Matrix::translate((x * scale_factor, y * scale_factor)),
+            Self::Skew(x, y) => Matrix::skew((x.to_radians().tan(), y.to_radians().tan())),
+            Self::Matrix([a, b, c, d, e, f]) => {
+                Matrix::new_all(a, c, e * scale_factor, b, d, f * scale_factor, 0., 0., 1.)
+            }
+        }
+    }
+
+    /// Parse a `translate` value, `<x>` or `<x> <y>`.
+    pub fn parse_translate(value: &str) -> Result<Self, ParseError> {
+        match parse_values(value, "")?[..] {
+            [x] => Ok(Self::Translate(x, 0.)),
+            [x, y] => Ok(Self::Translate(x, y)),
+            _ => Err(ParseError),
+        }
+    }
+
+    /// Parse a `skew` value, `<x>deg` or `<x>deg <y>deg`.
+    pub fn parse_skew(value: &str) -> Result<Self, ParseError> {
+        match parse_values(value, "deg")?[..] {
+            [x] => Ok(Self::Skew(x, 0.)),
+            [x, y] => Ok(Self::Skew(x, y)),
+            _ => Err(ParseError),
+        }
+    }
+
+    /// Parse a `matrix` value, `<a>, <b>, <c>, <d>, <e>, <f>`.
+    pub fn parse_matrix(value: &str) -> Result<Self, ParseError> {
+        let values = parse_values(value, "")?;
+        Ok(Self::Matrix(values.try_into().map_err(|_| ParseError)?))
+    }
+}
+
+/// Position of a [TransformOrigin] in one axis.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OriginPosition {
+    /// Percentage of the element size.
+    Percentage(f32),
+    /// Pixels from the start of the element.
+    Pixels(f32),
+}
+
+impl OriginPosition {
+    fn resolve(&self, start: f32, size: f32, scale_factor: f32) -> f32 {
+        match self {
+            Self::Percentage(percentage) => start + size * percentage / 100.,
+            Self::Pixels(pixels) => start + pixels * scale_factor,
+        }
+    }
+}
+
+/// Point around which the transformations of an element are applied, the center by default.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TransformOrigin {
+    pub x: OriginPosition,
+    pub y: OriginPosition,
+}
+
+impl Default for TransformOrigin {
+    fn default() -> Self {
+        Self {
+            x: OriginPosition::Percentage(50.),
+            y: OriginPosition::Percentage(50.),
+        }
+    }
+}
+
+impl TransformOrigin {
+    /// Get the origin point in the given area.
+    pub fn resolve(&self, area: &Area, scale_factor: f32) -> (f32, f32) {
+        (
+            self.x.resolve(area.min_x(), area.width(), scale_factor),
+            self.y.resolve(area.min_y(), area.height(), scale_factor),
+        )
+    }
+}
+
+impl Parse for TransformOrigin {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let parts = value.split_ascii_whitespace().collect::<Vec<_>>();
+
+        // Keywords can be in any order, e.g `top left`
+        let (x, y) = match parts[..] {
+            [part] => match part {
+                "top" | "bottom" => ("center", part),
+                _ => (part, "center"),
+            },
+            [first, second]
+                if matches!(first, "top" | "bottom") || matches!(second, "left" | "right") =>
+            {
+                (second, first)
+            }
+            [first, second] => (first, second),
+            _ => return Err(ParseError),
+        };
+
+        let x = match x {
+            "left" => OriginPosition::Percentage(0.),
+            "center" => OriginPosition::Percentage(50.),
+            "right" => OriginPosition::Percentage(100.),
+            x => parse_position(x)?,
+        };
+        let y = match y {
+            "top" => OriginPosition::Percentage(0.),
+            "center" => OriginPosition::Percentage(50.),
+            "bottom" => OriginPosition::Percentage(100.),
+            y => parse_position(y)?,
+        };
+
+        Ok(Self { x, y })
+    }
+}
+
+fn parse_position(value: &str) -> Result<OriginPosition, ParseError> {
+    if let Some(percentage) = value.strip_suffix('%') {
+        Ok(OriginPosition::Percentage(
+            percentage.parse().map_err(|_| ParseError)?,
+        ))
+    } else {
+        Ok(OriginPosition::Pixels(
+            value.parse().map_err(|_| ParseError)?,
+        ))
+    }
+}
+
+/// Parse a list of numbers separated by whitespaces or commas, with an optional unit.
+fn parse_values(value: &str, unit: &str) -> Result<Vec<f32>, ParseError> {
+    value
+        .split(|c: char| c == ',' || c.is_ascii_whitespace())
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            value
+                .strip_suffix(unit)
+                .unwrap_or(value)
+                .parse::<f32>()
+                .map_err(|_| ParseError)
+        })
+        .collect()
+}
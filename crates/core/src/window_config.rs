@@ -9,6 +9,7 @@ use dioxus_signals::{
     GlobalSignal,
     Readable,
 };
+use ragnarok::ClickCountConfig;
 use winit::window::{
     Icon,
     Window,
@@ -72,6 +73,8 @@ pub struct WindowConfig {
     pub window_attributes_hook: Option<WindowBuilderHook>,
     /// Max resource in bytes to be used by the GPU. Defaults to automatic.
     pub max_gpu_resources_bytes: Option<usize>,
+    /// Thresholds for consecutive clicks to be counted as double or triple clicks.
+    pub click_count_config: ClickCountConfig,
 }
 
 impl WindowConfig {
@@ -108,6 +111,7 @@ impl WindowConfig {
             on_close: None,
             window_attributes_hook: None,
             max_gpu_resources_bytes: None,
+            click_count_config: ClickCountConfig::default(),
         }
     }
 
@@ -151,6 +155,11 @@ impl WindowConfig {
         self.max_gpu_resources_bytes = Some(max_gpu_resources_bytes);
         self
     }
+    /// Specify the thresholds for consecutive clicks to be counted as double or triple clicks.
+    pub fn with_click_count_config(mut self, click_count_config: ClickCountConfig) -> Self {
+        self.click_count_config = click_count_config;
+        self
+    }
     /// Specify the Window background color.
     pub fn with_background(mut self, background: &str) -> Self {
         self.background = Color::parse(background).unwrap_or(Color::WHITE);
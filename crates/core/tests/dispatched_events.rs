@@ -0,0 +1,42 @@
+use freya::prelude::*;
+use freya_core::events::EventName;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn dispatched_events() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: |_| {},
+                rect {
+                    width: "50",
+                    height: "50",
+                    onclick: |e: MouseEvent| e.prevent_default(),
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let parent_id = utils.root().children_ids()[0];
+    let child_id = utils.root().get(0).children_ids()[0];
+
+    utils.take_dispatched_events();
+    utils.click_cursor((5., 5.)).await;
+
+    let clicks = utils
+        .take_dispatched_events()
+        .into_iter()
+        .filter(|event| event.name == EventName::Click)
+        .collect::<Vec<_>>();
+
+    // The click bubbles from the child to the parent
+    assert_eq!(clicks.len(), 1);
+    assert_eq!(clicks[0].node_id, child_id);
+    assert_eq!(clicks[0].path, vec![child_id, parent_id]);
+    assert!(clicks[0].cancelled);
+}
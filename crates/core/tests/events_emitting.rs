@@ -733,3 +733,130 @@ pub async fn prevent_default_pointer() {
 
     assert_eq!(root.get(0).get(1).get(0).text(), Some("0"));
 }
+
+#[tokio::test]
+pub async fn click_event_transformed_element() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    translate: "200 0",
+                    onclick: move |_| {
+                        state.push(1);
+                    }
+                }
+                label {
+                    "{state:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    // The untransformed area no longer receives the events
+    utils.click_cursor((50., 50.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    // But the translated one does
+    utils.click_cursor((250., 50.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[1]"));
+}
+
+#[tokio::test]
+pub async fn click_event_clipped_element() {
+    fn app() -> Element {
+        let mut state = use_signal(Vec::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                rect {
+                    height: "100",
+                    width: "100",
+                    background: "red",
+                    clip_path: "circle(50%)",
+                    onclick: move |_| {
+                        state.push(1);
+                    }
+                }
+                label {
+                    "{state:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    // The corners are outside of the circle
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    // But the center is inside
+    utils.click_cursor((50., 50.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[1]"));
+}
+
+#[tokio::test]
+pub async fn double_click_event() {
+    fn app() -> Element {
+        let mut clicks = use_signal(Vec::new);
+        let mut double_clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                rect {
+                    height: "100",
+                    width: "200",
+                    onclick: move |e: MouseEvent| {
+                        clicks.push(e.get_click_count());
+                    },
+                    ondoubleclick: move |_| {
+                        double_clicks += 1;
+                    }
+                }
+                label {
+                    "{clicks:?} {double_clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    // Consecutive clicks in the same place are counted
+    utils.click_cursor((50., 50.)).await;
+    utils.click_cursor((50., 50.)).await;
+    utils.click_cursor((51., 50.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[1, 2, 3] 1"));
+
+    // Clicks somewhere else start a new count
+    utils.click_cursor((150., 50.)).await;
+
+    assert_eq!(label.get(0).text(), Some("[1, 2, 3, 1] 1"));
+}
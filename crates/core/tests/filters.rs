@@ -0,0 +1,37 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use image::ImageFormat;
+
+#[tokio::test]
+pub async fn filter_is_applied_once_to_the_children() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                direction: "horizontal",
+                filter: "blur(5)",
+                rect {
+                    width: "100",
+                    height: "100",
+                    background: "red",
+                }
+                rect {
+                    width: "100",
+                    height: "100",
+                    background: "blue",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let snapshot = utils.create_snapshot();
+    let snapshot = image::load_from_memory_with_format(&snapshot, ImageFormat::Png)
+        .unwrap()
+        .into_rgba8();
+
+    // Both children are blurred together, so the white background doesn't show between them
+    let [_, green, _, _] = snapshot.get_pixel(100, 50).0;
+    assert!(green < 10, "The background is visible between the children");
+}
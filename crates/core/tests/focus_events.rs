@@ -0,0 +1,89 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn focus_events() {
+    fn focus_events_app() -> Element {
+        let mut log = use_signal(Vec::<String>::new);
+        let mut focus_a = use_focus();
+        let focus_b = use_focus();
+        let text = log.read().join(", ");
+
+        rsx!(
+            rect {
+                onfocusin: move |e: FocusEvent| log.push(format!("focusin {:?}", e.origin)),
+                onfocusout: move |_| log.push("focusout".to_string()),
+                onglobalkeydown: move |e: KeyboardEvent| {
+                    if e.key == Key::Enter {
+                        focus_a.request_focus();
+                    }
+                },
+                rect {
+                    width: "100",
+                    height: "100",
+                    a11y_id: focus_a.attribute(),
+                    a11y_focusable: "true",
+                    onclick: move |_| focus_a.request_focus(),
+                    onfocus: move |_| log.push("focus a".to_string()),
+                    onblur: move |_| log.push("blur a".to_string()),
+                }
+                rect {
+                    width: "100",
+                    height: "100",
+                    a11y_id: focus_b.attribute(),
+                    a11y_focusable: "true",
+                    onfocus: move |_| log.push("focus b".to_string()),
+                    onblur: move |_| log.push("blur b".to_string()),
+                }
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(focus_events_app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(1);
+    assert_eq!(label.get(0).text(), Some(""));
+
+    // Focus with the pointer
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("focus a, focusin Pointer"));
+
+    // Focus the next element with the keyboard
+    utils.push_event(TestEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("focus a, focusin Pointer, blur a, focusout, focus b, focusin Keyboard")
+    );
+
+    // Focus from code
+    utils.push_event(TestEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("focus a, focusin Pointer, blur a, focusout, focus b, focusin Keyboard, blur b, focusout, focus a, focusin Programmatic")
+    );
+}
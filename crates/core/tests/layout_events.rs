@@ -0,0 +1,87 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn layout_events() {
+    fn layout_events_app() -> Element {
+        let mut resizes = use_signal(|| 0);
+        let mut layouts = use_signal(|| 0);
+        let mut height = use_signal(|| 0.);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "50%",
+                onresize: move |e: LayoutEvent| {
+                    resizes += 1;
+                    height.set(e.area.height());
+                },
+                onlayout: move |_| layouts += 1,
+            }
+            label {
+                "{resizes} {layouts} {height}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(layout_events_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Elements are notified about their first layout
+    let label = utils.root().get(1);
+    assert_eq!(label.get(0).text(), Some("1 1 250"));
+
+    utils.resize((500., 300.).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("2 2 150"));
+
+    // Only the width changed
+    utils.resize((400., 300.).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("3 3 150"));
+}
+
+#[tokio::test]
+pub async fn scroll_events() {
+    fn scroll_events_app() -> Element {
+        let mut offset = use_signal(|| 0);
+        let mut scroll = use_signal(ScrollData::default);
+        let data = scroll.read();
+
+        rsx!(
+            rect {
+                height: "100",
+                width: "100",
+                offset_y: "{offset}",
+                onclick: move |_| offset -= 50,
+                onscroll: move |e: ScrollEvent| scroll.set((*e.data()).clone()),
+                rect {
+                    height: "300",
+                    width: "100",
+                }
+            }
+            label {
+                "{data.scroll_y} {data.content_size.height} {data.viewport_size.height}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(scroll_events_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Elements are not scrolled when they first appear
+    let label = utils.root().get(1);
+    assert_eq!(label.get(0).text(), Some("0 0 0"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("50 300 100"));
+}
@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_core::offscreen::{
+    OffscreenConfig,
+    OffscreenImageFormat,
+    OffscreenRenderer,
+};
+
+#[tokio::test]
+pub async fn offscreen_render() {
+    fn offscreen_app() -> Element {
+        let mut color = use_signal(|| "blue");
+
+        use_hook(move || {
+            color.set("red");
+        });
+
+        rsx!(rect {
+            width: "50%",
+            height: "fill",
+            background: "{color}",
+        })
+    }
+
+    let mut renderer = OffscreenRenderer::new(
+        VirtualDom::new(offscreen_app),
+        OffscreenConfig::new()
+            .with_size(100., 50.)
+            .with_scale_factor(2.),
+    );
+    renderer.wait_until_idle().await;
+
+    let mut image = renderer.render();
+    assert_eq!(image.width(), 200);
+    assert_eq!(image.height(), 100);
+
+    let pixels = image.to_rgba();
+    assert_eq!(pixels.len(), 200 * 100 * 4);
+
+    // Inside the rect
+    let pixel = |x: usize, y: usize| {
+        let i = (y * 200 + x) * 4;
+        &pixels[i..i + 4]
+    };
+    assert_eq!(pixel(50, 50), &[255, 0, 0, 255]);
+
+    // Outside the rect
+    assert_eq!(pixel(150, 50), &[255, 255, 255, 255]);
+
+    let png = image.encode(OffscreenImageFormat::Png).unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+}
@@ -3,6 +3,7 @@ use freya_core::{
     values::{
         Border,
         BorderAlignment,
+        BorderStyle,
         BorderWidth,
         Color,
         Fill,
@@ -25,7 +26,8 @@ fn parse_basic_border() {
                 left: 1.0,
             },
             fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -47,7 +49,8 @@ fn parse_border_widths() {
                 left: 2.0,
             },
             fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -61,7 +64,8 @@ fn parse_border_widths() {
                 left: 2.0,
             },
             fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -75,7 +79,8 @@ fn parse_border_widths() {
                 left: 2.0,
             },
             fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 
@@ -89,7 +94,8 @@ fn parse_border_widths() {
                 left: 4.0,
             },
             fill: Fill::Color(Color::RED),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -119,7 +125,8 @@ fn parse_gradient_border() {
                     }
                 ]
             })),
-            alignment: BorderAlignment::Inner
+            alignment: BorderAlignment::Inner,
+            style: BorderStyle::Solid,
         })
     );
 }
@@ -136,3 +143,56 @@ fn parse_border_alignments() {
     assert_eq!(center, Ok(BorderAlignment::Center));
     assert_eq!(invalid, Ok(BorderAlignment::Inner));
 }
+
+#[test]
+fn parse_border_styles() {
+    let solid = Border::parse("2 inner solid red");
+    let dashed = Border::parse("2 outer dashed blue");
+    let custom_dashed = Border::parse("1 2 inner dashed(6, 3) red");
+    let dotted = Border::parse("2 center dotted(4) red");
+
+    assert_eq!(solid.map(|border| border.style), Ok(BorderStyle::Solid));
+    assert_eq!(
+        dashed,
+        Ok(Border {
+            width: BorderWidth {
+                top: 2.0,
+                right: 2.0,
+                bottom: 2.0,
+                left: 2.0,
+            },
+            fill: Fill::Color(Color::BLUE),
+            alignment: BorderAlignment::Outer,
+            style: BorderStyle::Dashed {
+                dash: None,
+                gap: None,
+            },
+        })
+    );
+    assert_eq!(
+        custom_dashed.map(|border| (border.width, border.style)),
+        Ok((
+            BorderWidth {
+                top: 1.0,
+                right: 2.0,
+                bottom: 1.0,
+                left: 2.0,
+            },
+            BorderStyle::Dashed {
+                dash: Some(6.0),
+                gap: Some(3.0),
+            }
+        ))
+    );
+    assert_eq!(
+        dotted.map(|border| border.style),
+        Ok(BorderStyle::Dotted { gap: Some(4.0) })
+    );
+}
+
+#[test]
+fn parse_invalid_border_styles() {
+    assert!(Border::parse("2 inner dashed").is_err());
+    assert!(Border::parse("2 inner dotted(1, 2, 3) red").is_err());
+    assert!(Border::parse("2 inner dashed(a) red").is_err());
+}
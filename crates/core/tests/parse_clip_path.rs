@@ -0,0 +1,93 @@
+use freya_core::{
+    parsing::Parse,
+    values::{
+        ClipLength,
+        ClipPath,
+        Color,
+        Fill,
+        Mask,
+        MaskMode,
+        MaskSource,
+    },
+};
+
+#[test]
+fn parse_clip_path_shapes() {
+    assert_eq!(
+        ClipPath::parse("circle(50%)"),
+        Ok(ClipPath::Circle {
+            radius: ClipLength::Percentage(50.),
+            center: (ClipLength::Percentage(50.), ClipLength::Percentage(50.)),
+        })
+    );
+    assert_eq!(
+        ClipPath::parse("circle(20 at 10 30%)"),
+        Ok(ClipPath::Circle {
+            radius: ClipLength::Pixels(20.),
+            center: (ClipLength::Pixels(10.), ClipLength::Percentage(30.)),
+        })
+    );
+    assert_eq!(
+        ClipPath::parse("ellipse(50% 25%)"),
+        Ok(ClipPath::Ellipse {
+            radius: (ClipLength::Percentage(50.), ClipLength::Percentage(25.)),
+            center: (ClipLength::Percentage(50.), ClipLength::Percentage(50.)),
+        })
+    );
+    assert_eq!(
+        ClipPath::parse("polygon(50% 0%, 100% 100%, 0 100%)"),
+        Ok(ClipPath::Polygon(vec![
+            (ClipLength::Percentage(50.), ClipLength::Percentage(0.)),
+            (ClipLength::Percentage(100.), ClipLength::Percentage(100.)),
+            (ClipLength::Pixels(0.), ClipLength::Percentage(100.)),
+        ]))
+    );
+    assert_eq!(
+        ClipPath::parse("inset(10 20% round 8)"),
+        Ok(ClipPath::Inset {
+            top: ClipLength::Pixels(10.),
+            right: ClipLength::Percentage(20.),
+            bottom: ClipLength::Pixels(10.),
+            left: ClipLength::Percentage(20.),
+            radius: 8.,
+        })
+    );
+    assert_eq!(
+        ClipPath::parse("path('M 0 0 L 100 0 L 50 100 Z')"),
+        Ok(ClipPath::Path("M 0 0 L 100 0 L 50 100 Z".to_string()))
+    );
+}
+
+#[test]
+fn parse_invalid_clip_paths() {
+    assert!(ClipPath::parse("square(50%)").is_err());
+    assert!(ClipPath::parse("circle(abc)").is_err());
+    assert!(ClipPath::parse("polygon(0 0, 100 100)").is_err());
+    assert!(ClipPath::parse("inset(1 2 3 4 5)").is_err());
+}
+
+#[test]
+fn parse_masks() {
+    assert_eq!(
+        Mask::parse("image"),
+        Ok(Mask {
+            source: MaskSource::Image,
+            mode: MaskMode::Alpha,
+        })
+    );
+    assert_eq!(
+        Mask::parse("image luminance"),
+        Ok(Mask {
+            source: MaskSource::Image,
+            mode: MaskMode::Luminance,
+        })
+    );
+    assert_eq!(
+        Mask::parse("red alpha"),
+        Ok(Mask {
+            source: MaskSource::Fill(Fill::Color(Color::RED)),
+            mode: MaskMode::Alpha,
+        })
+    );
+    assert!(Mask::parse("luminance").is_err());
+}
@@ -0,0 +1,87 @@
+use freya_core::{
+    parsing::Parse,
+    values::{
+        Color,
+        Filter,
+        FilterFunction,
+    },
+};
+
+#[test]
+fn parse_filter_functions() {
+    assert_eq!(
+        FilterFunction::parse("blur(4)"),
+        Ok(FilterFunction::Blur(4.))
+    );
+    assert_eq!(
+        FilterFunction::parse("grayscale(50%)"),
+        Ok(FilterFunction::Grayscale(0.5))
+    );
+    assert_eq!(
+        FilterFunction::parse("brightness(1.5)"),
+        Ok(FilterFunction::Brightness(1.5))
+    );
+    assert_eq!(
+        FilterFunction::parse("invert()"),
+        Ok(FilterFunction::Invert(1.))
+    );
+    assert_eq!(
+        FilterFunction::parse("hue-rotate(90deg)"),
+        Ok(FilterFunction::HueRotate(90.))
+    );
+    assert_eq!(
+        FilterFunction::parse("drop-shadow(2 -3)"),
+        Ok(FilterFunction::DropShadow {
+            x: 2.,
+            y: -3.,
+            blur: 0.,
+            color: Color::BLACK,
+        })
+    );
+    assert_eq!(
+        FilterFunction::parse("drop-shadow(2 3 6 rgb(255, 0, 0))"),
+        Ok(FilterFunction::DropShadow {
+            x: 2.,
+            y: 3.,
+            blur: 6.,
+            color: Color::RED,
+        })
+    );
+}
+
+#[test]
+fn parse_filter_chains() {
+    assert_eq!(
+        Filter::parse("sepia(1) contrast(200%) saturate(0.2)"),
+        Ok(Filter {
+            functions: vec![
+                FilterFunction::Sepia(1.),
+                FilterFunction::Contrast(2.),
+                FilterFunction::Saturate(0.2),
+            ]
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_filters() {
+    assert!(Filter::parse("").is_err());
+    assert!(Filter::parse("blur(4) shiny(2)").is_err());
+    assert!(Filter::parse("grayscale(-1)").is_err());
+    assert!(Filter::parse("hue-rotate(90)").is_err());
+    assert!(Filter::parse("drop-shadow(2)").is_err());
+}
+
+#[test]
+fn filter_color_matrices() {
+    // Neutral amounts leave the colors untouched
+    let identity = FilterFunction::Brightness(1.).color_matrix();
+    assert_eq!(FilterFunction::Invert(0.).color_matrix(), identity);
+    assert_eq!(FilterFunction::Contrast(1.).color_matrix(), identity);
+
+    // Inverting completely maps every channel to `1 - channel`
+    let invert = FilterFunction::Invert(1.).color_matrix();
+    assert_eq!(invert[0], -1.);
+    assert_eq!(invert[4], 1.);
+    assert_eq!(invert[18], 1.);
+}
@@ -0,0 +1,94 @@
+use freya_core::{
+    parsing::{
+        Parse,
+        ParseError,
+    },
+    values::{
+        parse_grid_areas,
+        parse_grid_tracks,
+    },
+};
+use torin::{
+    geometry::Length,
+    grid::{
+        GridArea,
+        GridTrack,
+    },
+};
+
+#[test]
+fn parse_grid_track() {
+    assert_eq!(GridTrack::parse("auto"), Ok(GridTrack::Auto));
+    assert_eq!(
+        GridTrack::parse("100"),
+        Ok(GridTrack::Pixels(Length::new(100.0)))
+    );
+    assert_eq!(
+        GridTrack::parse("25%"),
+        Ok(GridTrack::Percentage(Length::new(25.0)))
+    );
+    assert_eq!(
+        GridTrack::parse("flex"),
+        Ok(GridTrack::Flex(Length::new(1.0)))
+    );
+    assert_eq!(
+        GridTrack::parse("flex(2.5)"),
+        Ok(GridTrack::Flex(Length::new(2.5)))
+    );
+}
+
+#[test]
+fn parse_grid_tracks_list() {
+    assert_eq!(
+        parse_grid_tracks("150  flex(2) auto 50%"),
+        Ok(vec![
+            GridTrack::Pixels(Length::new(150.0)),
+            GridTrack::Flex(Length::new(2.0)),
+            GridTrack::Auto,
+            GridTrack::Percentage(Length::new(50.0)),
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_grid_tracks() {
+    assert_eq!(parse_grid_tracks("100 big"), Err(ParseError));
+}
+
+#[test]
+fn parse_grid_areas_template() {
+    assert_eq!(
+        parse_grid_areas("header header, sidebar main, . main"),
+        Ok(vec![
+            GridArea {
+                name: "header".to_string(),
+                column: 0,
+                row: 0,
+                column_span: 2,
+                row_span: 1,
+            },
+            GridArea {
+                name: "sidebar".to_string(),
+                column: 0,
+                row: 1,
+                column_span: 1,
+                row_span: 1,
+            },
+            GridArea {
+                name: "main".to_string(),
+                column: 1,
+                row: 1,
+                column_span: 1,
+                row_span: 2,
+            },
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_grid_areas() {
+    // Rows of different lengths
+    assert_eq!(parse_grid_areas("a a, b"), Err(ParseError));
+    // Not a rectangle
+    assert_eq!(parse_grid_areas("a a, a b"), Err(ParseError));
+}
@@ -0,0 +1,65 @@
+use freya_core::{
+    parsing::Parse,
+    values::{
+        OriginPosition,
+        TransformOperation,
+        TransformOrigin,
+    },
+};
+
+#[test]
+fn parse_transform_operations() {
+    assert_eq!(
+        TransformOperation::parse_translate("10"),
+        Ok(TransformOperation::Translate(10., 0.))
+    );
+    assert_eq!(
+        TransformOperation::parse_translate("10 -5.5"),
+        Ok(TransformOperation::Translate(10., -5.5))
+    );
+    assert_eq!(
+        TransformOperation::parse_skew("20deg 10deg"),
+        Ok(TransformOperation::Skew(20., 10.))
+    );
+    assert_eq!(
+        TransformOperation::parse_matrix("1, 0.5, 0, 1, 10, 20"),
+        Ok(TransformOperation::Matrix([1., 0.5, 0., 1., 10., 20.]))
+    );
+}
+
+#[test]
+fn parse_invalid_transform_operations() {
+    assert!(TransformOperation::parse_translate("10 20 30").is_err());
+    assert!(TransformOperation::parse_skew("abc").is_err());
+    assert!(TransformOperation::parse_matrix("1, 0, 0, 1").is_err());
+}
+
+#[test]
+fn parse_transform_origin() {
+    assert_eq!(
+        TransformOrigin::parse("center"),
+        Ok(TransformOrigin::default())
+    );
+    assert_eq!(
+        TransformOrigin::parse("top left"),
+        Ok(TransformOrigin {
+            x: OriginPosition::Percentage(0.),
+            y: OriginPosition::Percentage(0.),
+        })
+    );
+    assert_eq!(
+        TransformOrigin::parse("bottom"),
+        Ok(TransformOrigin {
+            x: OriginPosition::Percentage(50.),
+            y: OriginPosition::Percentage(100.),
+        })
+    );
+    assert_eq!(
+        TransformOrigin::parse("25% 10"),
+        Ok(TransformOrigin {
+            x: OriginPosition::Percentage(25.),
+            y: OriginPosition::Pixels(10.),
+        })
+    );
+    assert!(TransformOrigin::parse("top left right").is_err());
+}
@@ -0,0 +1,38 @@
+use freya::prelude::*;
+use freya_native_core::attributes::AttributeName;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn set_attribute_until_the_vdom_changes_it() {
+    fn app() -> Element {
+        let mut width = use_signal(|| 100);
+
+        rsx!(rect {
+            width: "{width}",
+            height: "100",
+            onclick: move |_| width += 100,
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let node_id = utils.root().children_ids()[0];
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.);
+
+    // Override the width
+    let (_, must_relayout) =
+        utils
+            .sdom()
+            .get_mut()
+            .set_attribute(node_id, AttributeName::Width, "50".to_string());
+    assert!(must_relayout);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 50.);
+
+    // The VirtualDOM changes the width again
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 200.);
+}
@@ -1,15 +1,82 @@
+use dioxus_radio::prelude::use_radio;
 use freya::prelude::*;
-use freya_devtools::AttributeType;
+use freya_devtools::{
+    AttributeType,
+    IncomingMessage,
+    IncomingMessageAction,
+};
+use freya_native_core::NodeId;
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::Message;
 
-use crate::property::{
-    BorderProperty,
-    ColorProperty,
-    GradientProperty,
-    Property,
-    ShadowProperty,
-    TextShadowProperty,
+use crate::{
+    property::{
+        BorderProperty,
+        ColorProperty,
+        GradientProperty,
+        Property,
+        ShadowProperty,
+        TextShadowProperty,
+    },
+    state::DevtoolsChannel,
 };
 
+/// Override an attribute of the inspected node, e.g `background` with `red`.
+#[component]
+pub fn AttributeEditor(node_id: NodeId, window_id: u64, attribute: Signal<String>) -> Element {
+    let mut attribute = attribute;
+    let radio = use_radio(DevtoolsChannel::Global);
+    let mut value = use_signal(String::new);
+
+    let onpress = move |_| {
+        let message = Message::Text(
+            serde_json::to_string(&IncomingMessage {
+                action: IncomingMessageAction::SetAttribute {
+                    window_id,
+                    node_id,
+                    attribute: attribute(),
+                    value: value(),
+                },
+            })
+            .unwrap()
+            .into(),
+        );
+        let client = radio.read().client.clone();
+        spawn(async move {
+            if let Some(client) = client.lock().await.as_mut() {
+                client.send(message).await.ok();
+            }
+        });
+    };
+
+    rsx!(
+        rect {
+            direction: "horizontal",
+            cross_align: "center",
+            padding: "5 16",
+            spacing: "8",
+            Input {
+                placeholder: "Attribute",
+                width: "120",
+                value: attribute,
+                onchange: move |new_attribute| attribute.set(new_attribute),
+            }
+            Input {
+                placeholder: "Value",
+                width: "150",
+                value,
+                onchange: move |new_value| value.set(new_value),
+            }
+            Button {
+                onpress,
+                label {
+                    "Apply"
+                }
+            }
+        }
+    )
+}
+
 pub fn attribute_element(i: usize, name: &str, attribute: AttributeType<'_>) -> Option<Element> {
     let element = match attribute {
         AttributeType::Measure(measure) => {
@@ -35,10 +35,13 @@ mod state;
 mod tabs;
 
 use tabs::{
+    accessibility::*,
     computed_layout::*,
+    events::*,
     font_style::*,
     layout::*,
     misc::*,
+    profiler::*,
     style::*,
     svg::*,
     tree::*,
@@ -62,6 +65,11 @@ pub fn app() -> Element {
         expanded_nodes: HashSet::default(),
         client: Arc::default(),
         animation_speed: AnimationClock::DEFAULT_SPEED / AnimationClock::MAX_SPEED * 100.,
+        events: Vec::new(),
+        events_paused: false,
+        accessibility_nodes: HashMap::new(),
+        frames: Vec::new(),
+        profiler_paused: false,
     });
     let radio = use_radio(DevtoolsChannel::Global);
 
@@ -86,6 +94,38 @@ pub fn app() -> Element {
                                         .nodes
                                         .insert(window_id, nodes);
                                 }
+                                OutgoingMessageAction::Frame { frame } => {
+                                    if radio.read().profiler_paused {
+                                        return;
+                                    }
+                                    let mut radio = radio.write_channel(DevtoolsChannel::Profiler);
+                                    radio.frames.push(frame);
+                                    let overflow = radio
+                                        .frames
+                                        .len()
+                                        .saturating_sub(DevtoolsState::MAX_FRAMES);
+                                    radio.frames.drain(..overflow);
+                                }
+                                OutgoingMessageAction::AccessibilityTree { window_id, nodes } => {
+                                    radio
+                                        .write_channel(DevtoolsChannel::UpdatedAccessibility)
+                                        .accessibility_nodes
+                                        .insert(window_id, nodes);
+                                }
+                                OutgoingMessageAction::DispatchedEvents { window_id, events } => {
+                                    if radio.read().events_paused {
+                                        return;
+                                    }
+                                    let mut radio = radio.write_channel(DevtoolsChannel::Events);
+                                    radio
+                                        .events
+                                        .extend(events.into_iter().map(|event| (window_id, event)));
+                                    let overflow = radio
+                                        .events
+                                        .len()
+                                        .saturating_sub(DevtoolsState::MAX_EVENTS);
+                                    radio.events.drain(..overflow);
+                                }
                             }
                         }
                     }
@@ -128,6 +168,39 @@ pub fn NavBar() -> Element {
                     }
                 }
             }
+            Link {
+                to: Route::Accessibility { },
+                ActivableRoute {
+                    route: Route::Accessibility { },
+                    Tab {
+                        label {
+                            "Accessibility"
+                        }
+                    }
+                }
+            }
+            Link {
+                to: Route::Events { },
+                ActivableRoute {
+                    route: Route::Events { },
+                    Tab {
+                        label {
+                            "Events"
+                        }
+                    }
+                }
+            }
+            Link {
+                to: Route::Profiler { },
+                ActivableRoute {
+                    route: Route::Profiler { },
+                    Tab {
+                        label {
+                            "Profiler"
+                        }
+                    }
+                }
+            }
             Link {
                 to: Route::Misc { },
                 ActivableRoute {
@@ -150,6 +223,12 @@ pub fn NavBar() -> Element {
 #[rustfmt::skip]
 pub enum Route {
     #[layout(NavBar)]
+        #[route("/accessibility")]
+        Accessibility {},
+        #[route("/events")]
+        Events {},
+        #[route("/profiler")]
+        Profiler {},
         #[route("/misc")]
         Misc {},
         #[layout(LayoutForDOMInspector)]
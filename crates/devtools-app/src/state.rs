@@ -7,7 +7,12 @@ use std::{
 };
 
 use dioxus_radio::prelude::*;
-use freya_devtools::NodeInfo;
+use freya_devtools::{
+    AccessibilityNodeInfo,
+    EventInfo,
+    FrameInfo,
+    NodeInfo,
+};
 use freya_native_core::prelude::NodeId;
 use futures_util::stream::SplitSink;
 use tokio::{
@@ -27,6 +32,21 @@ pub struct DevtoolsState {
     pub(crate) expanded_nodes: HashSet<(u64, NodeId)>,
     pub(crate) client: Arc<Mutex<Option<WebSocket>>>,
     pub(crate) animation_speed: f32,
+    /// Latest events dispatched in the inspected app, along with their window.
+    pub(crate) events: Vec<(u64, EventInfo)>,
+    pub(crate) events_paused: bool,
+    pub(crate) accessibility_nodes: HashMap<u64, Vec<AccessibilityNodeInfo>>,
+    /// Latest frames rendered in the inspected app.
+    pub(crate) frames: Vec<FrameInfo>,
+    pub(crate) profiler_paused: bool,
+}
+
+impl DevtoolsState {
+    /// Maximum amount of events kept in the log.
+    pub const MAX_EVENTS: usize = 500;
+
+    /// Maximum amount of frames kept in the profiler.
+    pub const MAX_FRAMES: usize = 300;
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
@@ -34,6 +54,9 @@ pub enum DevtoolsChannel {
     Global,
     UpdatedDOM,
     Misc,
+    Events,
+    UpdatedAccessibility,
+    Profiler,
 }
 
 impl RadioChannel<DevtoolsState> for DevtoolsChannel {}
@@ -0,0 +1,289 @@
+use accesskit::NodeId as AccessibilityId;
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+use freya_devtools::{
+    AccessibilityNodeInfo,
+    IncomingMessage,
+    IncomingMessageAction,
+};
+use freya_router::prelude::use_navigator;
+use futures_util::SinkExt;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::{
+    Route,
+    state::DevtoolsChannel,
+};
+
+#[component]
+pub fn Accessibility() -> Element {
+    let radio = use_radio(DevtoolsChannel::UpdatedAccessibility);
+    let mut selected = use_signal::<Option<(u64, AccessibilityId)>>(|| None);
+
+    let nodes = {
+        let radio = radio.read();
+        let mut windows = radio
+            .accessibility_nodes
+            .iter()
+            // Skip the windows that got closed
+            .filter(|(window_id, _)| {
+                radio
+                    .nodes
+                    .get(window_id)
+                    .is_some_and(|nodes| !nodes.is_empty())
+            })
+            .collect::<Vec<_>>();
+        windows.sort_by_key(|(window_id, _)| **window_id);
+        windows
+            .into_iter()
+            .flat_map(|(_, nodes)| nodes.iter().cloned())
+            .collect::<Vec<_>>()
+    };
+
+    if nodes.is_empty() {
+        return rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                main_align: "center",
+                cross_align: "center",
+                label {
+                    "Waiting for an app to connect..."
+                }
+            }
+        );
+    }
+
+    let mut onselected = move |node: &AccessibilityNodeInfo| {
+        selected.set(Some((node.window_id, node.id)));
+
+        // Highlight the DOM node of this accessibility node
+        if let Some(node_id) = node.node_id {
+            let message = Message::Text(
+                serde_json::to_string(&IncomingMessage {
+                    action: IncomingMessageAction::HighlightNode {
+                        window_id: node.window_id,
+                        node_id,
+                    },
+                })
+                .unwrap()
+                .into(),
+            );
+            let client = radio.read().client.clone();
+            spawn(async move {
+                if let Some(client) = client.lock().await.as_mut() {
+                    client.send(message).await.ok();
+                }
+            });
+        }
+    };
+
+    let selected_node = selected().and_then(|(window_id, id)| {
+        nodes
+            .iter()
+            .find(|node| node.window_id == window_id && node.id == id)
+            .cloned()
+    });
+
+    rsx!(
+        ResizableContainer {
+            direction: "horizontal",
+            ResizablePanel {
+                initial_size: 50.,
+                ScrollView {
+                    show_scrollbar: true,
+                    height: "fill",
+                    width: "fill",
+                    padding: "10",
+                    for node in nodes {
+                        {
+                            let key = format!("{}-{}", node.window_id, node.id.0);
+                            let is_selected = selected() == Some((node.window_id, node.id));
+                            let selected_node = node.clone();
+
+                            rsx!(
+                                AccessibilityNodeElement {
+                                    key: "{key}",
+                                    is_selected,
+                                    onselected: move |_| onselected(&selected_node),
+                                    node,
+                                }
+                            )
+                        }
+                    }
+                }
+            }
+            if let Some(node) = selected_node {
+                ResizablePanel {
+                    initial_size: 50.,
+                    AccessibilityNodeDetails {
+                        node
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn AccessibilityNodeElement(
+    node: AccessibilityNodeInfo,
+    is_selected: bool,
+    onselected: EventHandler<()>,
+) -> Element {
+    let margin_left = node.depth * 10;
+    let issues = node.issues().len();
+    let background = if is_selected {
+        "rgb(40, 40, 40)"
+    } else {
+        "none"
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "27",
+            corner_radius: "99",
+            cross_align: "center",
+            padding: "0 8",
+            background,
+            onclick: move |_| onselected.call(()),
+            paragraph {
+                offset_x: "{margin_left}",
+                max_lines: "1",
+                text_overflow: "ellipsis",
+                text {
+                    font_size: "14",
+                    color: "white",
+                    "{node.role:?}"
+                }
+                if let Some(name) = &node.name {
+                    text {
+                        font_size: "14",
+                        color: "rgb(200, 200, 200)",
+                        " \"{name}\""
+                    }
+                }
+                if node.focused {
+                    text {
+                        font_size: "14",
+                        color: "rgb(102, 163, 217)",
+                        " (focused)"
+                    }
+                }
+                if issues > 0 {
+                    text {
+                        font_size: "14",
+                        color: "rgb(252,181,172)",
+                        " ⚠ {issues}"
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn AccessibilityNodeDetails(node: AccessibilityNodeInfo) -> Element {
+    let navigator = use_navigator();
+
+    let none = || "none".to_string();
+    let bounds = node
+        .bounds
+        .map(|bounds| {
+            format!(
+                "{}, {} ({} x {})",
+                bounds.x0,
+                bounds.y0,
+                bounds.width(),
+                bounds.height()
+            )
+        })
+        .unwrap_or_else(none);
+    let properties = [
+        ("Role", format!("{:?}", node.role)),
+        ("Name", node.name.clone().unwrap_or_else(none)),
+        ("Value", node.value.clone().unwrap_or_else(none)),
+        ("Description", node.description.clone().unwrap_or_else(none)),
+        ("Focusable", node.focusable.to_string()),
+        ("Focused", node.focused.to_string()),
+        ("Disabled", node.disabled.to_string()),
+        ("Hidden", node.hidden.to_string()),
+        ("Bounds", bounds),
+    ];
+    let issues = node.issues();
+    let window_id = node.window_id;
+
+    rsx!(
+        rect {
+            overflow: "clip",
+            width: "fill",
+            height: "fill",
+            background: "rgb(30, 30, 30)",
+            margin: "0 10 10 10",
+            corner_radius: "16",
+            padding: "6",
+            spacing: "6",
+            ScrollView {
+                show_scrollbar: true,
+                height: "fill",
+                width: "fill",
+                for (i, (name, value)) in properties.into_iter().enumerate() {
+                    {
+                        let background = if i % 2 == 0 {
+                            "rgb(255, 255, 255, 0.1)"
+                        } else {
+                            "transparent"
+                        };
+
+                        rsx!(
+                            rect {
+                                key: "{name}",
+                                background,
+                                padding: "5 16",
+                                width: "fill",
+                                paragraph {
+                                    width: "fill",
+                                    text {
+                                        font_size: "15",
+                                        color: "rgb(102, 163, 217)",
+                                        "{name}: "
+                                    }
+                                    text {
+                                        font_size: "15",
+                                        color: "rgb(215, 215, 215)",
+                                        "{value}"
+                                    }
+                                }
+                            }
+                        )
+                    }
+                }
+                for issue in issues {
+                    rect {
+                        key: "{issue:?}",
+                        padding: "5 16",
+                        label {
+                            font_size: "15",
+                            color: "rgb(252,181,172)",
+                            "⚠ {issue.description()}"
+                        }
+                    }
+                }
+                if let Some(node_id) = node.node_id {
+                    rect {
+                        padding: "5 16",
+                        Button {
+                            onpress: move |_| {
+                                navigator.push(Route::NodeInspectorLayout { node_id, window_id });
+                            },
+                            label {
+                                "Inspect node {node_id.index()}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
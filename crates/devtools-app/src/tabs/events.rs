@@ -0,0 +1,109 @@
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+
+use crate::state::DevtoolsChannel;
+
+#[component]
+pub fn Events() -> Element {
+    let mut radio = use_radio(DevtoolsChannel::Events);
+    let mut filter = use_signal(String::new);
+
+    let is_paused = radio.read().events_paused;
+    let filter_value = filter.read().to_lowercase();
+    let events = radio
+        .read()
+        .events
+        .iter()
+        .rev()
+        .filter(|(_, event)| event.name.contains(&filter_value))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "8",
+                Input {
+                    placeholder: "Filter by name",
+                    value: filter,
+                    onchange: move |value| filter.set(value),
+                }
+                Button {
+                    onpress: move |_| {
+                        let mut radio = radio.write();
+                        radio.events_paused = !radio.events_paused;
+                    },
+                    label {
+                        if is_paused {
+                            "Resume"
+                        } else {
+                            "Pause"
+                        }
+                    }
+                }
+                Button {
+                    onpress: move |_| radio.write().events.clear(),
+                    label {
+                        "Clear"
+                    }
+                }
+            }
+            ScrollView {
+                show_scrollbar: true,
+                height: "fill",
+                width: "fill",
+                for (i, (window_id, event)) in events.into_iter().enumerate() {
+                    {
+                        let background = if i % 2 == 0 {
+                            "rgb(255, 255, 255, 0.1)"
+                        } else {
+                            "transparent"
+                        };
+                        let path = event
+                            .path
+                            .iter()
+                            .map(|node_id| node_id.index().to_string())
+                            .collect::<Vec<_>>()
+                            .join(" → ");
+                        let node_id = event.node_id.index();
+
+                        rsx!(
+                            rect {
+                                key: "{i}",
+                                background,
+                                padding: "5 16",
+                                width: "fill",
+                                paragraph {
+                                    width: "fill",
+                                    text {
+                                        font_size: "15",
+                                        color: "rgb(102, 163, 217)",
+                                        "{event.name}"
+                                    }
+                                    text {
+                                        font_size: "15",
+                                        color: "rgb(215, 215, 215)",
+                                        " on {node_id}, window {window_id}, path: {path}"
+                                    }
+                                    if event.cancelled {
+                                        text {
+                                            font_size: "15",
+                                            color: "rgb(252,181,172)",
+                                            " (cancelled)"
+                                        }
+                                    }
+                                }
+                            }
+                        )
+                    }
+                }
+            }
+        }
+    )
+}
@@ -3,17 +3,26 @@ use freya_devtools::NodeStateAttributes;
 use freya_native_core::NodeId;
 
 use crate::{
-    components::attribute::attribute_element,
+    components::attribute::{
+        AttributeEditor,
+        attribute_element,
+    },
     hooks::use_node_info,
 };
 
 #[component]
 pub fn NodeInspectorLayout(node_id: NodeId, window_id: u64) -> Element {
+    let mut edited_attribute = use_signal(String::new);
     let Some(node) = use_node_info(node_id, window_id) else {
         return Ok(VNode::placeholder());
     };
 
     rsx!(
+        AttributeEditor {
+            node_id,
+            window_id,
+            attribute: edited_attribute,
+        }
         ScrollView {
             show_scrollbar: true,
             height : "fill",
@@ -26,11 +35,13 @@ pub fn NodeInspectorLayout(node_id: NodeId, window_id: u64) -> Element {
                 };
 
                 let element = attribute_element(i, name, attribute)?;
+                let name = name.to_string();
 
                 Some(rsx!(
                     rect {
                         background,
                         padding: "5 16",
+                        onclick: move |_| edited_attribute.set(name.clone()),
                         {element}
                     }
                 ))
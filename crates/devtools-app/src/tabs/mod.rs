@@ -1,7 +1,10 @@
+pub mod accessibility;
 pub mod computed_layout;
+pub mod events;
 pub mod font_style;
 pub mod layout;
 pub mod misc;
+pub mod profiler;
 pub mod style;
 pub mod svg;
 pub mod tree;
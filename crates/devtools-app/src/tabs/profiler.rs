@@ -0,0 +1,186 @@
+use dioxus_radio::prelude::use_radio;
+use freya::prelude::*;
+use freya_devtools::{
+    FrameInfo,
+    FramePhase,
+    chrome_trace,
+};
+
+use crate::state::DevtoolsChannel;
+
+/// Height of every phase in the timeline.
+const PHASE_HEIGHT: usize = 18;
+
+#[component]
+pub fn Profiler() -> Element {
+    let mut radio = use_radio(DevtoolsChannel::Profiler);
+    let mut zoom = use_signal(|| 25.);
+    let mut export_status = use_signal(String::new);
+
+    let is_paused = radio.read().profiler_paused;
+    let frames = radio
+        .read()
+        .frames
+        .iter()
+        .rev()
+        .cloned()
+        .collect::<Vec<_>>();
+    // Pixels per millisecond
+    let scale = 10. + zoom() * 4.;
+
+    let onexport = move |_| {
+        let trace = chrome_trace(&radio.read().frames);
+        let path = std::env::current_dir()
+            .unwrap_or_default()
+            .join("freya-trace.json");
+        match std::fs::write(&path, trace.to_string()) {
+            Ok(_) => export_status.set(format!("Exported to {}", path.display())),
+            Err(err) => export_status.set(format!("Failed to export: {err}")),
+        }
+    };
+
+    rsx!(
+        rect {
+            height: "fill",
+            width: "fill",
+            padding: "8",
+            spacing: "8",
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "8",
+                Button {
+                    onpress: move |_| {
+                        let mut radio = radio.write();
+                        radio.profiler_paused = !radio.profiler_paused;
+                    },
+                    label {
+                        if is_paused {
+                            "Resume"
+                        } else {
+                            "Pause"
+                        }
+                    }
+                }
+                Button {
+                    onpress: move |_| radio.write().frames.clear(),
+                    label {
+                        "Clear"
+                    }
+                }
+                Button {
+                    onpress: onexport,
+                    label {
+                        "Export trace"
+                    }
+                }
+                label {
+                    "Zoom"
+                }
+                Slider {
+                    size: "150",
+                    value: zoom(),
+                    onmoved: move |p| zoom.set(p),
+                }
+                label {
+                    "{export_status}"
+                }
+            }
+            ScrollView {
+                show_scrollbar: true,
+                height: "fill",
+                width: "fill",
+                for (i, frame) in frames.into_iter().enumerate() {
+                    {
+                        let key = format!("{}-{}", frame.window_id, frame.start);
+
+                        rsx!(
+                            FrameTimeline {
+                                key: "{key}",
+                                index: i,
+                                frame,
+                                scale,
+                            }
+                        )
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn FrameTimeline(index: usize, frame: FrameInfo, scale: f64) -> Element {
+    let duration = frame.duration as f64 / 1000.;
+    let width = (duration * scale).max(1.);
+    let depth = frame
+        .phases
+        .iter()
+        .map(|timing| timing.phase.depth() + 1)
+        .max()
+        .unwrap_or(1);
+    let height = depth * PHASE_HEIGHT;
+    let background = if index % 2 == 0 {
+        "rgb(255, 255, 255, 0.1)"
+    } else {
+        "transparent"
+    };
+
+    rsx!(
+        rect {
+            background,
+            padding: "5 16",
+            spacing: "4",
+            label {
+                font_size: "13",
+                color: "rgb(215, 215, 215)",
+                "Window {frame.window_id}, {duration:.2}ms"
+            }
+            rect {
+                width: "{width}",
+                height: "{height}",
+                background: "rgb(45, 45, 45)",
+                for timing in frame.phases {
+                    {
+                        let left = (timing.start - frame.start) as f64 / 1000. * scale;
+                        let width = (timing.duration as f64 / 1000. * scale).max(1.);
+                        let top = timing.phase.depth() * PHASE_HEIGHT;
+                        let background = phase_color(timing.phase);
+
+                        rsx!(
+                            rect {
+                                key: "{timing.phase:?}-{timing.start}",
+                                position: "absolute",
+                                position_left: "{left}",
+                                position_top: "{top}",
+                                width: "{width}",
+                                height: "{PHASE_HEIGHT}",
+                                background,
+                                overflow: "clip",
+                                label {
+                                    font_size: "11",
+                                    color: "white",
+                                    max_lines: "1",
+                                    "{timing.phase.name()}"
+                                }
+                            }
+                        )
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn phase_color(phase: FramePhase) -> &'static str {
+    match phase {
+        FramePhase::PollVDOM => "rgb(102, 163, 217)",
+        FramePhase::UpdateDOM => "rgb(86, 137, 183)",
+        FramePhase::ApplyMutations => "rgb(125, 104, 198)",
+        FramePhase::UpdateStates => "rgb(160, 104, 198)",
+        FramePhase::Events => "rgb(214, 150, 82)",
+        FramePhase::Layout => "rgb(86, 170, 120)",
+        FramePhase::Render => "rgb(198, 96, 96)",
+        FramePhase::Compositing => "rgb(222, 130, 130)",
+    }
+}
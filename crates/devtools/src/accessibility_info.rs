@@ -0,0 +1,166 @@
+use accesskit::{
+    Action,
+    Node,
+    NodeId as AccessibilityId,
+    Rect,
+    Role,
+};
+use freya_native_core::NodeId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Snapshot of an accessibility node as seen by assistive technology.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct AccessibilityNodeInfo {
+    pub window_id: u64,
+    pub id: AccessibilityId,
+    /// DOM node this accessibility node was created from.
+    pub node_id: Option<NodeId>,
+    pub depth: usize,
+    pub role: Role,
+    pub name: Option<String>,
+    pub value: Option<String>,
+    pub description: Option<String>,
+    pub focusable: bool,
+    pub focused: bool,
+    pub disabled: bool,
+    pub hidden: bool,
+    pub bounds: Option<Rect>,
+    pub children: Vec<AccessibilityId>,
+}
+
+impl AccessibilityNodeInfo {
+    pub fn new(
+        window_id: u64,
+        id: AccessibilityId,
+        node_id: Option<NodeId>,
+        depth: usize,
+        node: &Node,
+        focused: bool,
+    ) -> Self {
+        Self {
+            window_id,
+            id,
+            node_id,
+            depth,
+            role: node.role(),
+            // `a11y_name` is exposed as the class name
+            name: node
+                .label()
+                .or_else(|| node.class_name())
+                .map(str::to_string),
+            value: node.value().map(str::to_string),
+            description: node.description().map(str::to_string),
+            focusable: node.supports_action(Action::Focus),
+            focused,
+            disabled: node.is_disabled(),
+            hidden: node.is_hidden(),
+            bounds: node.bounds(),
+            children: node.children().to_vec(),
+        }
+    }
+
+    /// Find common problems that make this node hard to use with assistive technology.
+    pub fn issues(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = Vec::new();
+
+        let has_name = self.name.is_some() || self.value.is_some();
+
+        if self.focusable && !has_name {
+            issues.push(AccessibilityIssue::FocusableWithoutName);
+        }
+
+        if self.role == Role::Image && !has_name {
+            issues.push(AccessibilityIssue::ImageWithoutName);
+        }
+
+        if self.focusable && self.hidden {
+            issues.push(AccessibilityIssue::FocusableHidden);
+        }
+
+        if self.focusable
+            && self
+                .bounds
+                .is_some_and(|bounds| bounds.width() <= 0. || bounds.height() <= 0.)
+        {
+            issues.push(AccessibilityIssue::FocusableWithoutSize);
+        }
+
+        issues
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccessibilityIssue {
+    FocusableWithoutName,
+    ImageWithoutName,
+    FocusableHidden,
+    FocusableWithoutSize,
+}
+
+impl AccessibilityIssue {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::FocusableWithoutName => "Focusable node without a name",
+            Self::ImageWithoutName => "Image without a name",
+            Self::FocusableHidden => "Hidden node that can still be focused",
+            Self::FocusableWithoutSize => "Focusable node with an empty area",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use accesskit::{
+        Action,
+        Node,
+        NodeId as AccessibilityId,
+        Rect,
+        Role,
+    };
+
+    use super::{
+        AccessibilityIssue,
+        AccessibilityNodeInfo,
+    };
+
+    fn info(node: &Node) -> AccessibilityNodeInfo {
+        AccessibilityNodeInfo::new(0, AccessibilityId(1), None, 0, node, false)
+    }
+
+    #[test]
+    fn issues() {
+        let mut button = Node::new(Role::Button);
+        button.add_action(Action::Focus);
+        button.set_bounds(Rect::new(0., 0., 100., 50.));
+        assert_eq!(
+            info(&button).issues(),
+            vec![AccessibilityIssue::FocusableWithoutName]
+        );
+
+        button.set_label("Submit");
+        assert!(info(&button).issues().is_empty());
+
+        // Nodes that can't be focused don't need a name
+        let container = Node::new(Role::GenericContainer);
+        assert!(info(&container).issues().is_empty());
+
+        let image = Node::new(Role::Image);
+        assert_eq!(
+            info(&image).issues(),
+            vec![AccessibilityIssue::ImageWithoutName]
+        );
+
+        button.set_hidden();
+        button.set_bounds(Rect::new(0., 0., 0., 50.));
+        assert_eq!(
+            info(&button).issues(),
+            vec![
+                AccessibilityIssue::FocusableHidden,
+                AccessibilityIssue::FocusableWithoutSize
+            ]
+        );
+    }
+}
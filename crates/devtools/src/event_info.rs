@@ -0,0 +1,16 @@
+use freya_native_core::NodeId;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// An event that was emitted to the DOM of the inspected app.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct EventInfo {
+    pub name: String,
+    pub node_id: NodeId,
+    /// Nodes listening for this event that it bubbled through, starting from the target node.
+    pub path: Vec<NodeId>,
+    /// Whether a handler prevented the default action of this event.
+    pub cancelled: bool,
+}
@@ -0,0 +1,166 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::{
+    Value,
+    json,
+};
+
+/// A phase of a frame measured by the devtools profiler.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum FramePhase {
+    PollVDOM,
+    UpdateDOM,
+    ApplyMutations,
+    UpdateStates,
+    Events,
+    Layout,
+    Render,
+    Compositing,
+}
+
+impl FramePhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::PollVDOM => "Poll VDOM",
+            Self::UpdateDOM => "Update DOM",
+            Self::ApplyMutations => "Apply mutations",
+            Self::UpdateStates => "Update states",
+            Self::Events => "Events",
+            Self::Layout => "Layout",
+            Self::Render => "Render",
+            Self::Compositing => "Compositing",
+        }
+    }
+
+    /// How nested this phase is, e.g [FramePhase::Compositing] happens inside of [FramePhase::Render].
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::ApplyMutations | Self::UpdateStates | Self::Compositing => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// Time spent in a [FramePhase], in microseconds since the profiler started.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct PhaseTiming {
+    pub phase: FramePhase,
+    pub start: u64,
+    pub duration: u64,
+}
+
+/// Timings of a rendered frame, in microseconds since the profiler started.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct FrameInfo {
+    pub window_id: u64,
+    pub start: u64,
+    pub duration: u64,
+    pub phases: Vec<PhaseTiming>,
+}
+
+/// Convert the given frames to the Chrome trace event format,
+/// which can be opened with `chrome://tracing` or <https://ui.perfetto.dev>.
+pub fn chrome_trace(frames: &[FrameInfo]) -> Value {
+    let events = frames
+        .iter()
+        .flat_map(|frame| {
+            let frame_event = json!({
+                "name": "Frame",
+                "cat": "frame",
+                "ph": "X",
+                "ts": frame.start,
+                "dur": frame.duration,
+                "pid": 1,
+                "tid": frame.window_id,
+            });
+            let phase_events = frame.phases.iter().map(|timing| {
+                json!({
+                    "name": timing.phase.name(),
+                    "cat": "phase",
+                    "ph": "X",
+                    "ts": timing.start,
+                    "dur": timing.duration,
+                    "pid": 1,
+                    "tid": frame.window_id,
+                })
+            });
+            std::iter::once(frame_event).chain(phase_events)
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "traceEvents": events,
+        "displayTimeUnit": "ms",
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{
+        FrameInfo,
+        FramePhase,
+        PhaseTiming,
+        chrome_trace,
+    };
+
+    #[test]
+    fn frames_to_chrome_trace() {
+        let frames = [FrameInfo {
+            window_id: 2,
+            start: 1000,
+            duration: 500,
+            phases: vec![
+                PhaseTiming {
+                    phase: FramePhase::Layout,
+                    start: 1000,
+                    duration: 200,
+                },
+                PhaseTiming {
+                    phase: FramePhase::Render,
+                    start: 1200,
+                    duration: 300,
+                },
+            ],
+        }];
+
+        assert_eq!(
+            chrome_trace(&frames),
+            json!({
+                "traceEvents": [
+                    {
+                        "name": "Frame",
+                        "cat": "frame",
+                        "ph": "X",
+                        "ts": 1000,
+                        "dur": 500,
+                        "pid": 1,
+                        "tid": 2,
+                    },
+                    {
+                        "name": "Layout",
+                        "cat": "phase",
+                        "ph": "X",
+                        "ts": 1000,
+                        "dur": 200,
+                        "pid": 1,
+                        "tid": 2,
+                    },
+                    {
+                        "name": "Render",
+                        "cat": "phase",
+                        "ph": "X",
+                        "ts": 1200,
+                        "dur": 300,
+                        "pid": 1,
+                        "tid": 2,
+                    },
+                ],
+                "displayTimeUnit": "ms",
+            })
+        );
+    }
+}
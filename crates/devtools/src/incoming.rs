@@ -11,6 +11,18 @@ pub struct IncomingMessage {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub enum IncomingMessageAction {
-    HighlightNode { window_id: u64, node_id: NodeId },
-    SetSpeedTo { speed: f32 },
+    HighlightNode {
+        window_id: u64,
+        node_id: NodeId,
+    },
+    SetSpeedTo {
+        speed: f32,
+    },
+    /// Override the attribute of a node, e.g `background` with `red`.
+    SetAttribute {
+        window_id: u64,
+        node_id: NodeId,
+        attribute: String,
+        value: String,
+    },
 }
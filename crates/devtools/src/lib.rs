@@ -1,3 +1,6 @@
+mod accessibility_info;
+mod event_info;
+mod frame_info;
 mod incoming;
 mod node_info;
 mod outgoing;
@@ -5,8 +8,13 @@ mod outgoing;
 #[cfg(feature = "server")]
 mod plugin;
 #[cfg(feature = "server")]
+mod profiler;
+#[cfg(feature = "server")]
 mod server;
 
+pub use accessibility_info::*;
+pub use event_info::*;
+pub use frame_info::*;
 pub use incoming::*;
 pub use node_info::*;
 pub use outgoing::*;
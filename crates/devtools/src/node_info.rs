@@ -83,11 +83,11 @@ impl NodeStateAttributes for NodeState {
             ("margin", AttributeType::Measures(self.layout.margin)),
             ("position", AttributeType::Position(&self.layout.position)),
             (
-                "main_alignment",
+                "main_align",
                 AttributeType::Alignment(&self.layout.main_alignment),
             ),
             (
-                "cross_alignment",
+                "cross_align",
                 AttributeType::Alignment(&self.layout.cross_alignment),
             ),
             (
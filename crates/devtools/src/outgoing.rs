@@ -3,7 +3,12 @@ use serde::{
     Serialize,
 };
 
-use crate::node_info::NodeInfo;
+use crate::{
+    accessibility_info::AccessibilityNodeInfo,
+    event_info::EventInfo,
+    frame_info::FrameInfo,
+    node_info::NodeInfo,
+};
 
 #[derive(Deserialize, Serialize)]
 pub struct OutgoingMessage {
@@ -16,4 +21,15 @@ pub enum OutgoingMessageAction {
         window_id: u64,
         nodes: Vec<NodeInfo>,
     },
+    DispatchedEvents {
+        window_id: u64,
+        events: Vec<EventInfo>,
+    },
+    AccessibilityTree {
+        window_id: u64,
+        nodes: Vec<AccessibilityNodeInfo>,
+    },
+    Frame {
+        frame: FrameInfo,
+    },
 }
@@ -6,7 +6,16 @@ use std::{
     },
 };
 
+use accesskit::{
+    Node,
+    NodeId as AccessibilityId,
+    TreeUpdate,
+};
 use freya_core::{
+    accessibility::{
+        ACCESSIBILITY_ROOT_ID,
+        NodeAccessibility,
+    },
     animation_clock::AnimationClock,
     dom::DioxusDOM,
     node_state_snapshot::NodeStateSnapshot,
@@ -37,9 +46,14 @@ use tungstenite::Message;
 use winit::window::WindowId;
 
 use crate::{
+    AccessibilityNodeInfo,
+    EventInfo,
+    FrameInfo,
+    FramePhase,
     OutgoingMessage,
     OutgoingMessageAction,
     node_info::NodeInfo,
+    profiler::FrameProfiler,
     server::run_server,
 };
 
@@ -50,6 +64,14 @@ pub(crate) type SharedWebsockets = Arc<tokio::sync::Mutex<Websockets>>;
 pub struct WindowState {
     pub animation_clock: AnimationClock,
     pub nodes: Vec<NodeInfo>,
+    pub accessibility_nodes: Vec<AccessibilityNodeInfo>,
+}
+
+/// Accessibility tree of a window, rebuilt from the incremental updates.
+#[derive(Default)]
+struct AccessibilityTreeState {
+    nodes: HashMap<AccessibilityId, Node>,
+    focus: Option<AccessibilityId>,
 }
 
 #[derive(Default)]
@@ -58,6 +80,8 @@ pub struct DevtoolsPlugin {
     websockets: SharedWebsockets,
     init: Option<()>,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
+    accessibility_trees: HashMap<u64, AccessibilityTreeState>,
+    profiler: FrameProfiler,
 }
 
 impl DevtoolsPlugin {
@@ -97,13 +121,20 @@ impl DevtoolsPlugin {
         });
 
         // Update nodes snapshot
-        self.windows.lock().unwrap().insert(
+        let mut windows = self.windows.lock().unwrap();
+        let accessibility_nodes = windows
+            .remove(&window_id)
+            .map(|window| window.accessibility_nodes)
+            .unwrap_or_default();
+        windows.insert(
             window_id,
             WindowState {
                 nodes: new_nodes,
                 animation_clock,
+                accessibility_nodes,
             },
         );
+        drop(windows);
 
         // Notify the existing subscribers of this change
         let outgoing_message = Message::Text(
@@ -130,16 +161,171 @@ impl DevtoolsPlugin {
             }
         });
     }
+
+    pub fn sync_accessibility(&mut self, window_id: WindowId, rdom: &DioxusDOM, tree: &TreeUpdate) {
+        let window_id: u64 = window_id.into();
+        let accessibility_tree = self.accessibility_trees.entry(window_id).or_default();
+
+        if tree.nodes.is_empty() && accessibility_tree.focus == Some(tree.focus) {
+            return;
+        }
+
+        accessibility_tree.focus = Some(tree.focus);
+        accessibility_tree.nodes.extend(tree.nodes.iter().cloned());
+
+        // Map the accessibility nodes back to the DOM
+        let mut dom_nodes = HashMap::new();
+        rdom.traverse_depth_first(|node| {
+            if let Some(accessibility_id) = node.get_accessibility_id() {
+                dom_nodes.insert(accessibility_id, node.id());
+            }
+        });
+
+        // Walk the tree from the root so removed nodes are left out
+        let mut accessibility_nodes = Vec::new();
+        let mut stack = vec![(ACCESSIBILITY_ROOT_ID, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let Some(node) = accessibility_tree.nodes.get(&id) else {
+                continue;
+            };
+            accessibility_nodes.push(AccessibilityNodeInfo::new(
+                window_id,
+                id,
+                dom_nodes.get(&id).copied(),
+                depth,
+                node,
+                id == tree.focus,
+            ));
+            stack.extend(
+                node.children()
+                    .iter()
+                    .rev()
+                    .map(|child| (*child, depth + 1)),
+            );
+        }
+        accessibility_tree
+            .nodes
+            .retain(|id, _| dom_nodes.contains_key(id));
+
+        if let Some(window) = self.windows.lock().unwrap().get_mut(&window_id) {
+            window.accessibility_nodes = accessibility_nodes.clone();
+        }
+
+        // Notify the existing subscribers of this change
+        let outgoing_message = Message::Text(
+            serde_json::to_string(&OutgoingMessage {
+                action: OutgoingMessageAction::AccessibilityTree {
+                    window_id,
+                    nodes: accessibility_nodes,
+                },
+            })
+            .unwrap()
+            .into(),
+        );
+        let websockets = self.websockets.clone();
+        tokio::spawn(async move {
+            for websocket in websockets.lock().await.values_mut() {
+                websocket.send(outgoing_message.clone()).await.unwrap();
+            }
+        });
+    }
+
+    /// Measure the phases of the frames and stream them once rendered.
+    fn profile(&mut self, event: &PluginEvent) {
+        let (window, started, finished) = match event {
+            PluginEvent::StartedPollingVDOM { window, .. } => {
+                (window, Some(FramePhase::PollVDOM), None)
+            }
+            PluginEvent::FinishedPollingVDOM { window, .. } => {
+                (window, None, Some(FramePhase::PollVDOM))
+            }
+            PluginEvent::StartedUpdatingDOM { window, .. } => {
+                self.profiler
+                    .start(window.id().into(), FramePhase::UpdateDOM);
+                (window, Some(FramePhase::ApplyMutations), None)
+            }
+            PluginEvent::StartedUpdatingStates { window, .. } => (
+                window,
+                Some(FramePhase::UpdateStates),
+                Some(FramePhase::ApplyMutations),
+            ),
+            PluginEvent::FinishedUpdatingStates { window, .. } => {
+                (window, None, Some(FramePhase::UpdateStates))
+            }
+            PluginEvent::FinishedUpdatingDOM { window, .. } => {
+                (window, None, Some(FramePhase::UpdateDOM))
+            }
+            PluginEvent::StartedMeasuringEvents { window, .. } => {
+                (window, Some(FramePhase::Events), None)
+            }
+            PluginEvent::FinishedMeasuringEvents { window, .. } => {
+                (window, None, Some(FramePhase::Events))
+            }
+            PluginEvent::StartedMeasuringLayout { window, .. } => {
+                (window, Some(FramePhase::Layout), None)
+            }
+            PluginEvent::FinishedMeasuringLayout { window, .. } => {
+                (window, None, Some(FramePhase::Layout))
+            }
+            PluginEvent::BeforeRender { window, .. } => (window, Some(FramePhase::Render), None),
+            PluginEvent::StartedCompositing { window } => {
+                (window, Some(FramePhase::Compositing), None)
+            }
+            PluginEvent::FinishedCompositing { window } => {
+                (window, None, Some(FramePhase::Compositing))
+            }
+            PluginEvent::AfterRender { window, .. } => {
+                let window_id: u64 = window.id().into();
+                self.profiler.finish(window_id, FramePhase::Render);
+                if let Some(frame) = self.profiler.finish_frame(window_id) {
+                    self.send_frame(frame);
+                }
+                return;
+            }
+            PluginEvent::WindowClosed { window, .. } => {
+                self.profiler.remove_window(window.id().into());
+                return;
+            }
+            _ => return,
+        };
+
+        let window_id: u64 = window.id().into();
+        if let Some(phase) = finished {
+            self.profiler.finish(window_id, phase);
+        }
+        if let Some(phase) = started {
+            self.profiler.start(window_id, phase);
+        }
+    }
+
+    fn send_frame(&self, frame: FrameInfo) {
+        let outgoing_message = Message::Text(
+            serde_json::to_string(&OutgoingMessage {
+                action: OutgoingMessageAction::Frame { frame },
+            })
+            .unwrap()
+            .into(),
+        );
+        let websockets = self.websockets.clone();
+        tokio::spawn(async move {
+            for websocket in websockets.lock().await.values_mut() {
+                websocket.send(outgoing_message.clone()).await.unwrap();
+            }
+        });
+    }
 }
 
 impl FreyaPlugin for DevtoolsPlugin {
     fn on_event(&mut self, event: &PluginEvent, plugin_handle: PluginHandle) {
+        self.profile(event);
+
         match event {
             PluginEvent::WindowClosed { window, .. } => {
                 let window_id: u64 = window.id().into();
 
                 // Update nodes snapshot
                 self.windows.lock().unwrap().remove(&window_id);
+                self.accessibility_trees.remove(&window_id);
 
                 // Notify the existing subscribers of this change
                 let outgoing_message = Message::Text(
@@ -198,6 +384,36 @@ impl FreyaPlugin for DevtoolsPlugin {
 
                 self.sync(window.id(), rdom, &layout, animation_clock.clone());
             }
+            PluginEvent::AccessibilityTreeUpdated { window, fdom, tree } => {
+                self.sync_accessibility(window.id(), fdom.rdom(), tree);
+            }
+            PluginEvent::DispatchedEvents { window, events, .. } => {
+                let window_id: u64 = window.id().into();
+                let events = events
+                    .iter()
+                    .map(|event| EventInfo {
+                        name: <&str>::from(event.name).to_string(),
+                        node_id: event.node_id,
+                        path: event.path.clone(),
+                        cancelled: event.cancelled,
+                    })
+                    .collect();
+
+                // Stream the events to the existing subscribers
+                let outgoing_message = Message::Text(
+                    serde_json::to_string(&OutgoingMessage {
+                        action: OutgoingMessageAction::DispatchedEvents { window_id, events },
+                    })
+                    .unwrap()
+                    .into(),
+                );
+                let websockets = self.websockets.clone();
+                tokio::spawn(async move {
+                    for websocket in websockets.lock().await.values_mut() {
+                        websocket.send(outgoing_message.clone()).await.unwrap();
+                    }
+                });
+            }
             PluginEvent::WindowCreated { .. } => {
                 if self.init.is_none() {
                     let nodes = self.windows.clone();
@@ -215,4 +431,8 @@ impl FreyaPlugin for DevtoolsPlugin {
             _ => {}
         }
     }
+
+    fn wants_dispatched_events(&self) -> bool {
+        true
+    }
 }
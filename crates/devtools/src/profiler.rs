@@ -0,0 +1,126 @@
+use std::{
+    collections::HashMap,
+    time::Instant,
+};
+
+use crate::{
+    FrameInfo,
+    FramePhase,
+    PhaseTiming,
+};
+
+#[derive(Default)]
+struct WindowProfile {
+    started: HashMap<FramePhase, u64>,
+    phases: Vec<PhaseTiming>,
+}
+
+/// Collects the timings of the frames of every window.
+#[derive(Default)]
+pub(crate) struct FrameProfiler {
+    epoch: Option<Instant>,
+    windows: HashMap<u64, WindowProfile>,
+}
+
+impl FrameProfiler {
+    /// Microseconds since the profiler started.
+    fn now(&mut self) -> u64 {
+        self.epoch
+            .get_or_insert_with(Instant::now)
+            .elapsed()
+            .as_micros() as u64
+    }
+
+    pub fn start(&mut self, window_id: u64, phase: FramePhase) {
+        let now = self.now();
+        self.windows
+            .entry(window_id)
+            .or_default()
+            .started
+            .insert(phase, now);
+    }
+
+    pub fn finish(&mut self, window_id: u64, phase: FramePhase) {
+        let now = self.now();
+        let profile = self.windows.entry(window_id).or_default();
+        if let Some(start) = profile.started.remove(&phase) {
+            profile.phases.push(PhaseTiming {
+                phase,
+                start,
+                duration: now - start,
+            });
+        }
+    }
+
+    /// Take the phases measured since the last frame of the window.
+    pub fn finish_frame(&mut self, window_id: u64) -> Option<FrameInfo> {
+        let now = self.now();
+        let profile = self.windows.get_mut(&window_id)?;
+        let mut phases = std::mem::take(&mut profile.phases);
+        phases.sort_by_key(|timing| timing.start);
+        let start = phases.first()?.start;
+
+        Some(FrameInfo {
+            window_id,
+            start,
+            duration: now - start,
+            phases,
+        })
+    }
+
+    pub fn remove_window(&mut self, window_id: u64) {
+        self.windows.remove(&window_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        thread::sleep,
+        time::Duration,
+    };
+
+    use super::FrameProfiler;
+    use crate::FramePhase;
+
+    #[test]
+    fn frame_profiler() {
+        let mut profiler = FrameProfiler::default();
+
+        profiler.start(1, FramePhase::Render);
+        sleep(Duration::from_millis(1));
+        profiler.start(1, FramePhase::Compositing);
+        sleep(Duration::from_millis(2));
+        profiler.finish(1, FramePhase::Compositing);
+        profiler.finish(1, FramePhase::Render);
+        // Phases of other windows and unfinished phases are not part of the frame
+        profiler.start(2, FramePhase::Layout);
+        profiler.finish(2, FramePhase::Layout);
+        profiler.start(1, FramePhase::Layout);
+
+        let frame = profiler.finish_frame(1).unwrap();
+        assert_eq!(frame.window_id, 1);
+        assert_eq!(
+            frame
+                .phases
+                .iter()
+                .map(|timing| timing.phase)
+                .collect::<Vec<_>>(),
+            vec![FramePhase::Render, FramePhase::Compositing]
+        );
+
+        let render = &frame.phases[0];
+        let compositing = &frame.phases[1];
+        assert_eq!(frame.start, render.start);
+        assert!(compositing.duration >= 2000);
+        assert!(render.start <= compositing.start);
+        assert!(render.start + render.duration >= compositing.start + compositing.duration);
+        assert!(frame.duration >= render.duration);
+
+        // The phases are taken by the frame
+        assert!(profiler.finish_frame(1).is_none());
+
+        profiler.remove_window(2);
+        assert!(profiler.finish_frame(2).is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    str::FromStr,
     sync::{
         Arc,
         Mutex,
@@ -17,7 +18,10 @@ use freya_core::{
     },
     plugins::PluginHandle,
 };
-use freya_native_core::NodeId;
+use freya_native_core::{
+    NodeId,
+    attributes::AttributeName,
+};
 use futures::{
     sink::SinkExt,
     stream::StreamExt,
@@ -97,7 +101,15 @@ async fn serve_websocket(
     let (mut write, mut read) = websocket.split();
 
     let windows_snapshot = windows.lock().unwrap().clone();
-    for (window_id, WindowState { nodes, .. }) in windows_snapshot {
+    for (
+        window_id,
+        WindowState {
+            nodes,
+            accessibility_nodes,
+            ..
+        },
+    ) in windows_snapshot
+    {
         let message = Message::Text(
             serde_json::to_string(&OutgoingMessage {
                 action: OutgoingMessageAction::Update { window_id, nodes },
@@ -107,6 +119,19 @@ async fn serve_websocket(
 
         // Send nodes snapshot
         write.send(message).await?;
+
+        let message = Message::Text(
+            serde_json::to_string(&OutgoingMessage {
+                action: OutgoingMessageAction::AccessibilityTree {
+                    window_id,
+                    nodes: accessibility_nodes,
+                },
+            })?
+            .into(),
+        );
+
+        // Send accessibility tree snapshot
+        write.send(message).await?;
     }
 
     // Store websocket
@@ -134,6 +159,25 @@ async fn serve_websocket(
                                 animation_clock.set_speed(speed);
                             }
                         }
+                        IncomingMessageAction::SetAttribute {
+                            window_id,
+                            node_id,
+                            attribute,
+                            value,
+                        } => {
+                            if let Ok(attribute) = AttributeName::from_str(&attribute) {
+                                plugin_handle.send_event_loop_event(EventLoopMessage {
+                                    window_id: Some(window_id.into()),
+                                    action: EventLoopMessageAction::SetAttribute {
+                                        node_id,
+                                        attribute,
+                                        value,
+                                    },
+                                });
+                            } else {
+                                tracing::warn!("Unknown attribute {attribute}");
+                            }
+                        }
                     }
                 } else {
                     println!("failed to parse");
@@ -38,6 +38,7 @@ def_attribute!(
     color,
 
     /// You can specify the size of the text using `font_size`.
+    /// Use `inherit` to keep the size of the parent element.
     ///
     /// ### Example
     ///
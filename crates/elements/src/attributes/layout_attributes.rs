@@ -390,6 +390,7 @@ def_attribute!(
     /// - `normal` (default): Uses parent bounds.
     /// - `fit`: Uses parent bounds but later shrunks to the size of the biggest element inside.
     /// - `flex`: Marks the container as flex container, children of this element will be able to use `size`/`size(n)` in their `width` and `height` attributes.
+    /// - `grid`: Marks the container as grid container, children of this element will be placed in the cells defined by `grid_columns` and `grid_rows`.
     ///
     ///
     /// ### `fit`
@@ -469,6 +470,176 @@ def_attribute!(
     /// ```
     wrap_content,
 
+    /// ### grid_columns
+    ///
+    /// Specify the columns of a `grid` container (see `content`), separated by whitespaces.
+    ///
+    /// Accepted values for each column:
+    ///
+    /// - `auto`: Sized by the biggest element placed only in this column.
+    /// - Pixels (e.g `100`) and percentages (e.g `25%`) of the container.
+    /// - `flex`/`flex(n)`: Share the space left by the other columns.
+    ///
+    /// Children are placed in order, row by row, unless they specify a `grid_column`, `grid_row` or `grid_area`.
+    /// Use `spacing` to separate columns and rows.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             content: "grid",
+    ///             grid_columns: "150 flex(1) flex(2)",
+    ///             grid_rows: "50 auto",
+    ///             spacing: "10",
+    ///             rect { width: "fill", height: "fill", background: "red" }
+    ///             rect { width: "fill", height: "fill", background: "green" }
+    ///             rect { width: "fill", height: "fill", background: "blue" }
+    ///             label { "Rows without a template are sized automatically" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_columns,
+
+    /// ### grid_rows
+    ///
+    /// Specify the rows of a `grid` container, accepts the same values as `grid_columns`.
+    /// Rows needed by the children but not specified here are sized as `auto`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             content: "grid",
+    ///             grid_columns: "flex flex",
+    ///             grid_rows: "25% flex",
+    ///             rect { width: "fill", height: "fill", background: "red" }
+    ///             rect { width: "fill", height: "fill", background: "green" }
+    ///             rect { width: "fill", height: "fill", background: "blue" }
+    ///             rect { width: "fill", height: "fill", background: "black" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_rows,
+
+    /// ### grid_areas
+    ///
+    /// Name the regions of a `grid` container so its children can be placed with `grid_area`.
+    /// Rows are separated by commas and cells by whitespaces, use `.` for cells without a name.
+    /// Every area must have a rectangular shape.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             content: "grid",
+    ///             grid_columns: "200 flex",
+    ///             grid_rows: "50 flex 50",
+    ///             grid_areas: "header header, sidebar main, footer footer",
+    ///             rect { grid_area: "header", width: "fill", height: "fill", background: "red" }
+    ///             rect { grid_area: "sidebar", width: "fill", height: "fill", background: "green" }
+    ///             rect { grid_area: "main", width: "fill", height: "fill", background: "white" }
+    ///             rect { grid_area: "footer", width: "fill", height: "fill", background: "blue" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_areas,
+
+    /// ### grid_column
+    ///
+    /// Specify the column (starting at `0`) where this element starts inside its `grid` container.
+    /// Elements without a column and row are placed automatically.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             content: "grid",
+    ///             grid_columns: "flex flex flex",
+    ///             rect {
+    ///                 grid_column: "2",
+    ///                 grid_row: "1",
+    ///                 width: "fill",
+    ///                 height: "50",
+    ///                 background: "red"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_column,
+
+    /// ### grid_row
+    ///
+    /// Specify the row (starting at `0`) where this element starts inside its `grid` container.
+    /// See `grid_column`.
+    grid_row,
+
+    /// ### grid_column_span
+    ///
+    /// Specify how many columns this element occupies inside its `grid` container, `1` by default.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             content: "grid",
+    ///             grid_columns: "flex flex flex",
+    ///             rect {
+    ///                 grid_column_span: "2",
+    ///                 width: "fill",
+    ///                 height: "50",
+    ///                 background: "red"
+    ///             }
+    ///             rect {
+    ///                 width: "fill",
+    ///                 height: "50",
+    ///                 background: "blue"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_column_span,
+
+    /// ### grid_row_span
+    ///
+    /// Specify how many rows this element occupies inside its `grid` container, `1` by default.
+    /// See `grid_column_span`.
+    grid_row_span,
+
+    /// ### grid_area
+    ///
+    /// Place this element in a named area of its `grid` container. See `grid_areas`.
+    grid_area,
+
     /// ### main_align
     ///
     /// Control how the inner elements are positioned inside the element. You can combine it with the `direction` attribute to create complex flows.
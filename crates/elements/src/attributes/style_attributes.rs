@@ -39,7 +39,7 @@ def_attribute!(
     /// Specify borders for an element.
     ///
     /// The `border` attribute follows this syntax:
-    /// border: `<width(s)> <alignment> <fill>`
+    /// border: `<width(s)> <alignment> [style] <fill>`
     ///
     /// Width specification follows CSS-like patterns:
     /// - Single value: Applied to all sides
@@ -52,6 +52,11 @@ def_attribute!(
     /// - `outer`: Border drawn outside the element bounds
     /// - `center` (default): Border centered on the element bounds
     ///
+    /// Style is optional and can be one of:
+    /// - `solid` (default): A continuous line
+    /// - `dashed` or `dashed(<dash>, <gap>)`: Dashes of the given length, three times the border width by default
+    /// - `dotted` or `dotted(<gap>)`: Round dots separated by the given gap, the border width by default
+    ///
     /// *Border alignment* determines how the border is positioned relative to the element's edge. Alignment can be `inner`, `outer`, or `center`.
     ///
     /// Note: Borders exist outside the layout system, which means they will be drawn underneath child elements and may overlap with adjacent elements.
@@ -85,6 +90,20 @@ def_attribute!(
     /// }
     /// ```
     ///
+    /// A dashed, blue border that respects the corner radius.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             corner_radius: "8",
+    ///             border: "2 inner dashed(6, 4) blue",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ///
     /// Borders can take any valid fill type, including gradients.
     ///
     /// ```rust, no_run
@@ -5,7 +5,9 @@ def_attribute!(
     ///
     /// Syntax is `<0-360>deg`.
     ///
-    /// Note: Rotations don't affect neither layout or mouse events, they are merely a rendering effect.
+    /// Rotations are applied around the `transform_origin`, the center by default.
+    ///
+    /// Note: Rotations don't affect the layout, they are merely a rendering effect. Mouse events do follow the rotated shape.
     ///
     /// ### Example
     ///
@@ -46,13 +48,44 @@ def_attribute!(
     /// ```
     opacity,
 
+    /// Apply visual filters to this element and its children.
+    ///
+    /// Accepts a chain of functions separated by spaces, applied in the order they are declared:
+    /// - `blur(<f32>)`: Gaussian blur with the given standard deviation.
+    /// - `brightness(<amount>)`, `contrast(<amount>)` and `saturate(<amount>)`: `1` leaves the element unchanged.
+    /// - `grayscale(<amount>)`, `invert(<amount>)` and `sepia(<amount>)`: `0` leaves the element unchanged and `1` applies it completely.
+    /// - `hue-rotate(<f32>deg)`
+    /// - `drop-shadow(<x> <y> [<blur>] [<color>])`: The color is black by default.
+    ///
+    /// Amounts can be numbers (`0.5`) or percentages (`50%`).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             filter: "grayscale(100%) drop-shadow(2 2 4 black)",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    filter,
+
     /// Specify the scale for this element.
     ///
     /// Accepted syntax:
     /// - `<f32>`: Same value for both scale x and y.
     /// - `<f32>, <f32>`: Specify the scale x and y separately.
     ///
-    /// Note: Scaling doesn't affect neither layout or mouse events, it is only a rendering effect.
+    /// Scaling is applied around the `transform_origin`, the center by default.
+    ///
+    /// Note: Scaling doesn't affect the layout, it is only a rendering effect. Mouse events do follow the scaled shape.
     ///
     /// ### Example
     ///
@@ -72,4 +105,179 @@ def_attribute!(
     /// ```
     scale,
 
+    /// Move this element by the given offset.
+    ///
+    /// Accepted syntax:
+    /// - `<f32>`: Offset in the x axis.
+    /// - `<f32> <f32>`: Offset in the x and y axis.
+    ///
+    /// Note: Translations don't affect the layout, they are merely a rendering effect. Mouse events do follow the translated shape.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             translate: "20 10",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    translate,
+
+    /// Skew this element in the x and optionally y axis.
+    ///
+    /// Syntax is `<f32>deg` or `<f32>deg <f32>deg`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             skew: "20deg",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    skew,
+
+    /// Transform this element with an arbitrary affine matrix.
+    ///
+    /// Syntax is `<a>, <b>, <c>, <d>, <e>, <f>`, just like the CSS `matrix()` function.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             matrix: "1, 0.2, 0, 1, 10, 0",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    matrix,
+
+    /// Specify the point around which the transformations (`rotate`, `scale`, `skew` and `matrix`) of this element are applied.
+    ///
+    /// Accepted syntax, for one or both axis:
+    /// - `left`, `center`, `right`, `top` or `bottom`.
+    /// - `<f32>%`: Percentage of the element size.
+    /// - `<f32>`: Pixels from the top-left corner of the element.
+    ///
+    /// By default it is `center`.
+    ///
+    /// The transformations of an element are composed in the order they are declared.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             background: "red",
+    ///             transform_origin: "top left",
+    ///             rotate: "45deg",
+    ///             label {
+    ///                 "Freya!"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    transform_origin,
+
+    /// Clip this element and its children to a shape. Clicks outside of the shape won't reach the element.
+    ///
+    /// Accepted syntax:
+    /// - `circle(<radius> [at <x> <y>])`: The radius percentage is relative to the smallest side of the element.
+    /// - `ellipse(<radius x> <radius y> [at <x> <y>])`
+    /// - `polygon(<x> <y>, <x> <y>, <x> <y>, ...)`
+    /// - `inset(<top> [<right> [<bottom> [<left>]]] [round <radius>])`
+    /// - `path(<svg path data>)`: Relative to the top-left corner of the element.
+    ///
+    /// Lengths can be pixels (`<f32>`) or percentages of the element size (`<f32>%`), and the center defaults to `50% 50%`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             clip_path: "circle(50%)",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    clip_path,
+
+    /// Hide parts of this element and its children using the alpha or the luminance of a fill or of an image.
+    ///
+    /// Accepted syntax: `<fill | image> [alpha | luminance]`. The mode is `alpha` by default.
+    ///
+    /// When using `image`, the image is taken from the `mask_data` attribute and stretched to the size of the element.
+    ///
+    /// Note: Other elements can't be used as masks.
+    /// Mouse events are only clipped to the bounds of masked elements, not to their visible parts.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             mask: "linear-gradient(black 0%, transparent 100%)",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    mask,
+
+    /// Specify the image data used by `mask: "image"`. It accepts the same values as `image_data`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// static MASK: &[u8] = include_bytes!("../_docs/rust_logo.png");
+    ///
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "200",
+    ///             height: "200",
+    ///             background: "blue",
+    ///             mask: "image",
+    ///             mask_data: static_bytes(MASK),
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    mask_data,
 );
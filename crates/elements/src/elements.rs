@@ -37,11 +37,19 @@ def_element!(
         position_bottom,
         position_left,
         layer,
+        grid_column,
+        grid_row,
+        grid_column_span,
+        grid_row_span,
+        grid_area,
 
         // Children layout
         direction,
         content,
         wrap_content,
+        grid_columns,
+        grid_rows,
+        grid_areas,
         main_align,
         cross_align,
         spacing,
@@ -81,7 +89,15 @@ def_element!(
         // Transform
         rotate,
         opacity,
+        filter,
+        translate,
+        skew,
+        matrix,
+        transform_origin,
         scale,
+        clip_path,
+        mask,
+        mask_data,
 
         // Reference
         canvas_reference,
@@ -187,6 +203,11 @@ def_element!(
         position_bottom,
         position_left,
         layer,
+        grid_column,
+        grid_row,
+        grid_column_span,
+        grid_row_span,
+        grid_area,
 
         // Children layout
         main_align,
@@ -218,6 +239,14 @@ def_element!(
         // Transform
         rotate,
         opacity,
+        filter,
+        translate,
+        skew,
+        matrix,
+        transform_origin,
+        clip_path,
+        mask,
+        mask_data,
 
         // Reference
         reference,
@@ -328,6 +357,11 @@ def_element!(
         position_bottom,
         position_left,
         layer,
+        grid_column,
+        grid_row,
+        grid_column_span,
+        grid_row_span,
+        grid_area,
 
         // Children layout
         main_align,
@@ -354,6 +388,13 @@ def_element!(
         // Transform
         rotate,
         opacity,
+        translate,
+        skew,
+        matrix,
+        transform_origin,
+        clip_path,
+        mask,
+        mask_data,
 
         // Text Editing
         cursor_index,
@@ -488,6 +529,11 @@ def_element!(
         position_bottom,
         position_left,
         layer,
+        grid_column,
+        grid_row,
+        grid_column_span,
+        grid_row_span,
+        grid_area,
 
         // Children layout
         direction,
@@ -502,6 +548,14 @@ def_element!(
         // Transform
         rotate,
         opacity,
+        filter,
+        translate,
+        skew,
+        matrix,
+        transform_origin,
+        clip_path,
+        mask,
+        mask_data,
 
         // Image
         image_data,
@@ -620,10 +674,23 @@ def_element!(
         position_bottom,
         position_left,
         layer,
+        grid_column,
+        grid_row,
+        grid_column_span,
+        grid_row_span,
+        grid_area,
 
         // Transform
         rotate,
         opacity,
+        filter,
+        translate,
+        skew,
+        matrix,
+        transform_origin,
+        clip_path,
+        mask,
+        mask_data,
 
         // Svg
         color,
@@ -1,17 +1,29 @@
+pub mod composition;
+pub mod drag;
 pub mod file;
+pub mod focus;
+pub mod gesture;
 pub mod keyboard;
+pub mod layout;
 pub mod mouse;
 pub mod pointer;
+pub mod scroll;
 pub mod touch;
 pub mod wheel;
 
 use std::any::Any;
 
+pub use composition::*;
 use dioxus_core::Event;
+pub use drag::*;
 pub use file::*;
+pub use focus::*;
+pub use gesture::*;
 pub use keyboard::*;
+pub use layout::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use scroll::*;
 pub use touch::*;
 pub use wheel::*;
 
@@ -20,6 +32,12 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type CompositionEvent = Event<CompositionData>;
+pub type FocusEvent = Event<FocusData>;
+pub type DragEvent = Event<DragData>;
+pub type LayoutEvent = Event<LayoutData>;
+pub type ScrollEvent = Event<ScrollData>;
+pub type GestureEvent = Event<GestureData>;
 
 /// A platform specific event.
 #[doc(hidden)]
@@ -0,0 +1,85 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    CompositionData;
+
+    /// The `compositionstart` event fires when the user starts composing text with an IME (Input Method Editor) in the currently focused element.
+    ///
+    /// Event Data: [`CompositionData`](crate::events::CompositionData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             oncompositionstart: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    oncompositionstart
+
+    /// The `compositionupdate` event fires when the text being composed with an IME changes in the currently focused element.
+    /// This text is not committed yet, so editors usually render it inline at the cursor.
+    ///
+    /// Event Data: [`CompositionData`](crate::events::CompositionData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             oncompositionupdate: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    oncompositionupdate
+
+    /// The `compositionend` event fires when the user finishes or cancels composing text with an IME in the currently focused element.
+    /// The committed text, if any, is received right after as a [`keydown`](crate::events::onkeydown()) event.
+    ///
+    /// Event Data: [`CompositionData`](crate::events::CompositionData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             oncompositionend: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    oncompositionend
+];
+
+/// Data of a Composition event.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompositionData {
+    /// Text being composed.
+    pub text: String,
+    /// Byte range of the cursor inside the composed text, `None` means the cursor should be hidden.
+    pub cursor: Option<(usize, usize)>,
+}
+
+impl CompositionData {
+    pub fn new(text: String, cursor: Option<(usize, usize)>) -> Self {
+        Self { text, cursor }
+    }
+}
+
+impl From<&ErasedEventData> for CompositionData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<CompositionData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    any::Any,
+    fmt,
+    sync::Arc,
+};
+
+use torin::geometry::CursorPoint;
+
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    DragData;
+
+    /// The `dragover` event fires when the user moves a dragged payload over an element, even if it comes from another window.
+    ///
+    /// Event Data: [`DragData`](crate::events::DragData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             ondragover: |e| println!("Dragging over at {:?}", e.element_coordinates)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ondragover
+
+    /// The `dragleave` event fires when the user moves a dragged payload out of an element.
+    /// It's the opposite of [`ondragover`](crate::events::ondragover()).
+    ///
+    /// Event Data: [`DragData`](crate::events::DragData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             ondragleave: |_| println!("Left!")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ondragleave
+
+    /// The `drop` event fires when the user drops a dragged payload over an element.
+    ///
+    /// Event Data: [`DragData`](crate::events::DragData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             ondrop: |e| println!("Dropped {:?}", e.payload.text())
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ondrop
+
+    /// The `globaldragover` event fires when the user moves a dragged payload anywhere in the window.
+    ///
+    /// Event Data: [`DragData`](crate::events::DragData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             onglobaldragover: |e| println!("Dragging at {:?}", e.screen_coordinates)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onglobaldragover
+
+    /// The `globaldragend` event fires in the windows involved in a drag session once it ends, either because the payload was dropped or because the drag was cancelled.
+    ///
+    /// Event Data: [`DragData`](crate::events::DragData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             onglobaldragend: |_| println!("Drag ended")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onglobaldragend
+];
+
+/// The data carried by a drag session.
+///
+/// It can hold a typed value, only readable by Freya apps, and plain text and URI representations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Default)]
+pub struct DragPayload {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value: Option<Arc<dyn Any + Send + Sync>>,
+    text: Option<String>,
+    uri: Option<String>,
+}
+
+impl DragPayload {
+    /// Create a payload with a typed value.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            value: Some(Arc::new(value)),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = Some(uri.into());
+        self
+    }
+
+    /// Get the typed value of this payload, if it is of type `T`.
+    pub fn value<T: Any>(&self) -> Option<&T> {
+        self.value.as_ref()?.downcast_ref()
+    }
+
+    /// Get the plain text representation of this payload.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Get the URI representation of this payload.
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+}
+
+impl PartialEq for DragPayload {
+    fn eq(&self, other: &Self) -> bool {
+        let same_value = match (&self.value, &other.value) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        same_value && self.text == other.text && self.uri == other.uri
+    }
+}
+
+impl fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragPayload")
+            .field("value", &self.value.is_some())
+            .field("text", &self.text)
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
+
+/// Data of a Drag event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub payload: DragPayload,
+}
+
+impl DragData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        payload: DragPayload,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            payload,
+        }
+    }
+
+    /// Get the pointer coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the pointer coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+}
+
+impl From<&ErasedEventData> for DragData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<DragData>().cloned().unwrap()
+    }
+}
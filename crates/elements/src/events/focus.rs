@@ -0,0 +1,128 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    FocusData;
+
+    /// The `focus` event fires when the element gains the focus. It does not bubble, use [`onfocusin`](crate::events::onfocusin()) if you want it to bubble.
+    /// Only elements with an `a11y_id` can be focused.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///     rsx!(
+    ///         rect {
+    ///             a11y_id: focus.attribute(),
+    ///             onfocus: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onfocus
+
+    /// The `blur` event fires when the element loses the focus. It does not bubble, use [`onfocusout`](crate::events::onfocusout()) if you want it to bubble.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///     rsx!(
+    ///         rect {
+    ///             a11y_id: focus.attribute(),
+    ///             onblur: |e| println!("Event: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onblur
+
+    /// The `focusin` event fires when the element or any of its descendants gains the focus.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///     rsx!(
+    ///         rect {
+    ///             onfocusin: |e| println!("Event: {e:?}"),
+    ///             rect {
+    ///                 a11y_id: focus.attribute(),
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onfocusin
+
+    /// The `focusout` event fires when the element or any of its descendants loses the focus.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///     rsx!(
+    ///         rect {
+    ///             onfocusout: |e| println!("Event: {e:?}"),
+    ///             rect {
+    ///                 a11y_id: focus.attribute(),
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onfocusout
+];
+
+/// What caused a focus change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FocusOrigin {
+    /// Navigating with the keyboard, e.g pressing `Tab`.
+    Keyboard,
+    /// Clicking or pressing an element.
+    Pointer,
+    /// Focusing an element from code outside of a pointer interaction, e.g in a timer, or from assistive technologies.
+    #[default]
+    Programmatic,
+}
+
+/// Data of a Focus event.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FocusData {
+    pub origin: FocusOrigin,
+}
+
+impl FocusData {
+    pub fn new(origin: FocusOrigin) -> Self {
+        Self { origin }
+    }
+
+    /// Check whether the focus was changed with the keyboard.
+    pub fn is_keyboard(&self) -> bool {
+        self.origin == FocusOrigin::Keyboard
+    }
+}
+
+impl From<&ErasedEventData> for FocusData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<FocusData>().cloned().unwrap()
+    }
+}
@@ -0,0 +1,110 @@
+use torin::geometry::CursorPoint;
+
+use crate::{
+    events::{
+        touch::TouchPhase,
+        ErasedEventData,
+    },
+    impl_event,
+};
+
+impl_event! [
+    GestureData;
+
+    /// The `pinch` event fires when the user pinches with two fingers on a touchpad while hovering over the element.
+    /// The delta is the change of magnification, positive when zooming in.
+    ///
+    /// Event Data: [`GestureData`](crate::events::GestureData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onpinch: |e: GestureEvent| println!("Zooming by {}", e.get_delta())
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onpinch
+
+    /// The `rotate` event fires when the user rotates two fingers on a touchpad while hovering over the element.
+    /// The delta is the change of rotation in degrees, positive when rotating counterclockwise.
+    ///
+    /// Event Data: [`GestureData`](crate::events::GestureData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onrotate: |e: GestureEvent| println!("Rotating by {}", e.get_delta())
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onrotate
+];
+
+/// Data of a touchpad Gesture event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub delta: f64,
+    pub phase: TouchPhase,
+}
+
+impl GestureData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        delta: f64,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            delta,
+            phase,
+        }
+    }
+}
+
+impl GestureData {
+    /// Get the cursor coordinates relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the change since the previous event of this gesture.
+    pub fn get_delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+}
+
+impl From<&ErasedEventData> for GestureData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<GestureData>().cloned().unwrap()
+    }
+}
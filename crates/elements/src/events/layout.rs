@@ -0,0 +1,76 @@
+use torin::geometry::{
+    Area,
+    Size2D,
+};
+
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    LayoutData;
+
+    /// The `layout` event fires after measuring the layout when the area of the element or the size of its content changes.
+    /// It also fires the first time the element is measured.
+    ///
+    /// Event Data: [`LayoutData`](crate::events::LayoutData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "fill",
+    ///             height: "50%",
+    ///             onlayout: |e: LayoutEvent| println!("New area: {:?}", e.area)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onlayout
+
+    /// The `resize` event fires after measuring the layout when the size of the element changes.
+    /// It also fires the first time the element is measured.
+    ///
+    /// Event Data: [`LayoutData`](crate::events::LayoutData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "fill",
+    ///             height: "50%",
+    ///             onresize: |e: LayoutEvent| println!("New size: {:?}", e.area.size)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onresize
+];
+
+/// Data of a Layout event.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LayoutData {
+    /// Area occupied by the element.
+    pub area: Area,
+    /// Size of the content of the element, including its padding.
+    pub inner_sizes: Size2D,
+}
+
+impl LayoutData {
+    pub fn new(area: Area, inner_sizes: Size2D) -> Self {
+        Self { area, inner_sizes }
+    }
+}
+
+impl From<&ErasedEventData> for LayoutData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<LayoutData>().cloned().unwrap()
+    }
+}
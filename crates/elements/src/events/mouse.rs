@@ -96,6 +96,28 @@ impl_event! [
     /// ```
     onrightclick
 
+    /// The `doubleclick` event fires when the user clicks an element twice in a row with the left button of the mouse.
+    /// For triple clicks and beyond, check the [`MouseData`](crate::events::MouseData)'s `click_count` property instead.
+    ///
+    /// Event Data: [`MouseData`](crate::events::MouseData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             ondoubleclick: |_| println!("Double clicked!")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ondoubleclick
+
     /// The `mouseup` event fires when the user ends the click in an element with the left button of the mouse.
     ///
     /// Event Data: [`MouseData`](crate::events::MouseData)
@@ -285,6 +307,7 @@ impl_event! [
     oncaptureglobalmousemove
 ];
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum MouseButton {
     Left,
@@ -301,6 +324,7 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub click_count: u32,
 }
 
 impl MouseData {
@@ -308,11 +332,13 @@ impl MouseData {
         screen_coordinates: CursorPoint,
         element_coordinates: CursorPoint,
         trigger_button: Option<MouseButton>,
+        click_count: u32,
     ) -> Self {
         Self {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            click_count,
         }
     }
 }
@@ -332,6 +358,12 @@ impl MouseData {
     pub fn get_trigger_button(&self) -> Option<MouseButton> {
         self.trigger_button
     }
+
+    /// Get how many consecutive clicks led to this event, e.g `2` for the second press of a double click.
+    /// Only press and release events have a click count, the rest have `0`.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
 }
 
 impl From<&ErasedEventData> for MouseData {
@@ -184,6 +184,7 @@ pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub pointer_type: PointerType,
+    pub click_count: u32,
 }
 
 impl PointerData {
@@ -191,11 +192,13 @@ impl PointerData {
         screen_coordinates: CursorPoint,
         element_coordinates: CursorPoint,
         point_type: PointerType,
+        click_count: u32,
     ) -> Self {
         Self {
             screen_coordinates,
             element_coordinates,
             pointer_type: point_type,
+            click_count,
         }
     }
 }
@@ -215,6 +218,12 @@ impl PointerData {
     pub fn get_pointer_type(&self) -> PointerType {
         self.pointer_type
     }
+
+    /// Get how many consecutive clicks or taps led to this event, e.g `2` for the second press of a double click.
+    /// Only press and release events have a click count, the rest have `0`.
+    pub fn get_click_count(&self) -> u32 {
+        self.click_count
+    }
 }
 
 impl From<&ErasedEventData> for PointerData {
@@ -0,0 +1,63 @@
+use torin::geometry::Size2D;
+
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    ScrollData;
+
+    /// The `scroll` event fires after measuring the layout when the `offset_x` or `offset_y` of the element changes,
+    /// such as the content of a `ScrollView` when it is scrolled.
+    ///
+    /// Event Data: [`ScrollData`](crate::events::ScrollData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         ScrollView {
+    ///             onscroll: |e: ScrollEvent| println!("Scrolled to {}", e.scroll_y),
+    ///             rect {
+    ///                 height: "1000",
+    ///                 width: "fill",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onscroll
+];
+
+/// Data of a Scroll event.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScrollData {
+    /// How far the content is scrolled horizontally, the opposite of the `offset_x` of the element.
+    pub scroll_x: f32,
+    /// How far the content is scrolled vertically, the opposite of the `offset_y` of the element.
+    pub scroll_y: f32,
+    /// Size of the scrolled content, including the padding of the element.
+    pub content_size: Size2D,
+    /// Size of the visible area of the element.
+    pub viewport_size: Size2D,
+}
+
+impl ScrollData {
+    pub fn new(scroll_x: f32, scroll_y: f32, content_size: Size2D, viewport_size: Size2D) -> Self {
+        Self {
+            scroll_x,
+            scroll_y,
+            content_size,
+            viewport_size,
+        }
+    }
+}
+
+impl From<&ErasedEventData> for ScrollData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<ScrollData>().cloned().unwrap()
+    }
+}
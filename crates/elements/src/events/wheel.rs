@@ -1,5 +1,8 @@
 use crate::{
-    events::ErasedEventData,
+    events::{
+        touch::TouchPhase,
+        ErasedEventData,
+    },
     impl_event,
 };
 
@@ -40,14 +43,16 @@ pub struct WheelData {
     source: WheelSource,
     delta_x: f64,
     delta_y: f64,
+    phase: TouchPhase,
 }
 
 impl WheelData {
-    pub fn new(source: WheelSource, delta_x: f64, delta_y: f64) -> Self {
+    pub fn new(source: WheelSource, delta_x: f64, delta_y: f64, phase: TouchPhase) -> Self {
         Self {
             source,
             delta_x,
             delta_y,
+            phase,
         }
     }
 }
@@ -67,6 +72,12 @@ impl WheelData {
     pub fn get_delta_y(&self) -> f64 {
         self.delta_y
     }
+
+    /// Get the phase of the gesture that caused this event.
+    /// Touchpads report when the gesture starts and ends, mouse wheels are always [`TouchPhase::Moved`].
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
 }
 
 impl From<&ErasedEventData> for WheelData {
@@ -217,6 +217,57 @@ impl Matrix {
     pub fn rotate_deg_pivot(_degrees: f32, _pivot: impl Into<SkPoint>) -> Self {
         unimplemented!("This is mocked")
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_all(
+        _scale_x: f32,
+        _skew_x: f32,
+        _trans_x: f32,
+        _skew_y: f32,
+        _scale_y: f32,
+        _trans_y: f32,
+        _pers_0: f32,
+        _pers_1: f32,
+        _pers_2: f32,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn translate(_d: impl Into<SkPoint>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn scale(_scale: (f32, f32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn rotate_deg(_degrees: f32) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn skew(_skew: (f32, f32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_concat(&mut self, _other: &Self) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_translate(&mut self, _d: impl Into<SkPoint>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn invert(&self) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_point(&self, _point: impl Into<SkPoint>) -> SkPoint {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_rect(&self, _rect: impl AsRef<Rect>) -> (Rect, bool) {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -692,6 +743,59 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_path_effect(&mut self, _path_effect: impl Into<Option<PathEffect>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_color_filter(&mut self, _color_filter: impl Into<Option<ColorFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_image_filter(&mut self, _image_filter: impl Into<Option<ImageFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct ColorFilter;
+
+pub mod luma_color_filter {
+    use super::ColorFilter;
+
+    pub fn new() -> ColorFilter {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub mod color_filters {
+    use super::ColorFilter;
+
+    pub enum Clamp {
+        No = 0,
+        Yes = 1,
+    }
+
+    pub fn matrix_row_major(_array: &[f32; 20], _clamp: impl Into<Option<Clamp>>) -> ColorFilter {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+pub struct PathEffect;
+
+impl PathEffect {
+    pub fn dash(_intervals: &[f32], _phase: f32) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -1056,6 +1160,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn reset_matrix(&self) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clip_rect(&self, _rect: Rect, _clip: ClipOp, _: bool) {
         unimplemented!("This is mocked")
     }
@@ -1170,6 +1278,58 @@ pub struct SamplingOptions;
 
 pub struct ImageFilter;
 
+pub mod image_filters {
+    use super::{
+        Color4f,
+        ColorFilter,
+        ImageFilter,
+        Rect,
+        SkColorSpace,
+        SkPoint,
+        TileMode,
+    };
+
+    pub struct CropRect;
+
+    impl CropRect {
+        pub const NO_CROP_RECT: CropRect = CropRect;
+    }
+
+    impl From<&Rect> for CropRect {
+        fn from(_rect: &Rect) -> Self {
+            unimplemented!("This is mocked")
+        }
+    }
+
+    pub fn blur(
+        _sigma: (f32, f32),
+        _tile_mode: impl Into<Option<TileMode>>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn color_filter(
+        _cf: impl Into<ColorFilter>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn drop_shadow(
+        _offset: impl Into<SkPoint>,
+        _sigma: (f32, f32),
+        _color: impl Into<Color4f>,
+        _color_space: impl Into<Option<SkColorSpace>>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub fn blur(
     (sigma_x, sigma_y): (f32, f32),
     tile_mode: impl Into<Option<()>>,
@@ -1543,6 +1703,30 @@ impl Path {
     pub fn set_fill_type(&mut self, _ft: PathFillType) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn add_oval(
+        &mut self,
+        _oval: impl AsRef<Rect>,
+        _dir_start: Option<(PathDirection, usize)>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn rect(_rect: impl AsRef<Rect>, _dir: impl Into<Option<PathDirection>>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn from_svg(_svg: impl AsRef<str>) -> Option<Path> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn with_transform(&self, _matrix: &Matrix) -> Path {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn contains(&self, _p: impl Into<SkPoint>) -> bool {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1794,6 +1978,10 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn image_snapshot_with_bounds(&mut self, _bounds: IRect) -> Option<Image> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn direct_context(&self) -> Option<DirectContext> {
         unimplemented!("This is mocked")
     }
@@ -1826,6 +2014,38 @@ impl Surface {
     pub fn new_surface_with_dimensions(&mut self, dim: impl Into<ISize>) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src_point: impl Into<IPoint>,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: impl Into<ISize>,
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<SkColorSpace>>,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
 }
 
 pub struct ISize;
@@ -1836,6 +2056,14 @@ impl From<(i32, i32)> for ISize {
     }
 }
 
+pub struct IPoint;
+
+impl From<(i32, i32)> for IPoint {
+    fn from(source: (i32, i32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct SkColorSpace;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
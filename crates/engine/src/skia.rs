@@ -1,5 +1,6 @@
 pub use skia_safe::{
     canvas::SaveLayerRec,
+    color_filters,
     font_style::{
         Slant,
         Weight,
@@ -24,8 +25,10 @@ pub use skia_safe::{
         set_resource_cache_single_allocation_byte_limit,
         set_resource_cache_total_bytes_limit,
     },
+    image_filters,
     image_filters::blur,
     images::raster_from_data,
+    luma_color_filter,
     path::ArcSize,
     resources::LocalResourceProvider,
     rrect::Corner,
@@ -60,6 +63,7 @@ pub use skia_safe::{
         TypefaceFontProvider,
     },
     wrapper::PointerWrapper,
+    AlphaType,
     Bitmap,
     BlendMode,
     BlurStyle,
@@ -67,6 +71,7 @@ pub use skia_safe::{
     ClipOp,
     Color as SkColor,
     Color4f,
+    ColorFilter,
     ColorSpace,
     ColorType,
     CubicResampler,
@@ -85,9 +90,11 @@ pub use skia_safe::{
     Matrix,
     MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathDirection,
+    PathEffect,
     PathFillType,
     Pixmap,
     Point as SkPoint,
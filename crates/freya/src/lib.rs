@@ -54,6 +54,7 @@
 //! - `custom-tokio-rt`: disables the default Tokio runtime created by Freya.
 //! - `performance-overlay`: enables the performance overlay plugin.
 //! - `disable-zoom-shortcuts`: disables the default zoom shortcuts.
+//! - `input-recorder`: enables the `InputRecorderPlugin`, to record input sessions that can be replayed in tests.
 
 /// Freya docs.
 #[cfg(doc)]
@@ -90,6 +91,11 @@ pub mod core {
     pub use freya_core::*;
 }
 
+/// Render components into images without a window.
+pub mod offscreen {
+    pub use freya_core::offscreen::*;
+}
+
 /// Elements, attributes and events definitions.
 pub use freya_elements::elements;
 /// Events data.
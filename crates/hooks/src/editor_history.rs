@@ -1,10 +1,15 @@
-use std::time::{
-    Duration,
-    Instant,
+use std::{
+    ops::Range,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use ropey::Rope;
 
+use crate::StyleRun;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum HistoryChange {
     InsertChar {
@@ -22,6 +27,38 @@ pub enum HistoryChange {
         len: usize,
         text: String,
     },
+    /// The styles of a range of text changed, only used by rich texts.
+    Format {
+        idx: usize,
+        len: usize,
+        before: Vec<StyleRun>,
+        after: Vec<StyleRun>,
+    },
+}
+
+/// A text where the changes of an [EditorHistory] can be undone and redone.
+pub trait HistoryBuffer {
+    /// Insert the text in the given UTF-16 position.
+    fn insert_text(&mut self, idx: usize, text: &str);
+
+    /// Remove the given UTF-16 range of text.
+    fn remove_text(&mut self, range: Range<usize>);
+
+    /// Replace the styles starting from the given UTF-16 position.
+    fn restyle(&mut self, _idx: usize, _styles: &[StyleRun]) {}
+}
+
+impl HistoryBuffer for Rope {
+    fn insert_text(&mut self, idx: usize, text: &str) {
+        let start = self.utf16_cu_to_char(idx);
+        self.insert(start, text);
+    }
+
+    fn remove_text(&mut self, range: Range<usize>) {
+        let start = self.utf16_cu_to_char(range.start);
+        let end = self.utf16_cu_to_char(range.end);
+        self.remove(start..end);
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -51,6 +88,11 @@ impl EditorHistory {
     }
 
     pub fn push_change(&mut self, change: HistoryChange) {
+        self.push_changes([change]);
+    }
+
+    /// Push multiple changes that must always be undone and redone together.
+    pub fn push_changes(&mut self, changes: impl IntoIterator<Item = HistoryChange>) {
         if self.can_redo() {
             self.transactions.drain(self.current_transaction..);
         }
@@ -60,7 +102,7 @@ impl EditorHistory {
             .get_mut(self.current_transaction.saturating_sub(1));
         if let Some(last_transaction) = last_transaction {
             if last_transaction.timestamp.elapsed() <= self.transaction_treshold_groping {
-                last_transaction.changes.push(change);
+                last_transaction.changes.extend(changes);
                 last_transaction.timestamp = Instant::now();
                 return;
             }
@@ -68,7 +110,7 @@ impl EditorHistory {
 
         self.transactions.push(HistoryTransaction {
             timestamp: Instant::now(),
-            changes: vec![change],
+            changes: changes.into_iter().collect(),
         });
 
         self.current_transaction = self.transactions.len();
@@ -91,7 +133,7 @@ impl EditorHistory {
         self.current_transaction < self.transactions.len()
     }
 
-    pub fn undo(&mut self, rope: &mut Rope) -> Option<usize> {
+    pub fn undo(&mut self, buffer: &mut impl HistoryBuffer) -> Option<usize> {
         if !self.can_undo() {
             return None;
         }
@@ -102,22 +144,23 @@ impl EditorHistory {
             for change in last_transaction.changes.iter().rev() {
                 idx_end.replace(match change {
                     HistoryChange::Remove { idx, text, len } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        rope.insert(start, text);
+                        buffer.insert_text(*idx, text);
                         *idx + len
                     }
                     HistoryChange::InsertChar { idx, len, .. } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        let end = rope.utf16_cu_to_char(*idx + len);
-                        rope.remove(start..end);
+                        buffer.remove_text(*idx..*idx + len);
                         *idx
                     }
                     HistoryChange::InsertText { idx, len, .. } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        let end = rope.utf16_cu_to_char(*idx + len);
-                        rope.remove(start..end);
+                        buffer.remove_text(*idx..*idx + len);
                         *idx
                     }
+                    HistoryChange::Format {
+                        idx, len, before, ..
+                    } => {
+                        buffer.restyle(*idx, before);
+                        *idx + len
+                    }
                 });
             }
 
@@ -129,7 +172,7 @@ impl EditorHistory {
         }
     }
 
-    pub fn redo(&mut self, rope: &mut Rope) -> Option<usize> {
+    pub fn redo(&mut self, buffer: &mut impl HistoryBuffer) -> Option<usize> {
         if !self.can_redo() {
             return None;
         }
@@ -140,19 +183,21 @@ impl EditorHistory {
             for change in &last_transaction.changes {
                 idx_end.replace(match change {
                     HistoryChange::Remove { idx, len, .. } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        let end = rope.utf16_cu_to_char(*idx + len);
-                        rope.remove(start..end);
+                        buffer.remove_text(*idx..*idx + len);
                         *idx
                     }
                     HistoryChange::InsertChar { idx, ch, len } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        rope.insert_char(start, *ch);
+                        buffer.insert_text(*idx, ch.encode_utf8(&mut [0; 4]));
                         *idx + len
                     }
                     HistoryChange::InsertText { idx, text, len } => {
-                        let start = rope.utf16_cu_to_char(*idx);
-                        rope.insert(start, text);
+                        buffer.insert_text(*idx, text);
+                        *idx + len
+                    }
+                    HistoryChange::Format {
+                        idx, len, after, ..
+                    } => {
+                        buffer.restyle(*idx, after);
                         *idx + len
                     }
                 });
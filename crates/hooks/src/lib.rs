@@ -4,8 +4,11 @@
 //! A collection of hooks to be used in Freya.
 
 mod editor_history;
+mod rich_clipboard;
+mod rich_rope_editor;
 mod rope_editor;
 mod shader_uniforms;
+mod syntax_highlighter;
 mod text_editor;
 mod theming;
 
@@ -22,14 +25,18 @@ mod use_platform;
 mod use_platform_information;
 mod use_popup;
 mod use_preferred_theme;
+mod use_syntax_highlighter;
 mod use_theme;
 
 #[cfg(feature = "use_camera")]
 mod use_camera;
 
 pub use editor_history::*;
+pub use rich_clipboard::*;
+pub use rich_rope_editor::*;
 pub use rope_editor::*;
 pub use shader_uniforms::*;
+pub use syntax_highlighter::*;
 pub use text_editor::*;
 pub use theming::*;
 pub use use_activable_route::*;
@@ -47,4 +54,5 @@ pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_popup::*;
 pub use use_preferred_theme::*;
+pub use use_syntax_highlighter::*;
 pub use use_theme::*;
@@ -0,0 +1,263 @@
+use dioxus_core::{
+    prelude::{
+        provide_root_context,
+        try_consume_context,
+    },
+    ScopeId,
+};
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+
+use crate::{
+    SpanStyle,
+    TextSpan,
+};
+
+/// HTML representation of the last styled text copied from a [RichRopeEditor](crate::RichRopeEditor).
+///
+/// The system clipboard only supports plain text, so the HTML is kept app-wide next to the plain text it was copied with,
+/// and it's only used when pasting if the system clipboard still holds that same plain text.
+#[derive(Clone, Copy, PartialEq)]
+pub struct RichClipboard {
+    contents: Signal<Option<(String, String)>>,
+}
+
+impl RichClipboard {
+    /// Get the current [RichClipboard].
+    pub fn current() -> Self {
+        match try_consume_context() {
+            Some(clipboard) => clipboard,
+            None => provide_root_context(RichClipboard {
+                contents: Signal::new_in_scope(None, ScopeId::ROOT),
+            }),
+        }
+    }
+
+    /// Store the given spans, copied as the given plain text.
+    pub fn set(&mut self, text: String, spans: &[TextSpan]) {
+        self.contents.set(Some((text, spans_to_html(spans))));
+    }
+
+    /// Get the stored spans if they were copied as the given plain text.
+    pub fn get(&self, text: &str) -> Option<Vec<TextSpan>> {
+        let contents = self.contents.peek();
+        let (copied_text, html) = contents.as_ref()?;
+        if copied_text != text {
+            return None;
+        }
+        let spans = spans_from_html(html);
+        let parsed_text = spans
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect::<String>();
+        (parsed_text == text).then_some(spans)
+    }
+}
+
+/// Serialize the spans of a rich text as an HTML fragment, see [spans_from_html].
+pub fn spans_to_html(spans: &[TextSpan]) -> String {
+    let mut html = String::new();
+    for span in spans.iter().filter(|span| !span.text.is_empty()) {
+        if let Some(link) = &span.style.link {
+            html.push_str(&format!("<a href=\"{}\">", escape_html(link)));
+        }
+        html.push_str(&format!(
+            "<span style=\"{}\">",
+            escape_html(&style_to_css(&span.style))
+        ));
+        html.push_str(&escape_html(&span.text).replace('\n', "<br>"));
+        html.push_str("</span>");
+        if span.style.link.is_some() {
+            html.push_str("</a>");
+        }
+    }
+    html
+}
+
+/// Parse an HTML fragment as the spans of a rich text.
+///
+/// Only the styles supported by [SpanStyle] are kept, these are `font-weight`, `font-style`, `color` and `font-size`
+/// in the `style` attribute of `span` elements and the `href` attribute of `a` elements.
+pub fn spans_from_html(html: &str) -> Vec<TextSpan> {
+    let mut spans: Vec<TextSpan> = Vec::new();
+    let mut styles = vec![SpanStyle::default()];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let text = if let Some(tag) = rest.strip_prefix('<') {
+            let Some(end) = tag.find('>') else {
+                break;
+            };
+            let (tag, after) = (&tag[..end], &tag[end + 1..]);
+            rest = after;
+
+            let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            let name = name.trim_end_matches('/').to_ascii_lowercase();
+            let style = styles.last().cloned().unwrap_or_default();
+            match name.as_str() {
+                "br" => "\n".to_string(),
+                "span" => {
+                    let css = attribute(attributes, "style").unwrap_or_default();
+                    styles.push(css_to_style(&css, style));
+                    continue;
+                }
+                "a" => {
+                    let link = attribute(attributes, "href");
+                    styles.push(SpanStyle { link, ..style });
+                    continue;
+                }
+                "/span" | "/a" => {
+                    if styles.len() > 1 {
+                        styles.pop();
+                    }
+                    continue;
+                }
+                _ => continue,
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = unescape_html(&rest[..end]);
+            rest = &rest[end..];
+            text
+        };
+
+        let style = styles.last().cloned().unwrap_or_default();
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => spans.push(TextSpan::new(text, style)),
+        }
+    }
+
+    spans
+}
+
+fn style_to_css(style: &SpanStyle) -> String {
+    let mut css = Vec::new();
+    if style.bold {
+        css.push("font-weight: bold".to_string());
+    }
+    if style.italic {
+        css.push("font-style: italic".to_string());
+    }
+    if let Some(color) = &style.color {
+        css.push(format!("color: {color}"));
+    }
+    if let Some(font_size) = style.font_size {
+        css.push(format!("font-size: {font_size}px"));
+    }
+    css.join("; ")
+}
+
+fn css_to_style(css: &str, mut style: SpanStyle) -> SpanStyle {
+    for declaration in css.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match property.trim() {
+            "font-weight" => {
+                style.bold = matches!(value, "bold" | "bolder" | "700" | "800" | "900")
+            }
+            "font-style" => style.italic = matches!(value, "italic" | "oblique"),
+            "color" => style.color = Some(value.to_string()),
+            "font-size" => {
+                style.font_size = value
+                    .trim_end_matches("px")
+                    .parse()
+                    .ok()
+                    .or(style.font_size)
+            }
+            _ => {}
+        }
+    }
+    style
+}
+
+/// Get the unescaped value of a quoted attribute.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes;
+    while let Some(equals) = rest.find('=') {
+        let key = rest[..equals].trim();
+        let value = rest[equals + 1..].trim_start();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let end = value[1..].find(quote)? + 1;
+        if key.eq_ignore_ascii_case(name) {
+            return Some(unescape_html(&value[1..end]));
+        }
+        rest = &value[end + 1..];
+    }
+    None
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        spans_from_html,
+        spans_to_html,
+    };
+    use crate::{
+        SpanStyle,
+        TextSpan,
+    };
+
+    #[test]
+    fn html_round_trip() {
+        let spans = vec![
+            TextSpan::new("Hello <", SpanStyle::default()),
+            TextSpan::new(
+                "Bold & italic\n",
+                SpanStyle::default().with_bold(true).with_italic(true),
+            ),
+            TextSpan::new(
+                "\"Red\"",
+                SpanStyle::default()
+                    .with_color("rgb(255, 0, 0)")
+                    .with_font_size(24.),
+            ),
+            TextSpan::new(
+                "Link",
+                SpanStyle::default().with_link("https://freyaui.dev/?a=1&b=2"),
+            ),
+        ];
+
+        let html = spans_to_html(&spans);
+        assert_eq!(spans_from_html(&html), spans);
+    }
+
+    #[test]
+    fn html_from_other_apps() {
+        let spans = spans_from_html(
+            "<meta charset='utf-8'><b>Ignored tag</b> <span style=\"font-weight:700\">Bold<br/></span>",
+        );
+        assert_eq!(
+            spans,
+            vec![
+                TextSpan::new("Ignored tag ", SpanStyle::default()),
+                TextSpan::new("Bold\n", SpanStyle::default().with_bold(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,876 @@
+use std::{
+    fmt::Display,
+    ops::Range,
+};
+
+use dioxus_clipboard::prelude::UseClipboard;
+use freya_elements::events::keyboard::{
+    Code,
+    Key,
+    Modifiers,
+};
+use ropey::Rope;
+
+use crate::{
+    text_editor::*,
+    EditableText,
+    EditorHistory,
+    HistoryBuffer,
+    HistoryChange,
+    LinesIterator,
+    RichClipboard,
+    RopeEditor,
+};
+
+/// Style of a part of the text in a [RichRopeEditor].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<String>,
+    pub font_size: Option<f32>,
+    pub link: Option<String>,
+}
+
+impl SpanStyle {
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    pub fn with_link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+}
+
+/// A number of consecutive UTF-16 code units sharing the same [SpanStyle].
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyleRun {
+    pub len: usize,
+    pub style: SpanStyle,
+}
+
+impl StyleRun {
+    pub fn new(len: usize, style: SpanStyle) -> Self {
+        Self { len, style }
+    }
+}
+
+/// Styles of a text, stored as a list of [StyleRun]s that together cover the whole text.
+///
+/// Consecutive runs always have different styles and runs are never empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleRuns(Vec<StyleRun>);
+
+impl StyleRuns {
+    /// Create the styles of a text of the given length with the default style.
+    pub fn new(len: usize) -> Self {
+        Self::from_runs(vec![StyleRun::new(len, SpanStyle::default())])
+    }
+
+    pub fn from_runs(runs: Vec<StyleRun>) -> Self {
+        let mut runs = Self(runs);
+        runs.normalize();
+        runs
+    }
+
+    pub fn runs(&self) -> &[StyleRun] {
+        &self.0
+    }
+
+    /// Total length of the styled text in UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|run| run.len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Style that a text inserted in the given position would inherit,
+    /// which is the style of the character right before it.
+    pub fn style_at(&self, idx: usize) -> SpanStyle {
+        let mut start = 0;
+        for run in &self.0 {
+            start += run.len;
+            if idx <= start {
+                return run.style.clone();
+            }
+        }
+        self.0
+            .last()
+            .map(|run| run.style.clone())
+            .unwrap_or_default()
+    }
+
+    /// Check whether all the text in the given range satisfies the predicate.
+    pub fn all(&self, range: Range<usize>, predicate: impl Fn(&SpanStyle) -> bool) -> bool {
+        self.slice(range).iter().all(|run| predicate(&run.style))
+    }
+
+    /// Get the runs of the given range.
+    pub fn slice(&self, range: Range<usize>) -> Vec<StyleRun> {
+        let mut runs = self.clone();
+        let start = runs.split_at(range.start);
+        let end = runs.split_at(range.end);
+        runs.0.drain(start..end).collect()
+    }
+
+    /// Insert a text of the given length and style in the given position.
+    pub fn insert(&mut self, idx: usize, len: usize, style: SpanStyle) {
+        self.insert_runs(idx, &[StyleRun::new(len, style)]);
+    }
+
+    /// Insert an already styled text in the given position.
+    pub fn insert_runs(&mut self, idx: usize, runs: &[StyleRun]) {
+        let i = self.split_at(idx);
+        self.0.splice(i..i, runs.iter().cloned());
+        self.normalize();
+    }
+
+    /// Remove the styles of the given range of text.
+    pub fn remove(&mut self, range: Range<usize>) {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        self.0.drain(start..end);
+        self.normalize();
+    }
+
+    /// Replace the styles starting from the given position, the text length doesn't change.
+    pub fn restyle(&mut self, idx: usize, runs: &[StyleRun]) {
+        let len = runs.iter().map(|run| run.len).sum::<usize>();
+        self.remove(idx..idx + len);
+        self.insert_runs(idx, runs);
+    }
+
+    /// Modify the styles of the given range of text.
+    pub fn apply(&mut self, range: Range<usize>, format: impl Fn(&mut SpanStyle)) {
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for run in &mut self.0[start..end] {
+            format(&mut run.style);
+        }
+        self.normalize();
+    }
+
+    /// Make sure a run starts in the given position and return its index.
+    fn split_at(&mut self, idx: usize) -> usize {
+        let mut start = 0;
+        for i in 0..self.0.len() {
+            let end = start + self.0[i].len;
+            if idx == start {
+                return i;
+            }
+            if idx < end {
+                let mut second = self.0[i].clone();
+                second.len = end - idx;
+                self.0[i].len = idx - start;
+                self.0.insert(i + 1, second);
+                return i + 1;
+            }
+            start = end;
+        }
+        self.0.len()
+    }
+
+    /// Remove the empty runs and merge the consecutive runs with the same style.
+    fn normalize(&mut self) {
+        let mut runs = Vec::<StyleRun>::with_capacity(self.0.len());
+        for run in self.0.drain(..).filter(|run| run.len > 0) {
+            match runs.last_mut() {
+                Some(last) if last.style == run.style => last.len += run.len,
+                _ => runs.push(run),
+            }
+        }
+        self.0 = runs;
+    }
+}
+
+/// A part of a text with its own style.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub style: SpanStyle,
+}
+
+impl TextSpan {
+    pub fn new(text: impl Into<String>, style: SpanStyle) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+/// The rope and styles of a [RichRopeEditor] as seen by its [EditorHistory].
+struct RichTextBuffer<'a> {
+    rope: &'a mut Rope,
+    styles: &'a mut StyleRuns,
+}
+
+impl HistoryBuffer for RichTextBuffer<'_> {
+    fn insert_text(&mut self, idx: usize, text: &str) {
+        self.rope.insert_text(idx, text);
+        let style = self.styles.style_at(idx);
+        self.styles.insert(idx, text.encode_utf16().count(), style);
+    }
+
+    fn remove_text(&mut self, range: Range<usize>) {
+        self.rope.remove_text(range.clone());
+        self.styles.remove(range);
+    }
+
+    fn restyle(&mut self, idx: usize, styles: &[StyleRun]) {
+        self.styles.restyle(idx, styles);
+    }
+}
+
+/// TextEditor implementing a Rope with styled parts of text, see [TextSpan].
+pub struct RichRopeEditor {
+    pub(crate) editor: RopeEditor,
+    pub(crate) styles: StyleRuns,
+    /// Style for the next inserted text in the given position, set when formatting without a selection.
+    pub(crate) typing_style: Option<(usize, SpanStyle)>,
+}
+
+impl Display for RichRopeEditor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.editor, f)
+    }
+}
+
+impl RichRopeEditor {
+    /// Create a new [`RichRopeEditor`], the styles must cover the whole text or they will be ignored.
+    pub fn new(editor: RopeEditor, styles: StyleRuns) -> Self {
+        let len = editor.len_utf16_cu();
+        let styles = if styles.len() == len {
+            styles
+        } else {
+            StyleRuns::new(len)
+        };
+        Self {
+            editor,
+            styles,
+            typing_style: None,
+        }
+    }
+
+    pub fn rope(&self) -> &Rope {
+        self.editor.rope()
+    }
+
+    pub fn styles(&self) -> &StyleRuns {
+        &self.styles
+    }
+
+    /// Text being composed with an IME, if any.
+    pub fn composition(&self) -> Option<&TextComposition> {
+        self.editor.composition()
+    }
+
+    /// Position of the cursor including the text being composed.
+    pub fn visible_cursor_pos(&self) -> usize {
+        self.editor.visible_cursor_pos()
+    }
+
+    /// Get the text split in parts of the same style.
+    pub fn spans(&self) -> Vec<TextSpan> {
+        let mut start = 0;
+        self.styles
+            .runs()
+            .iter()
+            .map(|run| {
+                let end = start + run.len;
+                let text = self
+                    .editor
+                    .rope
+                    .slice(self.utf16_cu_to_char(start)..self.utf16_cu_to_char(end))
+                    .to_string();
+                start = end;
+                TextSpan::new(text, run.style.clone())
+            })
+            .collect()
+    }
+
+    /// Replace the whole text with the given spans.
+    pub fn set_spans(&mut self, spans: &[TextSpan]) {
+        let text = spans
+            .iter()
+            .map(|span| span.text.as_str())
+            .collect::<String>();
+        self.editor.set(&text);
+        self.styles = spans_to_styles(spans);
+        self.typing_style = None;
+    }
+
+    /// Style of the selected text, or the style of the text that would be typed if there is no selection.
+    pub fn current_style(&self) -> SpanStyle {
+        match self.non_empty_selection() {
+            Some((start, _)) => self.styles.slice(start..start + 1)[0].style.clone(),
+            None => self.typing_style(),
+        }
+    }
+
+    /// Modify the style of the selected text or of the text that will be typed if there is no selection.
+    pub fn format_selection(&mut self, format: impl Fn(&mut SpanStyle)) {
+        if let Some((start, end)) = self.non_empty_selection() {
+            let before = self.styles.slice(start..end);
+            self.styles.apply(start..end, format);
+            let after = self.styles.slice(start..end);
+            if before != after {
+                self.editor.history.push_change(HistoryChange::Format {
+                    idx: start,
+                    len: end - start,
+                    before,
+                    after,
+                });
+            }
+        } else {
+            let mut style = self.typing_style();
+            format(&mut style);
+            self.typing_style = Some((self.cursor_pos(), style));
+        }
+    }
+
+    /// Make the selected text bold, or regular if it's all bold already.
+    pub fn toggle_bold(&mut self) {
+        let bold = !self.selection_all(|style| style.bold);
+        self.format_selection(|style| style.bold = bold);
+    }
+
+    /// Make the selected text italic, or regular if it's all italic already.
+    pub fn toggle_italic(&mut self) {
+        let italic = !self.selection_all(|style| style.italic);
+        self.format_selection(|style| style.italic = italic);
+    }
+
+    /// Set or remove (with `None`) the color of the selected text.
+    pub fn set_color(&mut self, color: Option<String>) {
+        self.format_selection(|style| style.color.clone_from(&color));
+    }
+
+    /// Set or remove (with `None`) the font size of the selected text.
+    pub fn set_font_size(&mut self, font_size: Option<f32>) {
+        self.format_selection(|style| style.font_size = font_size);
+    }
+
+    /// Set or remove (with `None`) the link of the selected text.
+    pub fn set_link(&mut self, link: Option<String>) {
+        self.format_selection(|style| style.link.clone_from(&link));
+    }
+
+    /// Insert an already styled text in the given position.
+    pub fn insert_runs(&mut self, text: &str, runs: &[StyleRun], idx: usize) -> usize {
+        let inserted_text_len = self.insert_rope(text, idx);
+        if runs.iter().map(|run| run.len).sum::<usize>() == inserted_text_len {
+            self.styles.insert_runs(idx, runs);
+        } else {
+            let style = self.typing_style_at(idx);
+            self.styles.insert(idx, inserted_text_len, style);
+        }
+        self.push_insert(text, idx, inserted_text_len);
+        inserted_text_len
+    }
+
+    /// Copy the selected text, keeping its styles when pasted into a [RichRopeEditor].
+    pub fn copy_selection(&mut self) {
+        let Some((start, end)) = self.get_selection_range() else {
+            return;
+        };
+        let text = self.get_selected_text().unwrap_or_default();
+        let mut run_start = start;
+        let spans = self
+            .styles
+            .slice(start..end)
+            .into_iter()
+            .map(|run| {
+                let run_end = run_start + run.len;
+                let text = self
+                    .editor
+                    .rope
+                    .slice(self.utf16_cu_to_char(run_start)..self.utf16_cu_to_char(run_end))
+                    .to_string();
+                run_start = run_end;
+                TextSpan::new(text, run.style)
+            })
+            .collect::<Vec<_>>();
+        RichClipboard::current().set(text.clone(), &spans);
+        self.get_clipboard().set(text).ok();
+    }
+
+    /// Paste the copied text in the cursor position, replacing the selected text if any.
+    ///
+    /// Its styles are restored if it was copied from a [RichRopeEditor], otherwise it inherits the style of the surrounding text.
+    pub fn paste(&mut self) -> bool {
+        let Ok(text) = self.get_clipboard().get() else {
+            return false;
+        };
+        let runs = RichClipboard::current()
+            .get(&text)
+            .map(|spans| spans_to_styles(&spans).runs().to_vec())
+            .unwrap_or_default();
+        if let Some((start, end)) = self.get_selection_range() {
+            self.remove(start..end);
+            self.set_cursor_pos(start);
+        }
+        let cursor_pos = self.cursor_pos();
+        let inserted_text_len = self.insert_runs(&text, &runs, cursor_pos);
+        self.set_cursor_pos(cursor_pos + inserted_text_len);
+        true
+    }
+
+    fn non_empty_selection(&self) -> Option<(usize, usize)> {
+        self.get_selection_range()
+            .filter(|(start, end)| start != end)
+    }
+
+    fn selection_all(&self, predicate: impl Fn(&SpanStyle) -> bool) -> bool {
+        match self.non_empty_selection() {
+            Some((start, end)) => self.styles.all(start..end, predicate),
+            None => predicate(&self.typing_style()),
+        }
+    }
+
+    fn typing_style(&self) -> SpanStyle {
+        self.typing_style_at(self.cursor_pos())
+    }
+
+    fn typing_style_at(&self, idx: usize) -> SpanStyle {
+        match &self.typing_style {
+            Some((pos, style)) if *pos == idx => style.clone(),
+            _ => self.styles.style_at(idx),
+        }
+    }
+
+    fn insert_rope(&mut self, text: &str, idx: usize) -> usize {
+        let len_before_insert = self.editor.rope.len_utf16_cu();
+        self.editor.rope.insert_text(idx, text);
+        len_before_insert.abs_diff(self.editor.rope.len_utf16_cu())
+    }
+
+    fn push_insert(&mut self, text: &str, idx: usize, len: usize) {
+        let runs = self.styles.slice(idx..idx + len);
+        self.editor.history.push_changes([
+            HistoryChange::InsertText {
+                idx,
+                len,
+                text: text.to_owned(),
+            },
+            HistoryChange::Format {
+                idx,
+                len,
+                before: runs.clone(),
+                after: runs,
+            },
+        ]);
+        self.typing_style = None;
+    }
+
+    fn history_buffer(&mut self) -> (&mut EditorHistory, RichTextBuffer<'_>) {
+        (
+            &mut self.editor.history,
+            RichTextBuffer {
+                rope: &mut self.editor.rope,
+                styles: &mut self.styles,
+            },
+        )
+    }
+}
+
+/// Create the styles of the text formed by the given spans.
+pub(crate) fn spans_to_styles(spans: &[TextSpan]) -> StyleRuns {
+    StyleRuns::from_runs(
+        spans
+            .iter()
+            .map(|span| StyleRun::new(span.text.encode_utf16().count(), span.style.clone()))
+            .collect(),
+    )
+}
+
+impl TextEditor for RichRopeEditor {
+    type LinesIterator<'a> = LinesIterator<'a>;
+
+    fn lines(&self) -> Self::LinesIterator<'_> {
+        self.editor.lines()
+    }
+
+    fn insert_char(&mut self, ch: char, idx: usize) -> usize {
+        self.insert(ch.encode_utf8(&mut [0; 4]), idx)
+    }
+
+    fn insert(&mut self, text: &str, idx: usize) -> usize {
+        let style = self.typing_style_at(idx);
+        let inserted_text_len = self.insert_rope(text, idx);
+        self.styles.insert(idx, inserted_text_len, style);
+        self.push_insert(text, idx, inserted_text_len);
+        inserted_text_len
+    }
+
+    fn remove(&mut self, range_utf16: Range<usize>) -> usize {
+        let range =
+            self.utf16_cu_to_char(range_utf16.start)..self.utf16_cu_to_char(range_utf16.end);
+        let text = self.editor.rope.slice(range.clone()).to_string();
+
+        let len_before_remove = self.editor.rope.len_utf16_cu();
+        self.editor.rope.remove(range);
+        let len_after_remove = self.editor.rope.len_utf16_cu();
+
+        let removed_text_len = len_before_remove - len_after_remove;
+        let idx = range_utf16.end - removed_text_len;
+
+        let runs = self.styles.slice(idx..idx + removed_text_len);
+        self.styles.remove(idx..idx + removed_text_len);
+
+        self.editor.history.push_changes([
+            HistoryChange::Format {
+                idx,
+                len: removed_text_len,
+                before: runs.clone(),
+                after: runs,
+            },
+            HistoryChange::Remove {
+                idx,
+                text,
+                len: removed_text_len,
+            },
+        ]);
+
+        removed_text_len
+    }
+
+    fn char_to_line(&self, char_idx: usize) -> usize {
+        self.editor.char_to_line(char_idx)
+    }
+
+    fn line_to_char(&self, line_idx: usize) -> usize {
+        self.editor.line_to_char(line_idx)
+    }
+
+    fn utf16_cu_to_char(&self, utf16_cu_idx: usize) -> usize {
+        self.editor.utf16_cu_to_char(utf16_cu_idx)
+    }
+
+    fn char_to_utf16_cu(&self, idx: usize) -> usize {
+        self.editor.char_to_utf16_cu(idx)
+    }
+
+    fn line(&self, line_idx: usize) -> Option<Line<'_>> {
+        self.editor.line(line_idx)
+    }
+
+    fn len_lines(&self) -> usize {
+        self.editor.len_lines()
+    }
+
+    fn len_chars(&self) -> usize {
+        self.editor.len_chars()
+    }
+
+    fn len_utf16_cu(&self) -> usize {
+        self.editor.len_utf16_cu()
+    }
+
+    fn cursor(&self) -> &TextCursor {
+        self.editor.cursor()
+    }
+
+    fn cursor_mut(&mut self) -> &mut TextCursor {
+        self.editor.cursor_mut()
+    }
+
+    fn expand_selection_to_cursor(&mut self) {
+        self.editor.expand_selection_to_cursor()
+    }
+
+    fn get_clipboard(&mut self) -> &mut UseClipboard {
+        self.editor.get_clipboard()
+    }
+
+    fn has_any_selection(&self) -> bool {
+        self.editor.has_any_selection()
+    }
+
+    fn get_selection(&self) -> Option<(usize, usize)> {
+        self.editor.get_selection()
+    }
+
+    fn get_visible_selection(&self, editor_id: usize) -> Option<(usize, usize)> {
+        self.editor.get_visible_selection(editor_id)
+    }
+
+    fn set(&mut self, text: &str) {
+        self.editor.set(text);
+        self.styles = StyleRuns::new(self.editor.len_utf16_cu());
+        self.typing_style = None;
+    }
+
+    fn clear_selection(&mut self) {
+        self.editor.clear_selection()
+    }
+
+    fn measure_new_selection(&self, from: usize, to: usize, editor_id: usize) -> (usize, usize) {
+        self.editor.measure_new_selection(from, to, editor_id)
+    }
+
+    fn measure_new_cursor(&self, to: usize, editor_id: usize) -> TextCursor {
+        self.editor.measure_new_cursor(to, editor_id)
+    }
+
+    fn set_selection(&mut self, selected: (usize, usize)) {
+        self.editor.set_selection(selected)
+    }
+
+    fn get_selected_text(&self) -> Option<String> {
+        self.editor.get_selected_text()
+    }
+
+    fn get_selection_range(&self) -> Option<(usize, usize)> {
+        self.editor.get_selection_range()
+    }
+
+    fn undo(&mut self) -> Option<usize> {
+        let (history, mut buffer) = self.history_buffer();
+        history.undo(&mut buffer)
+    }
+
+    fn redo(&mut self) -> Option<usize> {
+        let (history, mut buffer) = self.history_buffer();
+        history.redo(&mut buffer)
+    }
+
+    fn editor_history(&mut self) -> &mut EditorHistory {
+        self.editor.editor_history()
+    }
+
+    fn get_identation(&self) -> u8 {
+        self.editor.get_identation()
+    }
+}
+
+impl EditableText for RichRopeEditor {
+    fn composition(&self) -> Option<&TextComposition> {
+        self.editor.composition()
+    }
+
+    fn is_composing(&self) -> bool {
+        self.editor.is_composing()
+    }
+
+    fn set_composition(&mut self, composition: Option<TextComposition>) {
+        self.editor.set_composition(composition)
+    }
+
+    fn process_key_event(
+        &mut self,
+        key: &Key,
+        code: &Code,
+        modifiers: &Modifiers,
+        allow_tabs: bool,
+        allow_changes: bool,
+        allow_clipboard: bool,
+    ) -> TextEvent {
+        let meta_or_ctrl = if cfg!(target_os = "macos") {
+            modifiers.meta()
+        } else {
+            modifiers.ctrl()
+        };
+
+        if !matches!(key, Key::Character(_)) || !meta_or_ctrl {
+            return self.process_key(
+                key,
+                code,
+                modifiers,
+                allow_tabs,
+                allow_changes,
+                allow_clipboard,
+            );
+        }
+
+        match code {
+            // Toggle bold
+            Code::KeyB if allow_changes => {
+                self.toggle_bold();
+                TextEvent::STYLE_CHANGED
+            }
+
+            // Toggle italic
+            Code::KeyI if allow_changes => {
+                self.toggle_italic();
+                TextEvent::STYLE_CHANGED
+            }
+
+            // Copy selected text
+            Code::KeyC if allow_clipboard => {
+                self.copy_selection();
+                TextEvent::empty()
+            }
+
+            // Cut selected text
+            Code::KeyX if allow_changes && allow_clipboard => {
+                let Some((start, end)) = self.get_selection_range() else {
+                    return TextEvent::empty();
+                };
+                self.copy_selection();
+                self.remove(start..end);
+                self.set_cursor_pos(start);
+                self.clear_selection();
+                TextEvent::TEXT_CHANGED | TextEvent::SELECTION_CHANGED
+            }
+
+            // Paste copied text
+            Code::KeyV if allow_changes && allow_clipboard => {
+                let mut event = if self.has_any_selection() {
+                    TextEvent::SELECTION_CHANGED
+                } else {
+                    TextEvent::empty()
+                };
+                if self.paste() {
+                    event.insert(TextEvent::TEXT_CHANGED);
+                }
+                self.clear_selection();
+                event
+            }
+
+            _ => self.process_key(
+                key,
+                code,
+                modifiers,
+                allow_tabs,
+                allow_changes,
+                allow_clipboard,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use ropey::Rope;
+
+    use super::{
+        RichTextBuffer,
+        SpanStyle,
+        StyleRun,
+        StyleRuns,
+    };
+    use crate::{
+        EditorHistory,
+        HistoryBuffer,
+        HistoryChange,
+    };
+
+    #[test]
+    fn style_runs() {
+        let bold = SpanStyle::default().with_bold(true);
+        let mut styles = StyleRuns::new(11);
+
+        // Make "World" bold
+        styles.apply(6..11, |style| style.bold = true);
+        assert_eq!(
+            styles.runs(),
+            &[
+                StyleRun::new(6, SpanStyle::default()),
+                StyleRun::new(5, bold.clone())
+            ]
+        );
+
+        // Text inserted at the end inherits the bold style
+        styles.insert(11, 1, styles.style_at(11));
+        assert_eq!(styles.runs()[1], StyleRun::new(6, bold.clone()));
+
+        // Removing the regular text leaves only bold text
+        styles.remove(0..6);
+        assert_eq!(styles.runs(), &[StyleRun::new(6, bold.clone())]);
+
+        // Restyling back merges the runs
+        styles.restyle(0, &[StyleRun::new(6, SpanStyle::default())]);
+        assert_eq!(styles.runs(), &[StyleRun::new(6, SpanStyle::default())]);
+    }
+
+    #[test]
+    fn rich_history() {
+        let mut rope = Rope::from_str("Hello");
+        let mut styles = StyleRuns::new(5);
+        let mut history = EditorHistory::new(Duration::ZERO);
+        let italic = SpanStyle::default().with_italic(true);
+
+        // Insert some italic text
+        let mut buffer = RichTextBuffer {
+            rope: &mut rope,
+            styles: &mut styles,
+        };
+        buffer.insert_text(5, " World");
+        buffer.restyle(5, &[StyleRun::new(6, italic.clone())]);
+        history.push_changes([
+            HistoryChange::InsertText {
+                idx: 5,
+                len: 6,
+                text: " World".to_string(),
+            },
+            HistoryChange::Format {
+                idx: 5,
+                len: 6,
+                before: vec![StyleRun::new(6, italic.clone())],
+                after: vec![StyleRun::new(6, italic.clone())],
+            },
+        ]);
+
+        // Remove part of it
+        let removed = buffer.styles.slice(5..8);
+        buffer.remove_text(5..8);
+        history.push_changes([
+            HistoryChange::Format {
+                idx: 5,
+                len: 3,
+                before: removed.clone(),
+                after: removed,
+            },
+            HistoryChange::Remove {
+                idx: 5,
+                len: 3,
+                text: " Wo".to_string(),
+            },
+        ]);
+        assert_eq!(buffer.rope.to_string(), "Hellorld");
+
+        // The removed text gets back its italic style
+        assert_eq!(history.undo(&mut buffer), Some(8));
+        assert_eq!(buffer.rope.to_string(), "Hello World");
+        assert_eq!(
+            buffer.styles.runs(),
+            &[
+                StyleRun::new(5, SpanStyle::default()),
+                StyleRun::new(6, italic.clone())
+            ]
+        );
+
+        assert_eq!(history.undo(&mut buffer), Some(5));
+        assert_eq!(buffer.rope.to_string(), "Hello");
+        assert_eq!(
+            buffer.styles.runs(),
+            &[StyleRun::new(5, SpanStyle::default())]
+        );
+
+        // Redoing the insertion restores the italic style
+        assert_eq!(history.redo(&mut buffer), Some(11));
+        assert_eq!(buffer.styles.runs()[1], StyleRun::new(6, italic));
+    }
+}
@@ -11,6 +11,7 @@ pub use ropey::Rope;
 use crate::{
     text_editor::*,
     EditableMode,
+    EditableText,
     EditorHistory,
     HistoryChange,
 };
@@ -24,6 +25,7 @@ pub struct RopeEditor {
     pub(crate) selected: Option<(usize, usize)>,
     pub(crate) clipboard: UseClipboard,
     pub(crate) history: EditorHistory,
+    pub(crate) composition: Option<TextComposition>,
 }
 
 impl Display for RopeEditor {
@@ -50,12 +52,45 @@ impl RopeEditor {
             mode,
             clipboard,
             history,
+            composition: None,
         }
     }
 
     pub fn rope(&self) -> &Rope {
         &self.rope
     }
+
+    /// Text being composed with an IME, if any.
+    pub fn composition(&self) -> Option<&TextComposition> {
+        self.composition.as_ref()
+    }
+
+    /// Check if there is some text being composed with an IME.
+    pub fn is_composing(&self) -> bool {
+        self.composition.is_some()
+    }
+
+    /// Start, update or finish (with `None`) the composition of text.
+    ///
+    /// The composed text is not added to the text or the history,
+    /// it will be received as a regular text input once committed.
+    pub fn set_composition(&mut self, composition: Option<TextComposition>) {
+        self.composition = composition;
+    }
+
+    /// Position of the cursor including the text being composed.
+    pub fn visible_cursor_pos(&self) -> usize {
+        let cursor_pos = self.cursor_pos();
+        match &self.composition {
+            Some(composition) => {
+                cursor_pos
+                    + composition
+                        .cursor
+                        .unwrap_or_else(|| composition.utf16_len())
+            }
+            None => cursor_pos,
+        }
+    }
 }
 
 impl TextEditor for RopeEditor {
@@ -331,6 +366,20 @@ impl TextEditor for RopeEditor {
     }
 }
 
+impl EditableText for RopeEditor {
+    fn composition(&self) -> Option<&TextComposition> {
+        RopeEditor::composition(self)
+    }
+
+    fn is_composing(&self) -> bool {
+        RopeEditor::is_composing(self)
+    }
+
+    fn set_composition(&mut self, composition: Option<TextComposition>) {
+        RopeEditor::set_composition(self, composition)
+    }
+}
+
 /// Iterator over text lines.
 pub struct LinesIterator<'a> {
     pub lines: Lines<'a>,
@@ -0,0 +1,272 @@
+use std::{
+    ops::Range,
+    rc::Rc,
+};
+
+use crate::{
+    Line,
+    SpanStyle,
+    TextEditor,
+    TextSpan,
+};
+
+/// A part of a line of text that must be highlighted with the given style.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    /// Byte range of the token in the line.
+    pub range: Range<usize>,
+    pub style: SpanStyle,
+}
+
+impl Token {
+    pub fn new(range: Range<usize>, style: SpanStyle) -> Self {
+        Self { range, style }
+    }
+}
+
+/// Split lines of text in [Token]s, used by the [SyntaxHighlighter].
+///
+/// Lines are tokenized one by one, so anything spanning multiple lines
+/// (e.g a block comment) must be tracked in the [`Tokenizer::State`].
+pub trait Tokenizer {
+    /// State of the tokenizer at the start of a line.
+    type State: Clone + PartialEq + Default;
+
+    /// Tokenize a line, including its line break, starting in the given state
+    /// and update the state to the one at the end of the line.
+    ///
+    /// The tokens must be sorted and not overlap, the text without tokens will use the default style.
+    fn tokenize_line(&self, line: &str, state: &mut Self::State) -> Vec<Token>;
+}
+
+/// A highlighted line of text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighlightedLine {
+    pub spans: Rc<[TextSpan]>,
+}
+
+struct CachedLine<S> {
+    text: String,
+    start_state: S,
+    end_state: S,
+    line: HighlightedLine,
+}
+
+/// Highlight the text of a [TextEditor] with a [Tokenizer].
+///
+/// Only the lines that changed since the last update are tokenized again,
+/// plus the following lines whose start state has changed because of them.
+pub struct SyntaxHighlighter<T: Tokenizer> {
+    tokenizer: T,
+    lines: Vec<CachedLine<T::State>>,
+}
+
+impl<T: Tokenizer> SyntaxHighlighter<T> {
+    pub fn new(tokenizer: T) -> Self {
+        Self {
+            tokenizer,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Get the highlighted lines as of the last update.
+    pub fn lines(&self) -> Vec<HighlightedLine> {
+        self.lines
+            .iter()
+            .map(|cached| cached.line.clone())
+            .collect()
+    }
+
+    /// Get a highlighted line as of the last update.
+    pub fn line(&self, line_idx: usize) -> Option<&HighlightedLine> {
+        self.lines.get(line_idx).map(|cached| &cached.line)
+    }
+
+    /// Forget all the highlighted lines, e.g after changing the tokenizer configuration.
+    pub fn invalidate(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Highlight the changes of the given editor and return how many lines had to be tokenized.
+    pub fn update(&mut self, editor: &impl TextEditor) -> usize {
+        self.update_lines(editor.lines().collect())
+    }
+
+    fn update_lines(&mut self, new_lines: Vec<Line<'_>>) -> usize {
+        // Lines that didn't change at the start and at the end of the text
+        let prefix = self
+            .lines
+            .iter()
+            .zip(&new_lines)
+            .take_while(|(cached, line)| cached.text == line.text)
+            .count();
+        let suffix = self
+            .lines
+            .iter()
+            .rev()
+            .zip(new_lines.iter().rev())
+            .take(self.lines.len().min(new_lines.len()) - prefix)
+            .take_while(|(cached, line)| cached.text == line.text)
+            .count();
+
+        let tail = self
+            .lines
+            .drain(self.lines.len() - suffix..)
+            .collect::<Vec<_>>();
+        self.lines.truncate(prefix);
+
+        let mut state = self
+            .lines
+            .last()
+            .map(|cached| cached.end_state.clone())
+            .unwrap_or_default();
+        let mut tokenized = 0;
+
+        for line in &new_lines[prefix..new_lines.len() - suffix] {
+            self.push_line(&line.text, &mut state);
+            tokenized += 1;
+        }
+
+        // Unchanged lines only need to be tokenized again if they now start in a different state
+        for (cached, line) in tail.into_iter().zip(&new_lines[new_lines.len() - suffix..]) {
+            if cached.start_state == state {
+                state = cached.end_state.clone();
+                self.lines.push(cached);
+            } else {
+                self.push_line(&line.text, &mut state);
+                tokenized += 1;
+            }
+        }
+
+        tokenized
+    }
+
+    fn push_line(&mut self, text: &str, state: &mut T::State) {
+        let start_state = state.clone();
+        let tokens = self.tokenizer.tokenize_line(text, state);
+        self.lines.push(CachedLine {
+            text: text.to_string(),
+            start_state,
+            end_state: state.clone(),
+            line: HighlightedLine {
+                spans: tokens_to_spans(text, tokens).into(),
+            },
+        });
+    }
+}
+
+/// Split a line in spans, the text between the tokens gets the default style.
+fn tokens_to_spans(text: &str, tokens: Vec<Token>) -> Vec<TextSpan> {
+    let mut spans = Vec::new();
+    let mut last_end = 0;
+
+    for Token { range, style } in tokens {
+        if range.start < last_end || text.get(range.clone()).is_none() {
+            continue;
+        }
+        if range.start > last_end {
+            spans.push(TextSpan::new(
+                &text[last_end..range.start],
+                SpanStyle::default(),
+            ));
+        }
+        if !range.is_empty() {
+            spans.push(TextSpan::new(&text[range.clone()], style));
+        }
+        last_end = range.end;
+    }
+
+    if last_end < text.len() {
+        spans.push(TextSpan::new(&text[last_end..], SpanStyle::default()));
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod test {
+    use ropey::Rope;
+
+    use super::{
+        Line,
+        SpanStyle,
+        SyntaxHighlighter,
+        TextSpan,
+        Token,
+        Tokenizer,
+    };
+
+    /// Highlight the `fn` keyword and `/* */` comments.
+    struct TestTokenizer;
+
+    impl Tokenizer for TestTokenizer {
+        /// Whether the line starts inside a comment
+        type State = bool;
+
+        fn tokenize_line(&self, line: &str, in_comment: &mut bool) -> Vec<Token> {
+            let comment = SpanStyle::default().with_color("gray");
+            let keyword = SpanStyle::default().with_bold(true);
+            let mut tokens = Vec::new();
+            let mut i = 0;
+
+            while i < line.len() {
+                if *in_comment {
+                    let end = line[i..].find("*/").map(|end| i + end + 2);
+                    tokens.push(Token::new(i..end.unwrap_or(line.len()), comment.clone()));
+                    *in_comment = end.is_none();
+                    i = end.unwrap_or(line.len());
+                } else if line[i..].starts_with("/*") {
+                    *in_comment = true;
+                } else if line[i..].starts_with("fn") {
+                    tokens.push(Token::new(i..i + 2, keyword.clone()));
+                    i += 2;
+                } else {
+                    i += line[i..].chars().next().unwrap().len_utf8();
+                }
+            }
+
+            tokens
+        }
+    }
+
+    fn lines(rope: &Rope) -> Vec<Line<'_>> {
+        rope.lines()
+            .map(|line| Line {
+                text: line.into(),
+                utf16_len: line.len_utf16_cu(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn incremental_highlighting() {
+        let mut rope = Rope::from_str("fn a\nb\nc\n");
+        let mut highlighter = SyntaxHighlighter::new(TestTokenizer);
+
+        assert_eq!(highlighter.update_lines(lines(&rope)), 4);
+        assert_eq!(
+            &*highlighter.line(0).unwrap().spans,
+            &[
+                TextSpan::new("fn", SpanStyle::default().with_bold(true)),
+                TextSpan::new(" a\n", SpanStyle::default())
+            ]
+        );
+
+        // Nothing changed
+        assert_eq!(highlighter.update_lines(lines(&rope)), 0);
+
+        // Only the edited line is tokenized again
+        rope.insert(5, "fn ");
+        assert_eq!(highlighter.update_lines(lines(&rope)), 1);
+        assert_eq!(highlighter.line(1).unwrap().spans.len(), 2);
+
+        // Opening a comment changes the state of the following lines
+        rope.insert(0, "/*\n");
+        assert_eq!(highlighter.update_lines(lines(&rope)), 5);
+        assert_eq!(highlighter.lines().len(), 5);
+        assert_eq!(
+            highlighter.line(2).unwrap().spans[0].style,
+            SpanStyle::default().with_color("gray")
+        );
+    }
+}
@@ -40,6 +40,30 @@ impl TextCursor {
     }
 }
 
+/// Text being composed with an IME, it's not part of the text until it gets committed.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct TextComposition {
+    /// The composed text.
+    pub text: String,
+    /// Position of the cursor in the composed text, in UTF-16 code units.
+    pub cursor: Option<usize>,
+}
+
+impl TextComposition {
+    /// Construct a new [TextComposition] given the byte range of the cursor in the composed text.
+    pub fn new(text: String, cursor: Option<(usize, usize)>) -> Self {
+        let cursor = cursor
+            .and_then(|(_, end)| text.get(..end))
+            .map(|text| text.encode_utf16().count());
+        Self { text, cursor }
+    }
+
+    /// Length of the composed text in UTF-16 code units.
+    pub fn utf16_len(&self) -> usize {
+        self.text.encode_utf16().count()
+    }
+}
+
 /// A text line from a [TextEditor]
 #[derive(Clone)]
 pub struct Line<'a> {
@@ -70,9 +94,45 @@ bitflags::bitflags! {
         const TEXT_CHANGED = 0x02;
         /// Selected text has changed
         const SELECTION_CHANGED = 0x04;
+        /// Style of the text has changed
+        const STYLE_CHANGED = 0x08;
     }
 }
 
+/// Kind of char, used to find where words start and end.
+#[derive(Clone, Copy, PartialEq)]
+enum CharKind {
+    Word,
+    Whitespace,
+    Symbol,
+}
+
+impl From<char> for CharKind {
+    fn from(char: char) -> Self {
+        if char.is_alphanumeric() || char == '_' {
+            Self::Word
+        } else if char.is_whitespace() {
+            Self::Whitespace
+        } else {
+            Self::Symbol
+        }
+    }
+}
+
+/// Get the start and length in UTF-16 code units and the kind of every char in a line, excluding its line break.
+fn line_chars(line: &Line) -> Vec<(usize, usize, CharKind)> {
+    let mut start = 0;
+    line.text
+        .trim_end_matches(['\n', '\r'])
+        .chars()
+        .map(|char| {
+            let len = char.len_utf16();
+            start += len;
+            (start - len, len, CharKind::from(char))
+        })
+        .collect()
+}
+
 /// Common trait for editable texts
 pub trait TextEditor {
     type LinesIterator<'a>: Iterator<Item = Line<'a>>
@@ -254,6 +314,58 @@ pub trait TextEditor {
     // Update the selection with a new cursor
     fn expand_selection_to_cursor(&mut self);
 
+    /// Select the word around the cursor and move the cursor to its end.
+    fn select_word_at_cursor(&mut self) {
+        let (row, col) = self.cursor_row_and_col();
+        let Some(line) = self.line(row) else {
+            return;
+        };
+        let line_start = self.char_to_utf16_cu(self.line_to_char(row));
+        let chars = line_chars(&line);
+
+        let after = chars.iter().position(|(start, ..)| *start == col);
+        let before = after.unwrap_or(chars.len()).checked_sub(1);
+
+        // Prefer the word that ends in the cursor over the spaces or symbols after it
+        let target = match (before, after) {
+            (Some(before), _) if chars[before].2 == CharKind::Word => before,
+            (_, Some(after)) => after,
+            (Some(before), None) => before,
+            (None, None) => return,
+        };
+        let kind = chars[target].2;
+
+        let mut first = target;
+        while first > 0 && chars[first - 1].2 == kind {
+            first -= 1;
+        }
+        let mut last = target;
+        while last + 1 < chars.len() && chars[last + 1].2 == kind {
+            last += 1;
+        }
+
+        let from = line_start + chars[first].0;
+        let to = line_start + chars[last].0 + chars[last].1;
+        self.set_selection((from, to));
+        self.set_cursor_pos(to);
+    }
+
+    /// Select the line of the cursor, without its line break, and move the cursor to its end.
+    fn select_line_at_cursor(&mut self) {
+        let row = self.cursor_row();
+        let Some(line) = self.line(row) else {
+            return;
+        };
+        let line_start = self.char_to_utf16_cu(self.line_to_char(row));
+        let len = line_chars(&line)
+            .last()
+            .map(|(start, len, _)| start + len)
+            .unwrap_or_default();
+
+        self.set_selection((line_start, line_start + len));
+        self.set_cursor_pos(line_start + len);
+    }
+
     fn get_clipboard(&mut self) -> &mut UseClipboard;
 
     // Process a Keyboard event
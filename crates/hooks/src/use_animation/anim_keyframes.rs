@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use super::{
+    apply_value,
+    AnimDirection,
+    AnimatedValue,
+    Ease,
+    Function,
+};
+
+/// A stop of an [AnimKeyframes].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Keyframe {
+    /// Position of the stop in the animation, from `0.0` to `100.0`.
+    pub percentage: f32,
+    pub value: f32,
+    /// Easing used to reach this stop from the previous one, the animation ones by default.
+    pub ease: Option<Ease>,
+    pub function: Option<Function>,
+}
+
+/// Animate a numeric value through multiple stops.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// // Goes from 0 to 100 in the first 200ms, and then to 50 in the remaining 300ms.
+/// let keyframes = AnimKeyframes::new(0.)
+///     .stop(40., 100.)
+///     .stop(100., 50.)
+///     .time(500);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct AnimKeyframes {
+    keyframes: Vec<Keyframe>,
+    time: Duration,
+    ease: Ease,
+    function: Function,
+
+    value: f32,
+}
+
+impl AnimKeyframes {
+    /// Create the keyframes with the value at `0%`.
+    pub fn new(initial: f32) -> Self {
+        Self {
+            keyframes: vec![Keyframe {
+                percentage: 0.,
+                value: initial,
+                ease: None,
+                function: None,
+            }],
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+
+            value: initial,
+        }
+    }
+
+    /// Add a stop at the given percentage (from `0.0` to `100.0`) of the animation.
+    pub fn stop(self, percentage: f32, value: f32) -> Self {
+        self.keyframe(Keyframe {
+            percentage,
+            value,
+            ease: None,
+            function: None,
+        })
+    }
+
+    /// Add a stop with its own easing.
+    pub fn stop_with(self, percentage: f32, value: f32, ease: Ease, function: Function) -> Self {
+        self.keyframe(Keyframe {
+            percentage,
+            value,
+            ease: Some(ease),
+            function: Some(function),
+        })
+    }
+
+    /// Add a [Keyframe], stops with the same percentage replace the existing ones.
+    pub fn keyframe(mut self, mut keyframe: Keyframe) -> Self {
+        keyframe.percentage = keyframe.percentage.clamp(0., 100.);
+        let idx = self
+            .keyframes
+            .partition_point(|stop| stop.percentage < keyframe.percentage);
+        match self.keyframes.get_mut(idx) {
+            Some(stop) if stop.percentage == keyframe.percentage => *stop = keyframe,
+            _ => self.keyframes.insert(idx, keyframe),
+        }
+        self.value = self.keyframes[0].value;
+        self
+    }
+
+    /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
+    pub fn time(mut self, time: u64) -> Self {
+        self.time = Duration::from_millis(time);
+        self
+    }
+
+    /// Set the animation duration using milliseconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the default easing type of the stops. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the default easing function of the stops. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Read the value of the [AnimKeyframes] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+
+    fn first_value(&self) -> f32 {
+        self.keyframes[0].value
+    }
+
+    fn last_value(&self) -> f32 {
+        self.keyframes[self.keyframes.len() - 1].value
+    }
+
+    /// Get the value at the given percentage of the animation.
+    fn value_at(&self, percentage: f32) -> f32 {
+        let next = self
+            .keyframes
+            .partition_point(|stop| stop.percentage < percentage);
+
+        let (Some(from), Some(to)) = (
+            self.keyframes.get(next.saturating_sub(1)),
+            self.keyframes.get(next),
+        ) else {
+            return self.last_value();
+        };
+
+        if to.percentage <= from.percentage {
+            return to.value;
+        }
+
+        let time_ms = self.time.as_secs_f32() * 1000.;
+        let segment_time = (to.percentage - from.percentage) / 100. * time_ms;
+        let segment_index = (percentage - from.percentage) / 100. * time_ms;
+
+        apply_value(
+            from.value,
+            to.value,
+            segment_index.round() as u128,
+            Duration::from_millis(segment_time.round() as u64),
+            to.ease.unwrap_or(self.ease),
+            to.function.unwrap_or(self.function),
+        )
+    }
+}
+
+impl From<&AnimKeyframes> for f32 {
+    fn from(value: &AnimKeyframes) -> Self {
+        value.read()
+    }
+}
+
+impl AnimatedValue for AnimKeyframes {
+    fn prepare(&mut self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.value = self.first_value(),
+            AnimDirection::Reverse => self.value = self.last_value(),
+        }
+    }
+
+    fn is_finished(&self, index: u128, direction: AnimDirection) -> bool {
+        index >= self.time.as_millis()
+            && match direction {
+                AnimDirection::Forward => self.value == self.last_value(),
+                AnimDirection::Reverse => self.value == self.first_value(),
+            }
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let time = self.time.as_millis();
+        let progress = if time == 0 {
+            100.
+        } else {
+            index.min(time) as f32 / time as f32 * 100.
+        };
+        let percentage = match direction {
+            AnimDirection::Forward => progress,
+            AnimDirection::Reverse => 100. - progress,
+        };
+        self.value = self.value_at(percentage);
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.advance(self.time.as_millis(), direction);
+    }
+}
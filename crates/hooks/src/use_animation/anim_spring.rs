@@ -0,0 +1,154 @@
+use super::{
+    AnimDirection,
+    AnimatedValue,
+};
+
+/// How many milliseconds are simulated on every step of the spring.
+const STEP_MS: u128 = 1;
+
+/// Animate a numeric value with a physics-based spring.
+///
+/// Instead of a fixed duration, the animation lasts until the spring comes to rest.
+/// It can be retargeted while running with [`UseAnimation::retarget`](crate::UseAnimation::retarget),
+/// keeping its current value and velocity so there is no visual jump.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// let spring = AnimSpring::new(0., 100.).stiffness(200.).damping(15.);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct AnimSpring {
+    origin: f32,
+    destination: f32,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    precision: f32,
+
+    value: f32,
+    velocity: f32,
+    last_index: u128,
+    retargeted: bool,
+}
+
+impl AnimSpring {
+    pub fn new(origin: f32, destination: f32) -> Self {
+        Self {
+            origin,
+            destination,
+            stiffness: 170.,
+            damping: 26.,
+            mass: 1.,
+            precision: 0.01,
+
+            value: origin,
+            velocity: 0.,
+            last_index: 0,
+            retargeted: false,
+        }
+    }
+
+    /// Set the stiffness of the spring, higher values make it faster. `170.0` by default.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// Set the damping of the spring, lower values make it bounce more. `26.0` by default.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Set the mass attached to the spring, higher values make it slower. `1.0` by default.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(f32::EPSILON);
+        self
+    }
+
+    /// Set how close to the destination and how slow the spring needs to be to come to rest. `0.01` by default.
+    pub fn precision(mut self, precision: f32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Change the destination while keeping the current value and velocity.
+    ///
+    /// The next time the animation runs it will continue from where it is instead of from the origin.
+    pub fn retarget(&mut self, destination: f32) {
+        self.destination = destination;
+        self.retargeted = true;
+    }
+
+    /// Read the value of the [AnimSpring] as a f32.
+    pub fn read(&self) -> f32 {
+        self.value
+    }
+
+    /// Read the current velocity of the [AnimSpring], in units per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    fn target(&self, direction: AnimDirection) -> f32 {
+        match direction {
+            AnimDirection::Forward => self.destination,
+            AnimDirection::Reverse => self.origin,
+        }
+    }
+}
+
+impl From<&AnimSpring> for f32 {
+    fn from(value: &AnimSpring) -> Self {
+        value.read()
+    }
+}
+
+impl AnimatedValue for AnimSpring {
+    fn prepare(&mut self, direction: AnimDirection) {
+        self.last_index = 0;
+        if self.retargeted {
+            self.retargeted = false;
+            return;
+        }
+        self.velocity = 0.;
+        match direction {
+            AnimDirection::Forward => self.value = self.origin,
+            AnimDirection::Reverse => self.value = self.destination,
+        }
+    }
+
+    fn is_finished(&self, _index: u128, direction: AnimDirection) -> bool {
+        self.value == self.target(direction) && self.velocity == 0.
+    }
+
+    fn advance(&mut self, index: u128, direction: AnimDirection) {
+        let target = self.target(direction);
+        let dt = STEP_MS as f32 / 1000.;
+
+        while self.last_index + STEP_MS <= index {
+            self.last_index += STEP_MS;
+
+            // Semi-implicit Euler integration
+            let spring_force = -self.stiffness * (self.value - target);
+            let damping_force = -self.damping * self.velocity;
+            self.velocity += (spring_force + damping_force) / self.mass * dt;
+            self.value += self.velocity * dt;
+
+            let is_resting = self.velocity.abs() <= self.precision
+                && (self.value - target).abs() <= self.precision;
+            if is_resting {
+                self.value = target;
+                self.velocity = 0.;
+                self.last_index = index;
+                break;
+            }
+        }
+    }
+
+    fn finish(&mut self, direction: AnimDirection) {
+        self.value = self.target(direction);
+        self.velocity = 0.;
+        self.retargeted = false;
+    }
+}
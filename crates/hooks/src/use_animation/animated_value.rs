@@ -17,7 +17,7 @@ pub trait AnimatedValue: Clone + 'static {
     fn finish(&mut self, direction: AnimDirection);
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Ease {
     In,
     #[default]
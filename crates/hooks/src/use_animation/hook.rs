@@ -32,6 +32,8 @@ pub struct AnimConfiguration {
     on_finish: OnFinish,
     on_creation: OnCreation,
     on_deps_change: OnDepsChange,
+    iterations: AnimIterations,
+    alternate: bool,
 }
 
 impl AnimConfiguration {
@@ -49,6 +51,45 @@ impl AnimConfiguration {
         self.on_deps_change = on_deps_change;
         self
     }
+
+    /// How many times the animation runs before being finished, see [OnFinish] for what happens after.
+    pub fn iterations(&mut self, iterations: AnimIterations) -> &mut Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Toggle the direction of the animation after every iteration.
+    pub fn alternate(&mut self, alternate: bool) -> &mut Self {
+        self.alternate = alternate;
+        self
+    }
+}
+
+/// How many times an animation runs.
+///
+/// By default it is [AnimIterations::Finite] with `1`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum AnimIterations {
+    /// Runs the given number of times.
+    Finite(u32),
+    /// Runs forever.
+    Infinite,
+}
+
+impl Default for AnimIterations {
+    fn default() -> Self {
+        Self::Finite(1)
+    }
+}
+
+impl AnimIterations {
+    /// Check if there are iterations left after the given number of completed iterations.
+    pub fn has_remaining(&self, completed: u32) -> bool {
+        match self {
+            Self::Finite(iterations) => completed < *iterations,
+            Self::Infinite => true,
+        }
+    }
 }
 
 /// Controls the direction of the animation.
@@ -189,6 +230,15 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
         self.run(AnimDirection::Forward)
     }
 
+    /// Modify the animated value and run the animation again in the last direction.
+    ///
+    /// Useful to change the destination of an [`AnimSpring`](crate::AnimSpring) while it's running,
+    /// as it continues from its current value and velocity.
+    pub fn retarget(&self, retarget: impl FnOnce(&mut Animated)) {
+        retarget(self.animated_value.write_unchecked().as_mut().unwrap());
+        self.run(*self.last_direction.peek());
+    }
+
     /// Run the animation with a given [`AnimDirection`]
     pub fn run(&self, mut direction: AnimDirection) {
         let platform = self.platform;
@@ -199,6 +249,8 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
         let animation_clock = self.animation_clock;
 
         let on_finish = self.conf.peek().on_finish;
+        let iterations = self.conf.peek().iterations;
+        let alternate = self.conf.peek().alternate;
         let mut animated_value = self.animated_value;
 
         last_direction.set(direction);
@@ -215,6 +267,7 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
             platform.request_animation_frame();
 
             let mut index = 0u128;
+            let mut completed_iterations = 0u32;
             let mut prev_frame = Instant::now();
 
             // Prepare the animations with the the proper direction
@@ -251,6 +304,21 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
                 prev_frame = Instant::now();
 
                 if is_finished {
+                    completed_iterations = completed_iterations.saturating_add(1);
+
+                    if iterations.has_remaining(completed_iterations) {
+                        if alternate {
+                            direction.toggle();
+                        }
+                        index = 0;
+
+                        // Run the next iteration
+                        animated_value.prepare(direction);
+                        continue;
+                    }
+
+                    completed_iterations = 0;
+
                     if OnFinish::Reverse == on_finish {
                         // Toggle direction
                         direction.toggle();
@@ -290,6 +358,9 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 ///
 /// For animations where you want to animate a value after one another you may use [crate::AnimSequential].
 ///
+/// Values can also go through multiple stops with [crate::AnimKeyframes], or be driven by a physics-based spring with [crate::AnimSpring].
+/// Use [AnimConfiguration::iterations] and [AnimConfiguration::alternate] to repeat the animation.
+///
 /// # Example
 ///
 /// Here is an example that animates a value from `0.0` to `100.0` in `50` milliseconds.
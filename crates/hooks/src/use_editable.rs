@@ -31,7 +31,10 @@ use freya_core::{
 use freya_elements::{
     events::{
         Code,
+        CompositionData,
+        Key,
         KeyboardData,
+        Modifiers,
         MouseData,
     },
     MouseButton,
@@ -40,12 +43,17 @@ use tokio::sync::mpsc::unbounded_channel;
 use torin::geometry::CursorPoint;
 
 use crate::{
+    rich_rope_editor::spans_to_styles,
     use_platform,
     EditorHistory,
+    RichRopeEditor,
     RopeEditor,
+    StyleRuns,
+    TextComposition,
     TextCursor,
     TextEditor,
     TextEvent,
+    TextSpan,
     UseId,
     UsePlatform,
 };
@@ -57,6 +65,9 @@ pub enum EditableEvent {
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
     KeyUp(Rc<KeyboardData>),
+    CompositionStart,
+    CompositionUpdate(Rc<CompositionData>),
+    CompositionEnd,
 }
 
 /// How the editable content must behave.
@@ -127,10 +138,41 @@ impl TextDragging {
     }
 }
 
+/// Text editors that can be managed by a [`UseEditable`].
+pub trait EditableText: TextEditor + 'static {
+    /// Text being composed with an IME, if any.
+    fn composition(&self) -> Option<&TextComposition>;
+
+    /// Check if there is some text being composed with an IME.
+    fn is_composing(&self) -> bool;
+
+    /// Start, update or finish (with `None`) the composition of text.
+    fn set_composition(&mut self, composition: Option<TextComposition>);
+
+    /// Process a Keyboard event, by default it's the same as [`TextEditor::process_key`].
+    fn process_key_event(
+        &mut self,
+        key: &Key,
+        code: &Code,
+        modifiers: &Modifiers,
+        allow_tabs: bool,
+        allow_changes: bool,
+        allow_clipboard: bool,
+    ) -> TextEvent {
+        self.process_key(
+            key,
+            code,
+            modifiers,
+            allow_tabs,
+            allow_changes,
+            allow_clipboard,
+        )
+    }
+}
+
 /// Manage an editable text.
-#[derive(Clone, Copy, PartialEq)]
-pub struct UseEditable {
-    pub(crate) editor: Signal<RopeEditor>,
+pub struct UseEditable<Editor: 'static = RopeEditor> {
+    pub(crate) editor: Signal<Editor>,
     pub(crate) cursor_reference: Signal<CursorReference>,
     pub(crate) dragging: Signal<TextDragging>,
     pub(crate) platform: UsePlatform,
@@ -139,6 +181,26 @@ pub struct UseEditable {
     pub(crate) allow_clipboard: bool,
 }
 
+impl<Editor: 'static> Clone for UseEditable<Editor> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Editor: 'static> Copy for UseEditable<Editor> {}
+
+impl<Editor: 'static> PartialEq for UseEditable<Editor> {
+    fn eq(&self, other: &Self) -> bool {
+        self.editor == other.editor
+            && self.cursor_reference == other.cursor_reference
+            && self.dragging == other.dragging
+            && self.platform == other.platform
+            && self.allow_tabs == other.allow_tabs
+            && self.allow_changes == other.allow_changes
+            && self.allow_clipboard == other.allow_clipboard
+    }
+}
+
 impl UseEditable {
     /// Manually create an editable content instead of using [use_editable].
     pub fn new_in_hook(
@@ -147,15 +209,43 @@ impl UseEditable {
         config: EditableConfig,
         mode: EditableMode,
     ) -> Self {
-        let text_id = UseId::<UseEditable>::get_in_hook();
-        let mut editor = Signal::new(RopeEditor::new(
-            config.content,
-            config.cursor,
+        let editor = RopeEditor::new(
+            config.content.clone(),
+            config.cursor.clone(),
             config.identation,
             mode,
             clipboard,
             EditorHistory::new(Duration::from_secs(1)),
-        ));
+        );
+        Self::new_with_editor(editor, platform, &config)
+    }
+}
+
+impl UseEditable<RichRopeEditor> {
+    /// Manually create an editable rich text instead of using [use_rich_editable].
+    pub fn new_rich_in_hook(
+        clipboard: UseClipboard,
+        platform: UsePlatform,
+        config: EditableConfig,
+    ) -> Self {
+        let editor = RopeEditor::new(
+            config.content.clone(),
+            config.cursor.clone(),
+            config.identation,
+            EditableMode::MultipleLinesSingleEditor,
+            clipboard,
+            EditorHistory::new(Duration::from_secs(1)),
+        );
+        let styles = config.styles.clone().unwrap_or_default();
+        Self::new_with_editor(RichRopeEditor::new(editor, styles), platform, &config)
+    }
+}
+
+impl<Editor: EditableText> UseEditable<Editor> {
+    /// Manually create an editable content with a custom [`EditableText`] editor.
+    pub fn new_with_editor(editor: Editor, platform: UsePlatform, config: &EditableConfig) -> Self {
+        let text_id = UseId::<UseEditable>::get_in_hook();
+        let mut editor = Signal::new(editor);
         let dragging = Signal::new(TextDragging::None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
@@ -225,12 +315,12 @@ impl UseEditable {
     }
 
     /// Reference to the editor.
-    pub fn editor(&self) -> &Signal<RopeEditor> {
+    pub fn editor(&self) -> &Signal<Editor> {
         &self.editor
     }
 
     /// Mutable reference to the editor.
-    pub fn editor_mut(&mut self) -> &mut Signal<RopeEditor> {
+    pub fn editor_mut(&mut self) -> &mut Signal<Editor> {
         &mut self.editor
     }
 
@@ -252,9 +342,49 @@ impl UseEditable {
         ))
     }
 
+    /// Split the given text at the cursor to render the text being composed with an IME where it will be inserted.
+    ///
+    /// The given text, or part of the text, starts at the char `text_start` of the editor.
+    /// It can be different from the editor text, e.g. a password mask, as long as it has the same chars count.
+    ///
+    /// Returns the text before the cursor, the composed text and the text after the cursor,
+    /// or `None` if there is no composition or the cursor is not within the given text.
+    pub fn split_composition<'a>(
+        &self,
+        text: &'a str,
+        text_start: usize,
+    ) -> Option<(&'a str, String, &'a str)> {
+        let editor = self.editor.read();
+        let composition = editor.composition()?;
+        let cursor_char = editor
+            .utf16_cu_to_char(editor.cursor_pos())
+            .checked_sub(text_start)?;
+        let split_byte = match text.char_indices().nth(cursor_char) {
+            Some((byte, _)) => byte,
+            None if cursor_char == text.chars().count() => text.len(),
+            None => return None,
+        };
+        let (before, after) = text.split_at(split_byte);
+        Some((before, composition.text.clone(), after))
+    }
+
     /// Process a [`EditableEvent`] event.
     pub fn process_event(&mut self, edit_event: &EditableEvent) {
         let res = match edit_event {
+            // The first click already moved the cursor, so a double click selects its word and a triple click its line
+            EditableEvent::MouseDown(e, _)
+                if e.get_trigger_button() == Some(MouseButton::Left) && e.get_click_count() > 1 =>
+            {
+                let mut editor = self.editor.write();
+                if e.get_click_count() == 2 {
+                    editor.select_word_at_cursor();
+                } else {
+                    editor.select_line_at_cursor();
+                }
+                *self.dragging.write() = TextDragging::None;
+
+                None
+            }
             EditableEvent::MouseDown(e, id)
                 if e.get_trigger_button() == Some(MouseButton::Left) =>
             {
@@ -286,6 +416,26 @@ impl UseEditable {
                 }
                 None
             }
+            EditableEvent::CompositionStart if self.allow_changes => {
+                self.editor
+                    .write()
+                    .set_composition(Some(TextComposition::default()));
+                None
+            }
+            EditableEvent::CompositionUpdate(e) if self.allow_changes => {
+                self.editor
+                    .write()
+                    .set_composition(Some(TextComposition::new(e.text.clone(), e.cursor)));
+                None
+            }
+            EditableEvent::CompositionEnd => {
+                if self.editor.peek().is_composing() {
+                    self.editor.write().set_composition(None);
+                }
+                None
+            }
+            // The IME handles the keys while composing
+            EditableEvent::KeyDown(_) if self.editor.peek().is_composing() => None,
             EditableEvent::KeyDown(e) => {
                 match e.code {
                     // Handle dragging
@@ -311,7 +461,7 @@ impl UseEditable {
                     }
                     // Handle editing
                     _ => {
-                        let event = self.editor.write().process_key(
+                        let event = self.editor.write().process_key_event(
                             &e.key,
                             &e.code,
                             &e.modifiers,
@@ -362,6 +512,7 @@ impl UseEditable {
 /// Create a configuration for a [`UseEditable`].
 pub struct EditableConfig {
     pub(crate) content: String,
+    pub(crate) styles: Option<StyleRuns>,
     pub(crate) cursor: TextCursor,
     pub(crate) identation: u8,
     pub(crate) allow_tabs: bool,
@@ -374,6 +525,7 @@ impl EditableConfig {
     pub fn new(content: String) -> Self {
         Self {
             content,
+            styles: None,
             cursor: TextCursor::default(),
             identation: 4,
             allow_tabs: false,
@@ -382,6 +534,13 @@ impl EditableConfig {
         }
     }
 
+    /// Create a [`EditableConfig`] for a rich text, see [use_rich_editable].
+    pub fn new_rich(spans: &[TextSpan]) -> Self {
+        let mut config = Self::new(spans.iter().map(|span| span.text.as_str()).collect());
+        config.styles = Some(spans_to_styles(spans));
+        config
+    }
+
     /// Specify a custom initial cursor position.
     pub fn with_cursor(mut self, pos: usize) -> Self {
         self.cursor = TextCursor::new(pos);
@@ -428,3 +587,18 @@ pub fn use_editable(
 
     use_hook(|| UseEditable::new_in_hook(clipboard, platform, initializer(), mode))
 }
+
+/// Hook to create an editable rich text, its text is split in parts with their own style, see [`RichRopeEditor`].
+///
+/// For manual creation use [UseEditable::new_rich_in_hook].
+///
+/// **This is a low level hook and is not expected to be used by the common user, in fact,
+/// you might be looking for something like the `RichTextEditor` component instead.**
+pub fn use_rich_editable(
+    initializer: impl FnOnce() -> EditableConfig,
+) -> UseEditable<RichRopeEditor> {
+    let platform = use_platform();
+    let clipboard = use_clipboard();
+
+    use_hook(|| UseEditable::new_rich_in_hook(clipboard, platform, initializer()))
+}
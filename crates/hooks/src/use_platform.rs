@@ -23,6 +23,7 @@ use freya_core::{
     },
     platform::CursorIcon,
 };
+use freya_elements::events::DragPayload;
 use tokio::sync::{
     broadcast,
     mpsc::UnboundedSender,
@@ -208,6 +209,15 @@ impl UsePlatform {
         }
     }
 
+    /// Start a drag session carrying the given [DragPayload].
+    /// The drag session ends once the mouse is released, emitting the `drop` event on the element under the cursor,
+    /// which might even be in another window of the app.
+    ///
+    /// The `preview` area of the window, if any, is used as a floating preview when the cursor is over other windows.
+    pub fn start_drag(&self, payload: DragPayload, preview: Option<Area>) {
+        self.send_app_event(EventLoopMessageAction::StartDrag { payload, preview });
+    }
+
     /// Closes the window.
     pub fn close_window(&self) {
         self.send_app_event(EventLoopMessageAction::CloseWindow);
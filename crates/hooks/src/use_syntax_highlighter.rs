@@ -0,0 +1,74 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use dioxus_core::use_hook;
+use dioxus_hooks::use_memo;
+use dioxus_signals::{
+    Memo,
+    Readable,
+};
+
+use crate::{
+    EditableText,
+    HighlightedLine,
+    SyntaxHighlighter,
+    Tokenizer,
+    UseEditable,
+};
+
+/// Highlight the text of an editable with the given [`Tokenizer`].
+///
+/// The highlighted lines are updated after every change in the editor,
+/// only tokenizing again the lines that changed, see [`SyntaxHighlighter`].
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// struct Keywords;
+///
+/// impl Tokenizer for Keywords {
+///     type State = ();
+///
+///     fn tokenize_line(&self, line: &str, _: &mut ()) -> Vec<Token> {
+///         line.match_indices("fn")
+///             .map(|(i, _)| Token::new(i..i + 2, SpanStyle::default().with_color("blue")))
+///             .collect()
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let editable = use_editable(
+///         || EditableConfig::new("fn main() {}".to_string()),
+///         EditableMode::MultipleLinesSingleEditor,
+///     );
+///     let highlighted = use_syntax_highlighter(editable, || Keywords);
+///
+///     rsx!(
+///         paragraph {
+///             for (line_idx, line) in highlighted.read().iter().enumerate() {
+///                 for (span_idx, span) in line.spans.iter().enumerate() {
+///                     text {
+///                         key: "{line_idx}-{span_idx}",
+///                         color: span.style.color.clone().unwrap_or_else(|| "inherit".to_string()),
+///                         "{span.text}"
+///                     }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_syntax_highlighter<E: EditableText, T: Tokenizer + 'static>(
+    editable: UseEditable<E>,
+    tokenizer: impl FnOnce() -> T,
+) -> Memo<Vec<HighlightedLine>> {
+    let highlighter = use_hook(|| Rc::new(RefCell::new(SyntaxHighlighter::new(tokenizer()))));
+
+    use_memo(move || {
+        let editor = editable.editor().read();
+        let mut highlighter = highlighter.borrow_mut();
+        highlighter.update(&*editor);
+        highlighter.lines()
+    })
+}
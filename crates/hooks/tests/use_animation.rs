@@ -271,3 +271,142 @@ pub async fn sequential() {
     assert_eq!(width_a, 100.0);
     assert_eq!(width_b, 100.0);
 }
+
+#[test]
+pub fn keyframes() {
+    let mut keyframes = AnimKeyframes::new(0.)
+        .stop(50., 100.)
+        .stop(100., 20.)
+        .time(100);
+
+    keyframes.prepare(AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 0.);
+
+    keyframes.advance(25, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 50.);
+
+    keyframes.advance(50, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 100.);
+
+    keyframes.advance(75, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 60.);
+
+    keyframes.advance(100, AnimDirection::Forward);
+    assert_eq!(keyframes.read(), 20.);
+    assert!(keyframes.is_finished(100, AnimDirection::Forward));
+
+    // Reverse goes through the stops backwards
+    keyframes.prepare(AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 20.);
+
+    keyframes.advance(25, AnimDirection::Reverse);
+    assert_eq!(keyframes.read(), 60.);
+}
+
+#[test]
+pub fn spring_retarget() {
+    let mut spring = AnimSpring::new(0., 100.);
+
+    spring.prepare(AnimDirection::Forward);
+    spring.advance(50, AnimDirection::Forward);
+
+    let value = spring.read();
+    let velocity = spring.velocity();
+    assert!(value > 0. && value < 100.);
+    assert!(velocity > 0.);
+
+    // Retargeting keeps the current value and velocity
+    spring.retarget(50.);
+    spring.prepare(AnimDirection::Forward);
+    assert_eq!(spring.read(), value);
+    assert_eq!(spring.velocity(), velocity);
+
+    // Eventually comes to rest in the new destination
+    spring.advance(5000, AnimDirection::Forward);
+    assert_eq!(spring.read(), 50.);
+    assert!(spring.is_finished(5000, AnimDirection::Forward));
+}
+
+#[tokio::test]
+pub async fn iterations_alternate() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|conf| {
+            conf.on_creation(OnCreation::Run)
+                .iterations(AnimIterations::Finite(2))
+                .alternate(true);
+            AnimNum::new(10., 100.).time(50)
+        });
+
+        let progress = animation.get().read().read();
+
+        rsx!(rect {
+            background: "white",
+            height: "100%",
+            width: "{progress}",
+        })
+    }
+
+    let mut utils = launch_test(use_animation_app);
+
+    // Initial state
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+
+    // First iteration finished
+    sleep(Duration::from_millis(70)).await;
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert!(width > 10.0);
+
+    // Second iteration went back to the origin
+    sleep(Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert_eq!(width, 10.0);
+}
+
+#[tokio::test]
+pub async fn virtual_clock_progress() {
+    fn use_animation_app() -> Element {
+        let animation = use_animation(|_conf| AnimNum::new(0., 100.).time(300));
+
+        let progress = animation.get().read().read();
+
+        use_hook(|| {
+            animation.start();
+        });
+
+        rsx!(rect {
+            width: "{progress}",
+        })
+    }
+
+    let mut utils = launch_test_with_config(
+        use_animation_app,
+        TestingConfig::<()> {
+            virtual_clock: true,
+            ..TestingConfig::default()
+        },
+    );
+
+    // Initial state, no time has passed yet
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 0.0);
+
+    // Exactly half of the animation
+    utils.advance(Duration::from_millis(150)).await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 50.0);
+
+    // Run the rest of the animation
+    let elapsed = utils.run_until_idle().await;
+
+    assert!(elapsed >= Duration::from_millis(150));
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 100.0);
+}
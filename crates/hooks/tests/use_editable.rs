@@ -1286,3 +1286,240 @@ pub async fn navigate_empty_lines() {
     let cursor = root.get(1).get(0);
     assert_eq!(cursor.text(), Some("1:0"));
 }
+
+#[tokio::test]
+pub async fn ime_composition() {
+    fn ime_composition_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let mut focus = use_focus();
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.visible_cursor_pos();
+        let composition = editor
+            .composition()
+            .map(|composition| composition.text.clone())
+            .unwrap_or_default();
+        let text = editor.to_string();
+        let split_text = editable
+            .split_composition(&text, 0)
+            .map(|(before, composition, after)| format!("{before}[{composition}]{after}"))
+            .unwrap_or_default();
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+            focus.request_focus();
+        };
+
+        let onkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        let oncompositionstart = move |_| {
+            editable.process_event(&EditableEvent::CompositionStart);
+        };
+
+        let oncompositionupdate = move |e: Event<CompositionData>| {
+            editable.process_event(&EditableEvent::CompositionUpdate(e.data));
+        };
+
+        let oncompositionend = move |_| {
+            editable.process_event(&EditableEvent::CompositionEnd);
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                a11y_id: focus.attribute(),
+                onmousedown,
+                onkeydown,
+                oncompositionstart,
+                oncompositionupdate,
+                oncompositionend,
+                paragraph {
+                    cursor_reference: cursor_attr,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{cursor_pos}:{composition}"
+                }
+                label {
+                    "{split_text}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(ime_composition_app);
+
+    // Move cursor and focus
+    utils.push_event(TestEvent::Mouse {
+        name: MouseEventName::MouseDown,
+        cursor: (35.0, 3.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let content = root.get(0).get(0).get(0);
+    let status = root.get(1).get(0);
+    assert_eq!(status.text(), Some("5:"));
+
+    // Compose some text
+    utils.push_event(TestEvent::Composition {
+        name: CompositionEventName::CompositionStart,
+        text: String::new(),
+        cursor: None,
+    });
+    utils.push_event(TestEvent::Composition {
+        name: CompositionEventName::CompositionUpdate,
+        text: "にほん".to_string(),
+        cursor: Some((9, 9)),
+    });
+    utils.wait_for_update().await;
+
+    // The composed text is not part of the text yet
+    assert_eq!(content.text(), Some("Hello Rustaceans"));
+    assert_eq!(status.text(), Some("8:にほん"));
+    assert_eq!(root.get(2).get(0).text(), Some("Hello[にほん] Rustaceans"));
+
+    // Keys are handled by the IME while composing
+    utils.push_event(TestEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Backspace,
+        code: Code::Backspace,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(content.text(), Some("Hello Rustaceans"));
+
+    // Commit the composition
+    utils.push_event(TestEvent::Composition {
+        name: CompositionEventName::CompositionEnd,
+        text: String::new(),
+        cursor: None,
+    });
+    utils.push_event(TestEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Character("日本".to_string()),
+        code: Code::Unidentified,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(content.text(), Some("Hello日本 Rustaceans"));
+    assert_eq!(status.text(), Some("7:"));
+
+    #[cfg(target_os = "macos")]
+    let modifiers = Modifiers::META;
+
+    #[cfg(not(target_os = "macos"))]
+    let modifiers = Modifiers::CONTROL;
+
+    // Undo the committed text at once
+    utils.push_event(TestEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Character("z".to_string()),
+        code: Code::KeyZ,
+        modifiers,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(content.text(), Some("Hello Rustaceans"));
+}
+
+#[tokio::test]
+pub async fn double_and_triple_click_selection() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans!\nHello Rustaceans".to_string()),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let cursor_reference = editable.cursor_attr();
+        let highlights = editable.highlights_attr(0);
+
+        let onmousedown = move |e: MouseEvent| {
+            editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+        };
+
+        let onclick = move |_: MouseEvent| {
+            editable.process_event(&EditableEvent::Click);
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                paragraph {
+                    cursor_reference,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    highlights,
+                    onmousedown,
+                    onclick,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+                label {
+                    color: "black",
+                    height: "50%",
+                    "{editor.cursor_row()}:{editor.cursor_col()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+
+    // Click in the middle of "Rustaceans"
+    utils.click_cursor((80., 3.)).await;
+    utils.wait_for_update().await;
+
+    let highlights = root.get(0).state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![]));
+
+    // Double click selects the word
+    utils.click_cursor((80., 3.)).await;
+    utils.wait_for_update().await;
+
+    let highlights = root.get(0).state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![(6, 16)]));
+    assert_eq!(root.get(1).get(0).text(), Some("0:16"));
+
+    // Triple click selects the line
+    utils.click_cursor((80., 3.)).await;
+    utils.wait_for_update().await;
+
+    let highlights = root.get(0).state().cursor.highlights.clone();
+    assert_eq!(highlights, Some(vec![(0, 17)]));
+    assert_eq!(root.get(1).get(0).text(), Some("0:17"));
+}
@@ -50,6 +50,14 @@ pub enum AttributeName {
     Opacity,
     Content,
     WrapContent,
+    GridColumns,
+    GridRows,
+    GridAreas,
+    GridColumn,
+    GridRow,
+    GridColumnSpan,
+    GridRowSpan,
+    GridArea,
     CanvasReference,
     Layer,
     OffsetY,
@@ -70,7 +78,15 @@ pub enum AttributeName {
     Spacing,
     BlendMode,
     BackdropBlur,
+    Filter,
     Scale,
+    Translate,
+    Skew,
+    Matrix,
+    TransformOrigin,
+    ClipPath,
+    Mask,
+    MaskData,
 
     // Image element
     AspectRatio,
@@ -284,6 +300,14 @@ impl FromStr for AttributeName {
             "opacity" => Ok(AttributeName::Opacity),
             "content" => Ok(AttributeName::Content),
             "wrap_content" => Ok(AttributeName::WrapContent),
+            "grid_columns" => Ok(AttributeName::GridColumns),
+            "grid_rows" => Ok(AttributeName::GridRows),
+            "grid_areas" => Ok(AttributeName::GridAreas),
+            "grid_column" => Ok(AttributeName::GridColumn),
+            "grid_row" => Ok(AttributeName::GridRow),
+            "grid_column_span" => Ok(AttributeName::GridColumnSpan),
+            "grid_row_span" => Ok(AttributeName::GridRowSpan),
+            "grid_area" => Ok(AttributeName::GridArea),
             "canvas_reference" => Ok(AttributeName::CanvasReference),
             "layer" => Ok(AttributeName::Layer),
             "offset_y" => Ok(AttributeName::OffsetY),
@@ -304,7 +328,15 @@ impl FromStr for AttributeName {
             "spacing" => Ok(AttributeName::Spacing),
             "blend_mode" => Ok(AttributeName::BlendMode),
             "backdrop_blur" => Ok(AttributeName::BackdropBlur),
+            "filter" => Ok(AttributeName::Filter),
             "scale" => Ok(AttributeName::Scale),
+            "translate" => Ok(AttributeName::Translate),
+            "skew" => Ok(AttributeName::Skew),
+            "matrix" => Ok(AttributeName::Matrix),
+            "transform_origin" => Ok(AttributeName::TransformOrigin),
+            "clip_path" => Ok(AttributeName::ClipPath),
+            "mask" => Ok(AttributeName::Mask),
+            "mask_data" => Ok(AttributeName::MaskData),
             "aspect_ratio" => Ok(AttributeName::AspectRatio),
             "cover" => Ok(AttributeName::ImageCover),
             "cache_key" => Ok(AttributeName::ImageCacheKey),
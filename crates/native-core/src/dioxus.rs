@@ -9,10 +9,7 @@ use dioxus_core::{
     TemplateNode,
     WriteMutations,
 };
-use rustc_hash::{
-    FxHashMap,
-    FxHashSet,
-};
+use rustc_hash::FxHashMap;
 use shipyard::Component;
 
 use crate::{
@@ -268,20 +265,21 @@ fn create_template_node<V: FromAnyValue + Send + Sync>(
             children,
             ..
         } => {
-            let node = NodeType::Element(ElementNode {
-                tag: TagName::from_str(tag).expect("Unexpected."),
-                attributes: attrs
-                    .iter()
-                    .filter_map(|attr| match attr {
-                        dioxus_core::TemplateAttribute::Static { name, value, .. } => Some((
+            let mut element = ElementNode::new(TagName::from_str(tag).expect("Unexpected."));
+            for (position, attr) in attrs.iter().enumerate() {
+                match attr {
+                    dioxus_core::TemplateAttribute::Static { name, value, .. } => element
+                        .declare_attribute(
+                            position,
                             AttributeName::from_str(name).expect("Unexpected."),
                             OwnedAttributeValue::Text(value.to_string()),
-                        )),
-                        dioxus_core::TemplateAttribute::Dynamic { .. } => None,
-                    })
-                    .collect(),
-                listeners: FxHashSet::default(),
-            });
+                        ),
+                    dioxus_core::TemplateAttribute::Dynamic { .. } => {
+                        element.declare_dynamic_attribute(position)
+                    }
+                }
+            }
+            let node = NodeType::Element(element);
             let node_id = rdom.create_node(node).id();
             for child in *children {
                 let child_id = create_template_node(rdom, child);
@@ -5,6 +5,7 @@ pub enum EventName {
     Click,
     MiddleClick,
     RightClick,
+    DoubleClick,
 
     MouseUp,
     MouseDown,
@@ -14,6 +15,9 @@ pub enum EventName {
 
     Wheel,
 
+    Pinch,
+    Rotate,
+
     PointerOver,
     PointerDown,
     PointerEnter,
@@ -26,6 +30,19 @@ pub enum EventName {
     GlobalKeyDown,
     GlobalKeyUp,
 
+    CompositionStart,
+    CompositionUpdate,
+    CompositionEnd,
+
+    Focus,
+    Blur,
+    FocusIn,
+    FocusOut,
+
+    Layout,
+    Resize,
+    Scroll,
+
     TouchCancel,
     TouchStart,
     TouchMove,
@@ -38,6 +55,12 @@ pub enum EventName {
     GlobalFileHover,
     GlobalFileHoverCancelled,
 
+    DragOver,
+    DragLeave,
+    Drop,
+    GlobalDragOver,
+    GlobalDragEnd,
+
     CaptureGlobalMouseMove,
     CaptureGlobalPointerUp,
 
@@ -52,12 +75,15 @@ impl FromStr for EventName {
             "click" => Ok(EventName::Click),
             "rightclick" => Ok(EventName::RightClick),
             "middleclick" => Ok(EventName::MiddleClick),
+            "doubleclick" => Ok(EventName::DoubleClick),
             "mouseup" => Ok(EventName::MouseUp),
             "mousedown" => Ok(EventName::MouseDown),
             "mousemove" => Ok(EventName::MouseMove),
             "mouseenter" => Ok(EventName::MouseEnter),
             "mouseleave" => Ok(EventName::MouseLeave),
             "wheel" => Ok(EventName::Wheel),
+            "pinch" => Ok(EventName::Pinch),
+            "rotate" => Ok(EventName::Rotate),
             "pointermove" => Ok(EventName::PointerOver),
             "pointerdown" => Ok(EventName::PointerDown),
             "pointerenter" => Ok(EventName::PointerEnter),
@@ -68,6 +94,16 @@ impl FromStr for EventName {
             "keyup" => Ok(EventName::KeyUp),
             "globalkeydown" => Ok(EventName::GlobalKeyDown),
             "globalkeyup" => Ok(EventName::GlobalKeyUp),
+            "compositionstart" => Ok(EventName::CompositionStart),
+            "compositionupdate" => Ok(EventName::CompositionUpdate),
+            "compositionend" => Ok(EventName::CompositionEnd),
+            "focus" => Ok(EventName::Focus),
+            "blur" => Ok(EventName::Blur),
+            "focusin" => Ok(EventName::FocusIn),
+            "focusout" => Ok(EventName::FocusOut),
+            "layout" => Ok(EventName::Layout),
+            "resize" => Ok(EventName::Resize),
+            "scroll" => Ok(EventName::Scroll),
             "touchcancel" => Ok(EventName::TouchCancel),
             "touchstart" => Ok(EventName::TouchStart),
             "touchmove" => Ok(EventName::TouchMove),
@@ -79,6 +115,11 @@ impl FromStr for EventName {
             "filedrop" => Ok(EventName::FileDrop),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "dragover" => Ok(EventName::DragOver),
+            "dragleave" => Ok(EventName::DragLeave),
+            "drop" => Ok(EventName::Drop),
+            "globaldragover" => Ok(EventName::GlobalDragOver),
+            "globaldragend" => Ok(EventName::GlobalDragEnd),
             "captureglobalmousemove" => Ok(EventName::CaptureGlobalMouseMove),
             "captureglobalpointerup" => Ok(EventName::CaptureGlobalPointerUp),
             _ => Err(()),
@@ -92,12 +133,15 @@ impl From<EventName> for &str {
             EventName::Click => "click",
             EventName::MiddleClick => "middleclick",
             EventName::RightClick => "rightclick",
+            EventName::DoubleClick => "doubleclick",
             EventName::MouseUp => "mouseup",
             EventName::MouseDown => "mousedown",
             EventName::MouseMove => "mousemove",
             EventName::MouseEnter => "mouseenter",
             EventName::MouseLeave => "mouseleave",
             EventName::Wheel => "wheel",
+            EventName::Pinch => "pinch",
+            EventName::Rotate => "rotate",
             EventName::PointerOver => "pointermove",
             EventName::PointerDown => "pointerdown",
             EventName::PointerEnter => "pointerenter",
@@ -108,6 +152,16 @@ impl From<EventName> for &str {
             EventName::KeyDown => "keydown",
             EventName::GlobalKeyDown => "globalkeydown",
             EventName::GlobalKeyUp => "globalkeyup",
+            EventName::CompositionStart => "compositionstart",
+            EventName::CompositionUpdate => "compositionupdate",
+            EventName::CompositionEnd => "compositionend",
+            EventName::Focus => "focus",
+            EventName::Blur => "blur",
+            EventName::FocusIn => "focusin",
+            EventName::FocusOut => "focusout",
+            EventName::Layout => "layout",
+            EventName::Resize => "resize",
+            EventName::Scroll => "scroll",
             EventName::TouchCancel => "touchcancel",
             EventName::TouchStart => "touchstart",
             EventName::TouchMove => "touchmove",
@@ -119,6 +173,11 @@ impl From<EventName> for &str {
             EventName::FileDrop => "filedrop",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::DragOver => "dragover",
+            EventName::DragLeave => "dragleave",
+            EventName::Drop => "drop",
+            EventName::GlobalDragOver => "globaldragover",
+            EventName::GlobalDragEnd => "globaldragend",
             EventName::CaptureGlobalMouseMove => "captureglobalmousemove",
             EventName::CaptureGlobalPointerUp => "captureglobalpointerup",
         }
@@ -177,9 +236,17 @@ impl EventName {
         )
     }
 
+    /// Check if the event is emitted after measuring the layout.
+    pub fn is_layout(&self) -> bool {
+        matches!(&self, Self::Layout | Self::Resize | Self::Scroll)
+    }
+
     /// Check if the event means the cursor has left.
     pub fn is_left(&self) -> bool {
-        matches!(&self, Self::MouseLeave | Self::PointerLeave)
+        matches!(
+            &self,
+            Self::MouseLeave | Self::PointerLeave | Self::DragLeave
+        )
     }
 }
 
@@ -193,6 +260,8 @@ impl ragnarok::NameOfEvent for EventName {
             Self::MouseMove => events.extend([Self::GlobalMouseMove, Self::CaptureGlobalMouseMove]),
             Self::GlobalFileHover => events.push(Self::GlobalFileHover),
             Self::GlobalFileHoverCancelled => events.push(Self::GlobalFileHoverCancelled),
+            Self::DragOver => events.push(Self::GlobalDragOver),
+            Self::GlobalDragEnd => events.push(Self::GlobalDragEnd),
             Self::KeyDown => events.push(Self::GlobalKeyDown),
             Self::KeyUp => events.push(Self::GlobalKeyUp),
             _ => {}
@@ -211,7 +280,13 @@ impl ragnarok::NameOfEvent for EventName {
             }
             Self::TouchMove => events.extend([Self::PointerEnter, Self::PointerOver]),
             Self::MouseDown | Self::TouchStart => events.push(Self::PointerDown),
-            Self::MouseUp | Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
+            Self::MouseUp => events.extend([
+                Self::Click,
+                Self::PointerUp,
+                Self::PointerPress,
+                Self::DoubleClick,
+            ]),
+            Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
                 events.extend([Self::Click, Self::PointerUp, Self::PointerPress])
             }
             Self::MouseLeave => events.push(Self::PointerLeave),
@@ -236,6 +311,7 @@ impl ragnarok::NameOfEvent for EventName {
 
             Self::PointerUp => events.extend([
                 Self::Click,
+                Self::DoubleClick,
                 Self::MiddleClick,
                 Self::GlobalClick,
                 Self::GlobalPointerUp,
@@ -254,6 +330,7 @@ impl ragnarok::NameOfEvent for EventName {
 
             Self::CaptureGlobalPointerUp => events.extend([
                 Self::Click,
+                Self::DoubleClick,
                 Self::GlobalClick,
                 Self::PointerUp,
                 Self::PointerPress,
@@ -280,11 +357,13 @@ impl ragnarok::NameOfEvent for EventName {
                 | Self::GlobalMouseMove
                 | Self::GlobalFileHover
                 | Self::GlobalFileHoverCancelled
+                | Self::GlobalDragOver
+                | Self::GlobalDragEnd
         )
     }
 
     fn is_moved(&self) -> bool {
-        matches!(&self, Self::MouseMove | Self::PointerEnter)
+        matches!(&self, Self::MouseMove | Self::PointerEnter | Self::DragOver)
     }
 
     fn does_bubble(&self) -> bool {
@@ -293,12 +372,20 @@ impl ragnarok::NameOfEvent for EventName {
             && !self.is_left()
             && !self.is_global()
             && !self.is_capture()
+            && !self.is_layout()
+            && !matches!(self, Self::Focus | Self::Blur)
     }
 
     fn does_go_through_solid(&self) -> bool {
         matches!(
             self,
-            Self::KeyDown | Self::KeyUp | Self::GlobalKeyDown | Self::GlobalKeyUp
+            Self::KeyDown
+                | Self::KeyUp
+                | Self::GlobalKeyDown
+                | Self::GlobalKeyUp
+                | Self::CompositionStart
+                | Self::CompositionUpdate
+                | Self::CompositionEnd
         )
     }
 
@@ -307,10 +394,24 @@ impl ragnarok::NameOfEvent for EventName {
     }
 
     fn is_released(&self) -> bool {
-        matches!(&self, Self::Click | Self::PointerPress)
+        matches!(&self, Self::Click | Self::DoubleClick | Self::PointerPress)
+    }
+
+    fn required_click_count(&self) -> Option<u32> {
+        match self {
+            Self::DoubleClick => Some(2),
+            _ => None,
+        }
     }
 
     fn new_leave() -> Self {
         Self::MouseLeave
     }
+
+    fn leave_event(&self) -> Self {
+        match self {
+            Self::DragOver => Self::DragLeave,
+            _ => Self::new_leave(),
+        }
+    }
 }
@@ -2,11 +2,13 @@
 
 use std::{
     any::Any,
+    collections::VecDeque,
     fmt::Debug,
 };
 
+use indexmap::IndexMap;
 use rustc_hash::{
-    FxHashMap,
+    FxBuildHasher,
     FxHashSet,
 };
 use shipyard::Component;
@@ -22,10 +24,92 @@ use crate::{
 pub struct ElementNode<V: FromAnyValue = ()> {
     /// The tag name of the element
     pub tag: TagName,
-    /// The attributes of the element
-    pub attributes: FxHashMap<AttributeName, OwnedAttributeValue<V>>,
+    /// The attributes of the element, in the order they were declared
+    pub attributes: IndexMap<AttributeName, OwnedAttributeValue<V>, FxBuildHasher>,
     /// The events the element is listening for
     pub listeners: FxHashSet<EventName>,
+    /// Position each attribute was declared in, in the same order as `attributes`
+    attribute_positions: Vec<usize>,
+    /// Position the dynamic attributes that have not been set yet were declared in
+    pending_positions: VecDeque<usize>,
+}
+
+impl<V: FromAnyValue> ElementNode<V> {
+    /// Create an element without attributes or listeners
+    pub fn new(tag: TagName) -> Self {
+        Self {
+            tag,
+            attributes: IndexMap::default(),
+            listeners: FxHashSet::default(),
+            attribute_positions: Vec::new(),
+            pending_positions: VecDeque::new(),
+        }
+    }
+
+    /// Declare a static attribute at the given position
+    pub fn declare_attribute(
+        &mut self,
+        position: usize,
+        name: AttributeName,
+        value: OwnedAttributeValue<V>,
+    ) {
+        self.insert_attribute(position, name, value);
+    }
+
+    /// Declare a dynamic attribute at the given position, it will be placed there once it's set
+    pub fn declare_dynamic_attribute(&mut self, position: usize) {
+        self.pending_positions.push_back(position);
+    }
+
+    /// Set an attribute, new attributes take the position of the next dynamic attribute that was declared
+    pub fn set_attribute(
+        &mut self,
+        name: AttributeName,
+        value: OwnedAttributeValue<V>,
+    ) -> Option<OwnedAttributeValue<V>> {
+        if let Some(current) = self.attributes.get_mut(&name) {
+            return Some(std::mem::replace(current, value));
+        }
+        let position = self.pending_positions.pop_front().unwrap_or(usize::MAX);
+        self.insert_attribute(position, name, value);
+        None
+    }
+
+    /// Remove an attribute
+    pub fn remove_attribute(&mut self, name: &AttributeName) -> Option<OwnedAttributeValue<V>> {
+        match self.attributes.shift_remove_full(name) {
+            Some((index, _, value)) => {
+                self.attribute_positions.remove(index);
+                Some(value)
+            }
+            None => {
+                // A dynamic attribute that was declared without a value
+                self.pending_positions.pop_front();
+                None
+            }
+        }
+    }
+
+    /// Add an event listener, new listeners take the position of the next dynamic attribute that was declared
+    pub fn add_listener(&mut self, event: EventName) {
+        if self.listeners.insert(event) {
+            self.pending_positions.pop_front();
+        }
+    }
+
+    fn insert_attribute(
+        &mut self,
+        position: usize,
+        name: AttributeName,
+        value: OwnedAttributeValue<V>,
+    ) {
+        let index = self
+            .attribute_positions
+            .partition_point(|declared| *declared <= position)
+            .min(self.attributes.len());
+        self.attribute_positions.insert(index, position);
+        self.attributes.shift_insert(index, name, value);
+    }
 }
 
 /// A type of node with data specific to the node type.
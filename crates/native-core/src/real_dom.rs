@@ -199,11 +199,7 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
         let workload = construct_workload(&mut tracked_states);
         let (workload, _) = workload.build().unwrap();
         let mut world = World::new();
-        let root_node: NodeType<V> = NodeType::Element(ElementNode {
-            tag: TagName::Root,
-            attributes: FxHashMap::default(),
-            listeners: FxHashSet::default(),
-        });
+        let root_node: NodeType<V> = NodeType::Element(ElementNode::new(TagName::Root));
         let root_id: NodeId = world.add_entity(root_node).into();
         {
             let mut tree: TreeMutView = world.borrow::<TreeMutView>().unwrap();
@@ -668,9 +664,9 @@ impl<V: FromAnyValue + Send + Sync> NodeMut<'_, V> {
         } = &mut self.dom;
         let mut view = world.borrow::<ViewMut<NodeType<V>>>().unwrap();
         let node_type: &mut NodeType<V> = &mut view[id.into()];
-        if let NodeType::Element(ElementNode { listeners, .. }) = node_type {
+        if let NodeType::Element(element) = node_type {
             dirty_nodes.mark_dirty(self.id, NodeMaskBuilder::new().with_listeners().build());
-            listeners.insert(event);
+            element.add_listener(event);
             match nodes_listening.get_mut(&event) {
                 Some(hs) => {
                     hs.insert(id);
@@ -850,7 +846,7 @@ impl<V: FromAnyValue + Send + Sync> ElementNodeMut<'_, V> {
                 .with_attrs(AttributeMaskBuilder::Some(&[name]))
                 .build(),
         );
-        self.element_mut().attributes.insert(name, value)
+        self.element_mut().set_attribute(name, value)
     }
 
     /// Remove an attribute from the element
@@ -861,7 +857,7 @@ impl<V: FromAnyValue + Send + Sync> ElementNodeMut<'_, V> {
                 .with_attrs(AttributeMaskBuilder::Some(&[*name]))
                 .build(),
         );
-        self.element_mut().attributes.remove(name)
+        self.element_mut().remove_attribute(name)
     }
 
     /// Get an attribute of the element
@@ -0,0 +1,111 @@
+use freya_native_core::{
+    events::EventName,
+    node::OwnedAttributeValue,
+    prelude::*,
+    tags::TagName,
+};
+
+fn attribute_names(element: &ElementNode) -> Vec<AttributeName> {
+    element.attributes.keys().copied().collect()
+}
+
+#[test]
+fn attributes_keep_declaration_order() {
+    // rect { rotate: "90deg", translate: "{x}", scale: "2", skew: "{y}" }
+    let mut element = ElementNode::new(TagName::Rect);
+    element.declare_attribute(
+        0,
+        AttributeName::Rotate,
+        OwnedAttributeValue::Text("90deg".to_string()),
+    );
+    element.declare_dynamic_attribute(1);
+    element.declare_attribute(
+        2,
+        AttributeName::Scale,
+        OwnedAttributeValue::Text("2".to_string()),
+    );
+    element.declare_dynamic_attribute(3);
+
+    element.set_attribute(
+        AttributeName::Translate,
+        OwnedAttributeValue::Text("20".to_string()),
+    );
+    element.set_attribute(
+        AttributeName::Skew,
+        OwnedAttributeValue::Text("10deg".to_string()),
+    );
+
+    assert_eq!(
+        attribute_names(&element),
+        vec![
+            AttributeName::Rotate,
+            AttributeName::Translate,
+            AttributeName::Scale,
+            AttributeName::Skew
+        ]
+    );
+
+    // Updating or removing an attribute doesn't move the others
+    element.set_attribute(
+        AttributeName::Translate,
+        OwnedAttributeValue::Text("40".to_string()),
+    );
+    element.remove_attribute(&AttributeName::Scale);
+
+    assert_eq!(
+        attribute_names(&element),
+        vec![
+            AttributeName::Rotate,
+            AttributeName::Translate,
+            AttributeName::Skew
+        ]
+    );
+}
+
+#[test]
+fn dynamic_attributes_without_value_keep_their_position() {
+    // rect { translate: None, rotate: "{r}", scale: "2" }
+    let mut element = ElementNode::new(TagName::Rect);
+    element.declare_dynamic_attribute(0);
+    element.declare_dynamic_attribute(1);
+    element.declare_attribute(
+        2,
+        AttributeName::Scale,
+        OwnedAttributeValue::Text("2".to_string()),
+    );
+
+    element.remove_attribute(&AttributeName::Translate);
+    element.set_attribute(
+        AttributeName::Rotate,
+        OwnedAttributeValue::Text("90deg".to_string()),
+    );
+
+    assert_eq!(
+        attribute_names(&element),
+        vec![AttributeName::Rotate, AttributeName::Scale]
+    );
+}
+
+#[test]
+fn listeners_take_their_declared_position() {
+    // rect { onclick, rotate: "{r}", scale: "2" }
+    let mut element = ElementNode::new(TagName::Rect);
+    element.declare_dynamic_attribute(0);
+    element.declare_dynamic_attribute(1);
+    element.declare_attribute(
+        2,
+        AttributeName::Scale,
+        OwnedAttributeValue::Text("2".to_string()),
+    );
+
+    element.add_listener(EventName::Click);
+    element.set_attribute(
+        AttributeName::Rotate,
+        OwnedAttributeValue::Text("90deg".to_string()),
+    );
+
+    assert_eq!(
+        attribute_names(&element),
+        vec![AttributeName::Rotate, AttributeName::Scale]
+    );
+}
@@ -4,18 +4,10 @@ use freya_native_core::{
     tags::TagName,
 };
 use freya_native_core_macro::partial_derive_state;
-use rustc_hash::{
-    FxHashMap,
-    FxHashSet,
-};
 use shipyard::Component;
 
 fn create_blank_element() -> NodeType {
-    NodeType::Element(ElementNode {
-        tag: TagName::Rect,
-        attributes: FxHashMap::default(),
-        listeners: FxHashSet::default(),
-    })
+    NodeType::Element(ElementNode::new(TagName::Rect))
 }
 
 #[test]
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::{
+    CursorPoint,
+    NameOfEvent,
+    SourceEvent,
+};
+
+/// Thresholds within which consecutive presses are counted as a single multi click, e.g a double click.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClickCountConfig {
+    /// Maximum time between two presses.
+    pub max_interval: Duration,
+    /// Maximum distance between two presses.
+    pub max_distance: f64,
+}
+
+impl Default for ClickCountConfig {
+    fn default() -> Self {
+        Self {
+            max_interval: Duration::from_millis(500),
+            max_distance: 4.0,
+        }
+    }
+}
+
+impl ClickCountConfig {
+    pub fn new(max_interval: Duration, max_distance: f64) -> Self {
+        Self {
+            max_interval,
+            max_distance,
+        }
+    }
+}
+
+/// Identifier of the primary button, see [`SourceEvent::try_button`].
+pub const PRIMARY_BUTTON: u16 = 0;
+
+/// [`ClickCounter`] counts the consecutive presses of the pointer device.
+/// Only the presses of the primary button, or those without a button such as touches, are counted as multi clicks.
+#[derive(Default)]
+pub struct ClickCounter {
+    config: ClickCountConfig,
+    last_press: Option<(Instant, CursorPoint, Option<u16>)>,
+    count: u32,
+}
+
+impl ClickCounter {
+    pub fn new(config: ClickCountConfig) -> Self {
+        Self {
+            config,
+            ..Default::default()
+        }
+    }
+
+    /// Get the click count of every source event.
+    /// Press events continue or start a new series of clicks, release events have the click count of the press they release
+    /// if it was of the same button and the rest of events have no click count.
+    pub fn count<Source: SourceEvent>(&mut self, source_events: &[Source]) -> Vec<u32> {
+        let now = Instant::now();
+        source_events
+            .iter()
+            .map(|source_event| {
+                if source_event.is_pressed() {
+                    match source_event.try_cursor() {
+                        Some(cursor) => self.press(cursor, source_event.try_button(), now),
+                        None => 0,
+                    }
+                } else if source_event
+                    .as_event_name()
+                    .get_derived_events()
+                    .iter()
+                    .any(|event| event.is_released())
+                    && self
+                        .last_press
+                        .is_some_and(|(_, _, button)| button == source_event.try_button())
+                {
+                    self.count
+                } else {
+                    0
+                }
+            })
+            .collect()
+    }
+
+    /// Register a press of the given button in the given cursor and time, returning its click count.
+    pub fn press(&mut self, cursor: CursorPoint, button: Option<u16>, time: Instant) -> u32 {
+        let is_consecutive =
+            self.last_press
+                .is_some_and(|(last_time, last_cursor, last_button)| {
+                    last_button == button
+                        && button.is_none_or(|button| button == PRIMARY_BUTTON)
+                        && time.saturating_duration_since(last_time) <= self.config.max_interval
+                        && cursor.distance_to(last_cursor) <= self.config.max_distance
+                });
+
+        self.count = if is_consecutive { self.count + 1 } else { 1 };
+        self.last_press = Some((time, cursor, button));
+
+        self.count
+    }
+}
@@ -6,6 +6,7 @@
 //! that are sent to the consumer of **Raganarok** and ultimately if an `Emmitable` event is cancelled
 //! this will discard some of the yet-to-emit `Emmitable` events and possibly affect the internal state of the nodes.
 
+pub mod click_count;
 pub mod emmitable;
 pub mod executor;
 pub mod key;
@@ -16,6 +17,7 @@ pub mod nodes_state;
 pub mod potential_event;
 pub mod source;
 
+pub use click_count::*;
 pub use emmitable::*;
 pub use executor::*;
 pub use key::*;
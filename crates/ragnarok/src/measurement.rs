@@ -10,6 +10,12 @@ use crate::{
     SourceEvent,
 };
 
+/// Check if an event can be emitted given the click count of its source event.
+fn is_click_count_met<Name: NameOfEvent>(name: &Name, click_count: u32) -> bool {
+    name.required_click_count()
+        .is_none_or(|required_click_count| required_click_count == click_count)
+}
+
 /// For every source event and its derivated events, a global equivalent event is emitted.
 pub fn measure_source_global_events<
     Key: NodeKey,
@@ -23,15 +29,20 @@ pub fn measure_source_global_events<
         Emmitable = Emmitable,
         Source = Source,
     >,
-    source_events: &Vec<Source>,
+    source_events: &[Source],
+    click_counts: &[u32],
     emmitable_events: &mut Vec<Emmitable>,
 ) {
-    for source_event in source_events {
+    for (source_event, click_count) in source_events.iter().zip(click_counts) {
         let event_name = source_event.as_event_name();
         let derived_events_names = event_name.get_derived_events();
 
         for derived_event_name in derived_events_names {
             for global_event_name in derived_event_name.get_global_events() {
+                if !is_click_count_met(&global_event_name, *click_count) {
+                    continue;
+                }
+
                 let listeners = events_measurer.get_listeners_of(&global_event_name);
 
                 for listener in listeners {
@@ -40,6 +51,7 @@ pub fn measure_source_global_events<
                         global_event_name,
                         source_event.clone(),
                         None,
+                        *click_count,
                     );
                     emmitable_events.push(event)
                 }
@@ -55,7 +67,8 @@ pub fn measure_potential_events<
     Source: SourceEvent<Name = Name>,
     Emmitable: EmmitableEvent<Key = Key, Name = Name>,
 >(
-    source_events: &Vec<Source>,
+    source_events: &[Source],
+    click_counts: &[u32],
     events_measurer: &impl EventsMeasurer<
         Key = Key,
         Name = Name,
@@ -69,7 +82,7 @@ pub fn measure_potential_events<
     // Walk layer by layer from the bottom to the top
     for (layer, layer_nodes) in sorted(events_measurer.get_layers()) {
         for node_id in layer_nodes.iter() {
-            for source_event in source_events {
+            for (source_event, click_count) in source_events.iter().zip(click_counts) {
                 let Some(cursor) = source_event.try_cursor() else {
                     if focus_id == Some(*node_id) {
                         let potential_event = PotentialEvent {
@@ -77,6 +90,7 @@ pub fn measure_potential_events<
                             layer: *layer,
                             name: source_event.as_event_name(),
                             source_event: source_event.clone(),
+                            click_count: *click_count,
                         };
                         potential_events
                             .entry(source_event.as_event_name())
@@ -95,6 +109,7 @@ pub fn measure_potential_events<
                     layer: *layer,
                     name: source_event.as_event_name(),
                     source_event: source_event.clone(),
+                    click_count: *click_count,
                 };
 
                 potential_events
@@ -141,6 +156,7 @@ pub fn measure_emmitable_events<
                 node_key: node_id,
                 name,
                 source_event,
+                click_count,
                 ..
             } in potential_events.iter().rev()
             {
@@ -150,7 +166,9 @@ pub fn measure_emmitable_events<
                     }
                 }
 
-                if events_measurer.is_listening_to(*node_id, &derived_event_name) {
+                if events_measurer.is_listening_to(*node_id, &derived_event_name)
+                    && is_click_count_met(&derived_event_name, *click_count)
+                {
                     let area = events_measurer.try_area_of(*node_id);
                     if let Some(area) = area {
                         let emmitable_event = events_measurer.new_emmitable_event(
@@ -158,6 +176,7 @@ pub fn measure_emmitable_events<
                             derived_event_name,
                             source_event.clone(),
                             Some(area),
+                            *click_count,
                         );
                         emmitable_events.push(emmitable_event);
 
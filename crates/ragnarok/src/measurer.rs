@@ -41,6 +41,7 @@ where
         name: Self::Name,
         source: Self::Source,
         area: Option<Area>,
+        click_count: u32,
     ) -> Self::Emmitable;
 }
 
@@ -57,13 +58,16 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         nodes_state: &mut NodesState<Self::Key>,
         focus_id: Option<Self::Key>,
     ) -> ProcessedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source> {
+        // Count the consecutive clicks, e.g. a double click
+        let click_counts = nodes_state.count_clicks(source_events);
+
         // Get potential events that could be emitted based on the elements layout and viewports
         let potential_events = measure_potential_events::<
             Self::Key,
             Self::Name,
             Self::Source,
             Self::Emmitable,
-        >(source_events, self, focus_id);
+        >(source_events, &click_counts, self, focus_id);
 
         // Get what events can be actually emitted based on what elements are listening
         let mut emmitable_events =
@@ -83,6 +87,7 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         measure_source_global_events::<Self::Key, Self::Name, Self::Source, Self::Emmitable>(
             self,
             source_events,
+            &click_counts,
             &mut emmitable_events,
         );
         // Join all the emmitable events and sort them
@@ -20,12 +20,22 @@ pub trait NameOfEvent:
     /// Create a new event that means the pointer device left a hovering node.
     fn new_leave() -> Self;
 
+    /// Get the event that means the pointer device left a node that was hovered by this movement event.
+    /// For example, a drag movement leaves with a drag leave instead of a mouse leave.
+    fn leave_event(&self) -> Self {
+        Self::new_leave()
+    }
+
     /// Get a set of events derived from this event. For example, mouse movement derives into mouse movement + mouse enter.
     fn get_derived_events(&self) -> Vec<Self>;
     /// Get a set of global events derived from this event.
     fn get_global_events(&self) -> Vec<Self> {
         Vec::new()
     }
+    /// Get how many consecutive clicks the source event must have for this event to be emitted, e.g `2` for a double click.
+    fn required_click_count(&self) -> Option<u32> {
+        None
+    }
     /// Get a set of events that will be discarded once this event is cancelled.
     fn get_cancellable_events(&self) -> Vec<Self> {
         vec![*self]
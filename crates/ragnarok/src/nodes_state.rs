@@ -6,6 +6,8 @@ use rustc_hash::{
 };
 
 use crate::{
+    ClickCountConfig,
+    ClickCounter,
     EmmitableEvent,
     EventsMeasurer,
     NameOfEvent,
@@ -19,18 +21,36 @@ use crate::{
 pub struct NodesState<Key: NodeKey> {
     pressed_nodes: FxHashSet<Key>,
     hovered_nodes: FxHashSet<Key>,
+    click_counter: ClickCounter,
 }
 
 pub type PotentialEvents<Key, Name, Source> =
     FxHashMap<Name, Vec<PotentialEvent<Key, Name, Source>>>;
 
 impl<Key: NodeKey> NodesState<Key> {
+    /// Create a new [`NodesState`] that counts the consecutive clicks with the given [`ClickCountConfig`].
+    pub fn new(click_count_config: ClickCountConfig) -> Self {
+        Self {
+            pressed_nodes: FxHashSet::default(),
+            hovered_nodes: FxHashSet::default(),
+            click_counter: ClickCounter::new(click_count_config),
+        }
+    }
+
+    /// Get the click count of every source event, see [`ClickCounter::count`].
+    pub(crate) fn count_clicks<Source: SourceEvent>(
+        &mut self,
+        source_events: &[Source],
+    ) -> Vec<u32> {
+        self.click_counter.count(source_events)
+    }
+
     /// Retain or not the states of the nodes given the [EmmitableEvent]s and based on the sideffects of these removals
     /// a set of [PotentialEvent]s are returned.
     pub(crate) fn retain_states<
         Emmitable: EmmitableEvent<Key = Key, Name = Name>,
         Name: NameOfEvent,
-        Source: SourceEvent,
+        Source: SourceEvent<Name = Name>,
     >(
         &mut self,
         events_measurer: &impl EventsMeasurer<
@@ -83,8 +103,8 @@ impl<Key: NodeKey> NodesState<Key> {
                 // the user does no longer want to hover this Node
                 if let Some(source_event) = source_movement_event {
                     if let Some(area) = events_measurer.try_area_of(*node_key) {
-                        // Emit a MouseLeave event as the cursor was moved outside the Node bounds
-                        let event = Name::new_leave();
+                        // Emit a leave event (e.g MouseLeave) as the cursor was moved outside the Node bounds
+                        let event = source_event.as_event_name().leave_event();
                         for derived_event in event.get_derived_events() {
                             let is_node_listening =
                                 events_measurer.is_listening_to(*node_key, &derived_event);
@@ -95,6 +115,7 @@ impl<Key: NodeKey> NodesState<Key> {
                                         derived_event,
                                         source_event.clone(),
                                         Some(area),
+                                        0,
                                     ),
                                 );
                             }
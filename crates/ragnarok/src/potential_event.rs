@@ -10,6 +10,7 @@ pub struct PotentialEvent<Key: NodeKey, Name: NameOfEvent, Source: SourceEvent>
     pub node_key: Key,
     pub name: Name,
     pub source_event: Source,
+    pub click_count: u32,
     pub layer: i16,
 }
 
@@ -11,5 +11,11 @@ pub trait SourceEvent: Clone + PartialEq {
 
     fn try_cursor(&self) -> Option<CursorPoint>;
 
+    /// Get an identifier of the button that triggered this event, e.g. the mouse button, where `0` is the primary button.
+    /// Presses of different buttons are never counted as consecutive clicks.
+    fn try_button(&self) -> Option<u16> {
+        None
+    }
+
     fn as_event_name(&self) -> Self::Name;
 }
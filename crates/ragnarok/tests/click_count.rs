@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use ragnarok::{
+    ClickCountConfig,
+    ClickCounter,
+};
+use tokio::time::Instant;
+
+#[test]
+fn consecutive_presses() {
+    let mut click_counter = ClickCounter::default();
+    let now = Instant::now();
+
+    assert_eq!(click_counter.press((10., 10.).into(), None, now), 1);
+    assert_eq!(
+        click_counter.press((11., 12.).into(), None, now + Duration::from_millis(200)),
+        2
+    );
+    assert_eq!(
+        click_counter.press((10., 10.).into(), None, now + Duration::from_millis(400)),
+        3
+    );
+}
+
+#[test]
+fn presses_out_of_threshold() {
+    let mut click_counter =
+        ClickCounter::new(ClickCountConfig::new(Duration::from_millis(300), 10.));
+    let now = Instant::now();
+
+    assert_eq!(click_counter.press((10., 10.).into(), None, now), 1);
+
+    // Too late
+    let now = now + Duration::from_millis(400);
+    assert_eq!(click_counter.press((10., 10.).into(), None, now), 1);
+
+    // Too far
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((50., 10.).into(), None, now), 1);
+
+    // Within both thresholds
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((55., 15.).into(), None, now), 2);
+}
+
+#[test]
+fn presses_of_different_buttons() {
+    let mut click_counter = ClickCounter::default();
+    let now = Instant::now();
+    let (left, right) = (Some(0), Some(1));
+
+    assert_eq!(click_counter.press((10., 10.).into(), left, now), 1);
+
+    // Pressing another button starts a new count
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((10., 10.).into(), right, now), 1);
+
+    // And so does pressing the first button again
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((10., 10.).into(), left, now), 1);
+
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((10., 10.).into(), left, now), 2);
+}
+
+#[test]
+fn presses_of_secondary_buttons() {
+    let mut click_counter = ClickCounter::default();
+    let now = Instant::now();
+    let back = Some(3);
+
+    // Only the primary button counts multi clicks
+    assert_eq!(click_counter.press((10., 10.).into(), back, now), 1);
+    let now = now + Duration::from_millis(100);
+    assert_eq!(click_counter.press((10., 10.).into(), back, now), 1);
+}
@@ -25,6 +25,7 @@ enum EventName {
     MouseLeave,
     MouseDown,
     MouseUp,
+    DoubleClick,
 
     KeyboardDown,
 
@@ -79,7 +80,7 @@ impl NameOfEvent for EventName {
     }
 
     fn is_released(&self) -> bool {
-        matches!(self, Self::MouseUp)
+        matches!(self, Self::MouseUp | Self::DoubleClick)
     }
 
     fn is_global(&self) -> bool {
@@ -103,12 +104,20 @@ impl NameOfEvent for EventName {
         #[allow(clippy::single_match)]
         match self {
             Self::MouseMove => events.push(Self::MouseEnter),
+            Self::MouseUp => events.push(Self::DoubleClick),
             _ => {}
         }
 
         events
     }
 
+    fn required_click_count(&self) -> Option<u32> {
+        match self {
+            Self::DoubleClick => Some(2),
+            _ => None,
+        }
+    }
+
     fn get_global_events(&self) -> Vec<Self> {
         match self {
             Self::MouseMove => vec![Self::CaptureGlobalMouseMove],
@@ -306,6 +315,7 @@ impl EventsMeasurer for TestMeasurer {
         name: Self::Name,
         source: Self::Source,
         _area: Option<ragnarok::Area>,
+        _click_count: u32,
     ) -> Self::Emmitable {
         TestEmmitableEvent {
             key,
@@ -562,3 +572,45 @@ fn cancel_emmited_events_on_capture() {
     // Assert that the node is not being hovvered as the event was can cancelled
     assert!(!nodes_state.is_hovered(0));
 }
+
+#[test]
+fn double_click_event() {
+    let mut test_measurer = TestMeasurer::default();
+    let mut nodes_state = NodesState::default();
+
+    test_measurer.add(0, None, 0, Area::new((0., 0.).into(), (100., 100.).into()));
+    test_measurer.listen_to(0, EventName::MouseUp);
+    test_measurer.listen_to(0, EventName::DoubleClick);
+
+    // Click the node three times in a row
+    for i in 0..3 {
+        let processed_events = test_measurer.run(
+            &mut vec![TestSourceEvent::MouseDown {
+                cursor: (25., 25.).into(),
+            }],
+            &mut nodes_state,
+            None,
+        );
+        TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+
+        let processed_events = test_measurer.run(
+            &mut vec![TestSourceEvent::MouseUp {
+                cursor: (25., 25.).into(),
+            }],
+            &mut nodes_state,
+            None,
+        );
+
+        let double_click = TestEmmitableEvent {
+            key: 0,
+            name: EventName::DoubleClick,
+            source: EventName::MouseUp,
+        };
+        // Assert a double click event is only emitted on the second click
+        assert_eq!(
+            processed_events.emmitable_events.contains(&double_click),
+            i == 1
+        );
+        TestExecutor::without_handler().run(&mut nodes_state, processed_events);
+    }
+}
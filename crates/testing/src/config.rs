@@ -8,6 +8,9 @@ pub struct TestingConfig<T: 'static + Clone> {
     pub vdom_timeout: Duration,
     pub size: Size2D,
     pub event_loop_ticker: bool,
+    /// Pause the Tokio clock and only let time pass with [`crate::test_handler::TestingHandler::advance`].
+    /// This requires the test to run in a `current_thread` runtime, which is the default of [`tokio::test`]. An already paused clock is left as is.
+    pub virtual_clock: bool,
     pub state: Option<T>,
 }
 
@@ -17,6 +20,7 @@ impl<T: 'static + Clone> Default for TestingConfig<T> {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
+            virtual_clock: false,
             state: None,
         }
     }
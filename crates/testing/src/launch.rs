@@ -47,12 +47,17 @@ use freya_elements as dioxus_elements;
 use freya_engine::prelude::*;
 use ragnarok::NodesState;
 use tokio::{
-    runtime::Runtime,
+    runtime::{
+        Handle,
+        Runtime,
+        RuntimeFlavor,
+    },
     sync::{
         broadcast,
         mpsc::unbounded_channel,
         watch,
     },
+    time,
 };
 use torin::prelude::Size2D;
 
@@ -98,11 +103,33 @@ pub fn launch_test(root: AppComponent) -> TestingHandler<()> {
     launch_test_with_config(root, TestingConfig::default())
 }
 
+/// A paused clock only moves when it is advanced, so it stays still while the thread sleeps.
+fn is_clock_paused() -> bool {
+    let start = time::Instant::now();
+    std::thread::sleep(std::time::Duration::from_millis(1));
+    start.elapsed().is_zero()
+}
+
 /// Run a Component in a headless testing environment
 pub fn launch_test_with_config<T: 'static + Clone>(
     root: AppComponent,
     config: TestingConfig<T>,
 ) -> TestingHandler<T> {
+    if config.virtual_clock {
+        let runtime = Handle::try_current().expect(
+            "The virtual clock requires a Tokio runtime, run the test with #[tokio::test].",
+        );
+        assert_eq!(
+            runtime.runtime_flavor(),
+            RuntimeFlavor::CurrentThread,
+            "The virtual clock requires a `current_thread` Tokio runtime, which is the default of #[tokio::test]."
+        );
+        // Tests started with #[tokio::test(start_paused = true)] already have the clock paused
+        if !is_clock_paused() {
+            time::pause();
+        }
+    }
+
     let vdom = with_accessibility(root);
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
@@ -130,13 +157,19 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         font_mgr,
         event_emitter,
         event_receiver,
-        utils: TestUtils { sdom },
+        utils: TestUtils {
+            sdom,
+            platform_receiver: platform_receiver.clone(),
+        },
         config,
         platform_event_emitter,
         platform_event_receiver,
         accessibility_tree: AccessibilityTree::new(ACCESSIBILITY_ROOT_ID),
         ticker_sender: broadcast::channel(5).0,
         cursor_icon: CursorIcon::default(),
+        drag_payload: None,
+        frame_requested: false,
+        dispatched_events: Vec::new(),
         platform_sender,
         platform_receiver,
     };
@@ -159,10 +159,21 @@
 //! assert_eq!(label_text.text(), Some("Hello World!"));
 //! # }
 //! ````
+//!
+//! ## Virtual clock
+//!
+//! `freya-testing` enables the `test-util` feature of `tokio`, so the Tokio clock can be paused and advanced by hand.
+//! Setting [TestingConfig::virtual_clock](crate::config::TestingConfig::virtual_clock) pauses the clock when the test is launched
+//! (unless it is already paused, e.g. with `#[tokio::test(start_paused = true)]`), and [TestingHandler::advance](crate::prelude::TestingHandler::advance)
+//! moves it forward, so timers and animations complete without waiting for real time.
+//!
+//! This requires a `current_thread` runtime, which is the default of [tokio::test]; a multi-threaded runtime panics at launch.
 
 pub mod config;
 pub mod event;
 pub mod launch;
+pub mod query;
+pub mod snapshot;
 pub mod test_handler;
 pub mod test_node;
 pub mod test_utils;
@@ -170,6 +181,7 @@ pub mod test_utils;
 const SCALE_FACTOR: f64 = 1.0;
 
 pub mod prelude {
+    pub use accesskit::Role;
     pub use freya_core::{
         accessibility::*,
         custom_attributes::*,
@@ -180,9 +192,12 @@ pub mod prelude {
     };
 
     pub use crate::{
+        assert_snapshot,
         config::*,
         event::*,
         launch::*,
+        query::*,
+        snapshot::*,
         test_handler::*,
         test_node::*,
         test_utils::*,
@@ -0,0 +1,89 @@
+use accesskit::{
+    NodeId as AccessibilityId,
+    Role,
+};
+
+use crate::test_node::TestNode;
+
+/// Find nodes by what assistive technology sees of them instead of by their position in the DOM.
+///
+/// ```rust, no_run
+/// # use freya_testing::prelude::*;
+/// # use freya::prelude::*;
+/// # let utils = launch_test(|| rsx!( rect { } ));
+/// let button =
+///     utils.get_by_accessibility(&AccessibilityQuery::role(Role::Button).with_name("Submit"));
+/// ```
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct AccessibilityQuery {
+    pub role: Option<Role>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub value: Option<String>,
+    pub id: Option<AccessibilityId>,
+}
+
+impl AccessibilityQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shortcut to create a query matching the given [Role].
+    pub fn role(role: Role) -> Self {
+        Self::new().with_role(role)
+    }
+
+    pub fn with_role(mut self, role: Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn with_id(mut self, id: AccessibilityId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Check if the given node matches all the specified properties.
+    pub fn matches(&self, node: &TestNode) -> bool {
+        if node.a11y_id().is_none() {
+            return false;
+        }
+
+        if self.id.is_some() && node.a11y_id() != self.id {
+            return false;
+        }
+
+        if self.role.is_some() && node.role() != self.role {
+            return false;
+        }
+
+        if self.name.is_some() && node.name() != self.name {
+            return false;
+        }
+
+        if self.description.is_some() && node.description() != self.description {
+            return false;
+        }
+
+        if self.value.is_some() && node.value() != self.value {
+            return false;
+        }
+
+        true
+    }
+}
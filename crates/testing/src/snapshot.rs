@@ -0,0 +1,217 @@
+use std::{
+    env,
+    fmt,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use image::{
+    ImageError,
+    ImageFormat,
+    Rgba,
+    RgbaImage,
+};
+
+/// Environment variable that when set to `1` makes the snapshot assertions write the
+/// rendered output into the golden files instead of comparing against them.
+///
+/// ```sh
+/// FREYA_UPDATE_SNAPSHOTS=1 cargo test
+/// ```
+pub const UPDATE_SNAPSHOTS_ENV: &str = "FREYA_UPDATE_SNAPSHOTS";
+
+/// How much a snapshot can differ from its golden file and still be considered equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SnapshotTolerance {
+    /// Maximum difference allowed in any color channel before a pixel is considered different.
+    pub channel: u8,
+    /// Maximum number of different pixels allowed.
+    pub pixels: usize,
+}
+
+impl SnapshotTolerance {
+    pub fn new(channel: u8, pixels: usize) -> Self {
+        Self { channel, pixels }
+    }
+}
+
+/// Reasons why a snapshot did not match its golden file.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// There is no golden file yet.
+    MissingGolden(PathBuf),
+    /// The snapshot and the golden file have different dimensions.
+    SizeMismatch {
+        expected: (u32, u32),
+        actual: (u32, u32),
+        actual_path: PathBuf,
+    },
+    /// More pixels than the allowed by the [`SnapshotTolerance`] are different.
+    PixelsMismatch {
+        different_pixels: usize,
+        actual_path: PathBuf,
+        diff_path: PathBuf,
+    },
+    Io(io::Error),
+    Image(ImageError),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingGolden(path) => write!(
+                f,
+                "Golden file {path:?} does not exist, run the tests with {UPDATE_SNAPSHOTS_ENV}=1 to create it."
+            ),
+            Self::SizeMismatch {
+                expected,
+                actual,
+                actual_path,
+            } => write!(
+                f,
+                "Expected a snapshot of {expected:?} but got {actual:?}, saved the snapshot in {actual_path:?}."
+            ),
+            Self::PixelsMismatch {
+                different_pixels,
+                actual_path,
+                diff_path,
+            } => write!(
+                f,
+                "{different_pixels} pixels are different, saved the snapshot in {actual_path:?} and the differences in {diff_path:?}."
+            ),
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Image(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ImageError> for SnapshotError {
+    fn from(err: ImageError) -> Self {
+        Self::Image(err)
+    }
+}
+
+/// Compare a PNG encoded snapshot against the golden file in the given path.
+///
+/// If [`UPDATE_SNAPSHOTS_ENV`] is enabled the golden file is overwritten instead.
+/// When they don't match, the snapshot is saved next to the golden file with the `.actual.png` extension,
+/// and an image highlighting in red the different pixels with the `.diff.png` extension.
+pub fn compare_snapshot(
+    snapshot: &[u8],
+    golden_path: &Path,
+    tolerance: SnapshotTolerance,
+) -> Result<(), SnapshotError> {
+    if env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| value == "1") {
+        if let Some(parent) = golden_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(golden_path, snapshot)?;
+        return Ok(());
+    }
+
+    if !golden_path.exists() {
+        return Err(SnapshotError::MissingGolden(golden_path.to_path_buf()));
+    }
+
+    let golden = image::open(golden_path)?.into_rgba8();
+    let actual = image::load_from_memory_with_format(snapshot, ImageFormat::Png)?.into_rgba8();
+
+    let actual_path = golden_path.with_extension("actual.png");
+
+    if golden.dimensions() != actual.dimensions() {
+        fs::write(&actual_path, snapshot)?;
+        return Err(SnapshotError::SizeMismatch {
+            expected: golden.dimensions(),
+            actual: actual.dimensions(),
+            actual_path,
+        });
+    }
+
+    let (diff, different_pixels) = diff_images(&golden, &actual, tolerance.channel);
+
+    if different_pixels > tolerance.pixels {
+        let diff_path = golden_path.with_extension("diff.png");
+        fs::write(&actual_path, snapshot)?;
+        diff.save_with_format(&diff_path, ImageFormat::Png)?;
+        return Err(SnapshotError::PixelsMismatch {
+            different_pixels,
+            actual_path,
+            diff_path,
+        });
+    }
+
+    Ok(())
+}
+
+/// Create an image with the different pixels in red over a faded version of the snapshot.
+fn diff_images(
+    golden: &RgbaImage,
+    actual: &RgbaImage,
+    channel_tolerance: u8,
+) -> (RgbaImage, usize) {
+    let mut different_pixels = 0;
+    let diff = RgbaImage::from_fn(actual.width(), actual.height(), |x, y| {
+        let expected = golden.get_pixel(x, y);
+        let pixel = actual.get_pixel(x, y);
+
+        let is_different = expected
+            .0
+            .iter()
+            .zip(pixel.0)
+            .any(|(a, b)| a.abs_diff(b) > channel_tolerance);
+
+        if is_different {
+            different_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let [r, g, b, _] = pixel.0.map(|c| 255 - (255 - c) / 4);
+            Rgba([r, g, b, 255])
+        }
+    });
+
+    (diff, different_pixels)
+}
+
+/// Assert that the rendered app matches the golden PNG file in the given path,
+/// optionally with a [`SnapshotTolerance`](crate::snapshot::SnapshotTolerance).
+///
+/// Run the tests with [`UPDATE_SNAPSHOTS_ENV`](crate::snapshot::UPDATE_SNAPSHOTS_ENV) set to `1` to create or update the golden files.
+///
+/// ```rust, no_run
+/// # use freya_testing::prelude::*;
+/// # use freya::prelude::*;
+/// # let mut utils = launch_test(|| rsx!( rect { } ));
+/// assert_snapshot!(utils, "./tests/snapshots/rect.png");
+/// assert_snapshot!(
+///     utils,
+///     "./tests/snapshots/rect.png",
+///     SnapshotTolerance::new(2, 10)
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($utils:expr, $path:expr) => {
+        $crate::assert_snapshot!(
+            $utils,
+            $path,
+            $crate::snapshot::SnapshotTolerance::default()
+        )
+    };
+    ($utils:expr, $path:expr, $tolerance:expr) => {
+        if let Err(err) = $utils.compare_snapshot($path, $tolerance) {
+            panic!("Snapshot assertion failed: {err}");
+        }
+    };
+}
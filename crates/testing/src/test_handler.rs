@@ -1,32 +1,43 @@
 use std::{
     fs::File,
     io::Write,
-    path::PathBuf,
+    path::{
+        Path,
+        PathBuf,
+    },
     sync::Arc,
     time::Duration,
 };
 
-use accesskit::NodeId as AccessibilityId;
+use accesskit::{
+    NodeId as AccessibilityId,
+    Role,
+};
 use dioxus_core::VirtualDom;
 use freya_core::{
-    accessibility::AccessibilityTree,
+    accessibility::{
+        AccessibilityFocusStrategy,
+        AccessibilityTree,
+    },
     dom::SafeDOM,
     event_loop_messages::{
         EventLoopMessage,
         EventLoopMessageAction,
     },
     events::{
+        DispatchedEvent,
+        DragEventName,
         EventsExecutorAdapter,
         EventsMeasurerAdapter,
+        InputRecording,
+        KeyboardEventName,
         MouseEventName,
         PlatformEvent,
+        RecordedInput,
     },
     layout::process_layout,
+    offscreen::render_to_surface,
     platform::CursorIcon,
-    render::{
-        Compositor,
-        RenderPipeline,
-    },
     states::AccessibilityState,
     style::fallback_fonts,
     types::{
@@ -38,9 +49,18 @@ use freya_core::{
     },
     values::Color,
 };
-use freya_elements::MouseButton;
+use freya_elements::{
+    events::{
+        keyboard::{
+            Code,
+            Key,
+            Modifiers,
+        },
+        DragPayload,
+    },
+    MouseButton,
+};
 use freya_engine::prelude::{
-    raster_n32_premul,
     Data,
     EncodedImageFormat,
     FontCollection,
@@ -56,6 +76,7 @@ use ragnarok::{
     NodesState,
 };
 use tokio::{
+    select,
     sync::{
         broadcast,
         mpsc::{
@@ -63,7 +84,9 @@ use tokio::{
             UnboundedSender,
         },
     },
+    task::yield_now,
     time::{
+        self,
         interval,
         timeout,
     },
@@ -78,11 +101,23 @@ use torin::{
 
 use crate::{
     config::TestingConfig,
+    query::AccessibilityQuery,
+    snapshot::{
+        compare_snapshot,
+        SnapshotError,
+        SnapshotTolerance,
+    },
     test_node::TestNode,
     test_utils::TestUtils,
     SCALE_FACTOR,
 };
 
+/// Duration of a frame when replaying an [InputRecording] or advancing the virtual clock.
+const FRAME_MS: u64 = 16;
+
+/// How long [TestingHandler::run_until_idle] can advance the virtual clock before giving up.
+const MAX_IDLE_DURATION: Duration = Duration::from_secs(60);
+
 /// Manages the lifecycle of your tests.
 pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) vdom: VirtualDom,
@@ -101,6 +136,9 @@ pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) config: TestingConfig<T>,
     pub(crate) ticker_sender: broadcast::Sender<()>,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) drag_payload: Option<DragPayload>,
+    pub(crate) frame_requested: bool,
+    pub(crate) dispatched_events: Vec<DispatchedEvent>,
 }
 
 impl<T: 'static + Clone> TestingHandler<T> {
@@ -142,6 +180,11 @@ impl<T: 'static + Clone> TestingHandler<T> {
         self.utils.sdom()
     }
 
+    /// Take the events emitted to the DOM since the last call.
+    pub fn take_dispatched_events(&mut self) -> Vec<DispatchedEvent> {
+        std::mem::take(&mut self.dispatched_events)
+    }
+
     /// Get the current [AccessibilityId].
     pub fn focus_id(&self) -> AccessibilityId {
         self.accessibility_tree.focused_id
@@ -162,7 +205,8 @@ impl<T: 'static + Clone> TestingHandler<T> {
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
         self.wait_for_work(self.config.size());
 
-        let mut ticker = if self.config.event_loop_ticker {
+        // With a virtual clock frames only happen when advancing the time
+        let mut ticker = if self.config.event_loop_ticker && !self.config.virtual_clock {
             Some(interval(Duration::from_millis(16)))
         } else {
             None
@@ -180,6 +224,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             if let Ok(message) = platform_ev {
                 match message.action {
                     EventLoopMessageAction::RequestRerender => {
+                        self.frame_requested = true;
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
@@ -195,6 +240,9 @@ impl<T: 'static + Clone> TestingHandler<T> {
                     EventLoopMessageAction::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventLoopMessageAction::StartDrag { payload, .. } => {
+                        self.drag_payload = Some(payload);
+                    }
                     EventLoopMessageAction::RemeasureTextGroup(text_measurement) => {
                         let fdom = self.utils.sdom.get();
                         fdom.measure_paragraphs(text_measurement, SCALE_FACTOR);
@@ -207,17 +255,29 @@ impl<T: 'static + Clone> TestingHandler<T> {
                 let sdom = self.utils.sdom();
                 let fdom = sdom.get();
                 let rdom = fdom.rdom();
+                fdom.accessibility_dirty_nodes()
+                    .track_pointer_interaction(&processed_events.emmitable_events);
                 let events_executor_adapter = EventsExecutorAdapter {
                     rdom,
                     vdom: &mut self.vdom,
+                    dispatched_events: Some(&mut self.dispatched_events),
                 };
                 events_executor_adapter.run(&mut self.nodes_state, processed_events);
             }
         }
 
-        timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
-            .await
-            .ok();
+        if self.config.virtual_clock {
+            // A timeout would make the paused clock advance on its own
+            select! {
+                biased;
+                _ = self.vdom.wait_for_work() => {},
+                _ = yield_now() => {},
+            }
+        } else {
+            timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
+                .await
+                .ok();
+        }
 
         let (must_repaint, must_relayout) = self
             .utils
@@ -227,11 +287,73 @@ impl<T: 'static + Clone> TestingHandler<T> {
 
         self.wait_for_work(self.config.size());
 
-        self.ticker_sender.send(()).unwrap();
+        if !self.config.virtual_clock {
+            self.ticker_sender.send(()).unwrap();
+        }
 
         (must_repaint, must_relayout)
     }
 
+    /// Advance the virtual clock by the given duration, processing a frame every 16ms.
+    /// Requires [TestingConfig::virtual_clock] to be enabled.
+    ///
+    /// ```rust, no_run
+    /// # use std::time::Duration;
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # fn app() -> Element { rsx!( rect { } ) }
+    /// let mut utils = launch_test_with_config(
+    ///     app,
+    ///     TestingConfig::<()> {
+    ///         virtual_clock: true,
+    ///         ..TestingConfig::default()
+    ///     },
+    /// );
+    /// utils.wait_for_update().await;
+    /// utils.advance(Duration::from_millis(150)).await;
+    /// # }
+    /// ```
+    pub async fn advance(&mut self, duration: Duration) {
+        assert!(
+            self.config.virtual_clock,
+            "The virtual clock is not enabled, see `TestingConfig::virtual_clock`."
+        );
+
+        let mut remaining = duration;
+        while !remaining.is_zero() {
+            let step = remaining.min(Duration::from_millis(FRAME_MS));
+            remaining -= step;
+
+            time::advance(step).await;
+            self.ticker_sender.send(()).ok();
+            self.wait_for_update().await;
+        }
+    }
+
+    /// Advance the virtual clock frame by frame until no more frames are requested,
+    /// for example once all the animations have finished. Returns how much time was advanced.
+    ///
+    /// Requires [TestingConfig::virtual_clock] to be enabled.
+    pub async fn run_until_idle(&mut self) -> Duration {
+        let frame = Duration::from_millis(FRAME_MS);
+        let mut elapsed = Duration::ZERO;
+
+        loop {
+            self.frame_requested = false;
+            self.wait_for_update().await;
+
+            if !self.frame_requested || elapsed >= MAX_IDLE_DURATION {
+                break;
+            }
+
+            self.advance(frame).await;
+            elapsed += frame;
+        }
+
+        elapsed
+    }
+
     /// Wait for layout and events to be processed
     fn wait_for_work(&mut self, size: Size2D) {
         let sdom = &self.utils.sdom();
@@ -243,6 +365,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
         let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
         let mut compositor_dirty_nodes = fdom.compositor_dirty_nodes();
         let mut compositor_dirty_area = fdom.compositor_dirty_area();
+        let mut layout_observers = fdom.layout_observers();
 
         // Process layout
         process_layout(
@@ -252,6 +375,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             &mut dirty_accessibility_tree,
             &mut compositor_dirty_nodes,
             &mut compositor_dirty_area,
+            &mut layout_observers,
             Area {
                 origin: (0.0, 0.0).into(),
                 size,
@@ -261,6 +385,9 @@ impl<T: 'static + Clone> TestingHandler<T> {
             &fallback_fonts(),
         );
 
+        // Notify the elements whose layout changed
+        layout_observers.emit_events(&self.event_emitter);
+
         // Process accessibility updates
         let (tree, node_id) = self.accessibility_tree.process_updates(
             rdom,
@@ -308,8 +435,47 @@ impl<T: 'static + Clone> TestingHandler<T> {
     /// ```
     ///
     /// For mouse **movements** and **clicks** you can use shortcuts like [TestingHandler::move_cursor] and [TestingHandler::click_cursor].
+    ///
+    /// Just like in a real window, while there is a drag session mouse movements also push `dragover` events,
+    /// and releasing the left button of the mouse drops the payload.
     pub fn push_event(&mut self, event: impl Into<PlatformEvent>) {
-        self.events_queue.push(event.into());
+        let event = event.into();
+
+        if let Some(payload) = &self.drag_payload {
+            match event {
+                PlatformEvent::Mouse {
+                    name: MouseEventName::MouseMove,
+                    cursor,
+                    ..
+                } => {
+                    self.events_queue.push(PlatformEvent::Drag {
+                        name: DragEventName::DragOver,
+                        cursor,
+                        payload: payload.clone(),
+                    });
+                }
+                PlatformEvent::Mouse {
+                    name: MouseEventName::MouseUp,
+                    cursor,
+                    button: Some(MouseButton::Left),
+                } => {
+                    let payload = self.drag_payload.take().unwrap();
+                    self.events_queue.push(PlatformEvent::Drag {
+                        name: DragEventName::Drop,
+                        cursor,
+                        payload: payload.clone(),
+                    });
+                    self.events_queue.push(PlatformEvent::Drag {
+                        name: DragEventName::DragEnd,
+                        cursor,
+                        payload,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        self.events_queue.push(event);
     }
 
     /// Get the Root node.
@@ -358,41 +524,17 @@ impl<T: 'static + Clone> TestingHandler<T> {
     /// ```
     pub fn create_snapshot(&mut self) -> Data {
         let fdom = self.utils.sdom.get();
-        let (width, height) = self.config.size.to_i32().to_tuple();
-
-        // Create the main surface
-        let mut surface =
-            raster_n32_premul((width, height)).expect("Failed to create the surface.");
-        surface.canvas().clear(Color::WHITE);
-
-        // Create the dirty surface
-        let mut dirty_surface = surface
-            .new_surface_with_dimensions((width, height))
-            .expect("Failed to create the dirty surface.");
-        dirty_surface.canvas().clear(Color::WHITE);
-
-        let mut compositor = Compositor::default();
 
         // Render to the canvas
-        let mut render_pipeline = RenderPipeline {
-            canvas_area: Area::from_size((width as f32, height as f32).into()),
-            rdom: fdom.rdom(),
-            compositor_dirty_area: &mut fdom.compositor_dirty_area(),
-            compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
-            compositor_cache: &mut fdom.compositor_cache(),
-            layers: &mut fdom.layers(),
-            layout: &mut fdom.layout(),
-            background: Color::WHITE,
-            surface: &mut surface,
-            dirty_surface: &mut dirty_surface,
-            compositor: &mut compositor,
-            scale_factor: SCALE_FACTOR as f32,
-            font_collection: &mut self.font_collection,
-            font_manager: &self.font_mgr,
-            fallback_fonts: &["Fira Sans".to_string()],
-            images_cache: &mut fdom.images_cache(),
-        };
-        render_pipeline.run();
+        let mut surface = render_to_surface(
+            &fdom,
+            self.config.size.to_i32().to_tuple(),
+            Color::WHITE,
+            SCALE_FACTOR as f32,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &["Fira Sans".to_string()],
+        );
 
         // Capture snapshot
         let image = surface.image_snapshot();
@@ -420,6 +562,26 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .expect("Failed to save the snapshot file.");
     }
 
+    /// Render the app and compare it against the golden PNG file in the given path.
+    /// See [compare_snapshot](crate::snapshot::compare_snapshot) for more details, or use [assert_snapshot!](crate::assert_snapshot) to panic on failure.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils
+    ///     .compare_snapshot("./tests/snapshots/rect.png", SnapshotTolerance::default())
+    ///     .unwrap();
+    /// ```
+    pub fn compare_snapshot(
+        &mut self,
+        golden_path: impl AsRef<Path>,
+        tolerance: SnapshotTolerance,
+    ) -> Result<(), SnapshotError> {
+        let snapshot_data = self.create_snapshot();
+        compare_snapshot(&snapshot_data, golden_path.as_ref(), tolerance)
+    }
+
     /// Shorthand to simulate a cursor move to the given location.
     ///
     /// ```rust
@@ -459,4 +621,181 @@ impl<T: 'static + Clone> TestingHandler<T> {
         });
         self.wait_for_update().await;
     }
+
+    /// Get the first node that matches the given [AccessibilityQuery].
+    pub fn get_by_accessibility(&self, query: &AccessibilityQuery) -> Option<TestNode> {
+        self.root().get_by_accessibility(query)
+    }
+
+    /// Get all the nodes that match the given [AccessibilityQuery].
+    pub fn get_all_by_accessibility(&self, query: &AccessibilityQuery) -> Vec<TestNode> {
+        self.root().get_all_by_accessibility(query)
+    }
+
+    /// Get the first node with the given [Role].
+    pub fn get_by_role(&self, role: Role) -> Option<TestNode> {
+        self.root().get_by_role(role)
+    }
+
+    /// Get the first node with the given accessible name.
+    pub fn get_by_name(&self, name: &str) -> Option<TestNode> {
+        self.root().get_by_name(name)
+    }
+
+    /// Get the node with the given [AccessibilityId].
+    pub fn get_by_a11y_id(&self, id: AccessibilityId) -> Option<TestNode> {
+        self.root().get_by_a11y_id(id)
+    }
+
+    /// Shorthand to simulate a click in the center of the given node.
+    pub async fn click(&mut self, node: &TestNode) {
+        let center = node.area().expect("The node has no layout.").center();
+        self.click_cursor((center.x as f64, center.y as f64)).await;
+    }
+
+    /// Shorthand to simulate a click in the center of the first node with the given [Role] and accessible name.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// utils.click_by_role(Role::Button, "Submit").await;
+    /// # }
+    /// ```
+    pub async fn click_by_role(&mut self, role: Role, name: &str) {
+        let node = self
+            .get_by_accessibility(&AccessibilityQuery::role(role).with_name(name))
+            .unwrap_or_else(|| panic!("Node with role {role:?} and name {name:?} not found"));
+        self.click(&node).await;
+    }
+
+    /// Focus the given node, just like assistive technology would.
+    pub async fn focus(&mut self, node: &TestNode) {
+        let id = node.a11y_id().expect("The node is not accessible.");
+        self.utils
+            .sdom
+            .get()
+            .accessibility_dirty_nodes()
+            .request_focus(AccessibilityFocusStrategy::Node(id));
+        // Process the focus and let the components react to it
+        self.wait_for_update().await;
+        self.wait_for_update().await;
+    }
+
+    /// Focus the given node and type the given text, one character at a time.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// let input = utils.get_by_role(Role::TextInput).unwrap();
+    /// utils.type_into(&input, "Hello").await;
+    /// # }
+    /// ```
+    pub async fn type_into(&mut self, node: &TestNode, text: &str) {
+        self.focus(node).await;
+        for character in text.chars() {
+            let key = Key::Character(character.to_string());
+            self.push_event(PlatformEvent::Keyboard {
+                name: KeyboardEventName::KeyDown,
+                key: key.clone(),
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+            self.push_event(PlatformEvent::Keyboard {
+                name: KeyboardEventName::KeyUp,
+                key,
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+            self.wait_for_update().await;
+        }
+    }
+
+    /// Replay an [InputRecording], for example one recorded with the `InputRecorderPlugin` of `freya-winit`.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// let recording = InputRecording::load("./tests/recordings/session.jsonl").unwrap();
+    /// utils.replay(&recording).await;
+    /// # }
+    /// ```
+    pub async fn replay(&mut self, recording: &InputRecording) {
+        self.replay_with(recording, |_, _| {}).await;
+    }
+
+    /// Replay an [InputRecording] and call `on_marker` every time a marker is reached,
+    /// useful to take snapshots at certain points of the session.
+    ///
+    /// A frame is processed for every 16ms of the recording, but how much time passes between them depends on the clock.
+    /// With [TestingConfig::virtual_clock] enabled the clock advances exactly 16ms per frame,
+    /// so replaying the same recording always produces the same frames, animations included.
+    /// Otherwise the real clock is used and the replay runs as fast as the frames are processed,
+    /// so time based behaviors like animations or double clicks might not match the recorded session.
+    ///
+    /// ```rust, no_run
+    /// # use freya_testing::prelude::*;
+    /// # use freya::prelude::*;
+    /// # async fn test() {
+    /// # let mut utils = launch_test(|| rsx!( rect { } ));
+    /// let recording = InputRecording::load("./tests/recordings/session.jsonl").unwrap();
+    /// utils
+    ///     .replay_with(&recording, |utils, marker| {
+    ///         utils.save_snapshot(format!("./{marker}.png"));
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn replay_with(
+        &mut self,
+        recording: &InputRecording,
+        mut on_marker: impl FnMut(&mut Self, &str),
+    ) {
+        let mut time = 0;
+
+        for entry in &recording.entries {
+            // Process the frames that happened before this input
+            while time + FRAME_MS <= entry.time {
+                time += FRAME_MS;
+                if self.config.virtual_clock {
+                    self.advance(Duration::from_millis(FRAME_MS)).await;
+                } else {
+                    self.wait_for_update().await;
+                }
+            }
+
+            match &entry.input {
+                RecordedInput::Resize(size) => {
+                    self.resize(*size * SCALE_FACTOR as f32);
+                }
+                RecordedInput::Event(event) => {
+                    let mut event = event.clone().scaled(SCALE_FACTOR);
+
+                    // Recorded drag payloads lose their typed value, so use the one of the current drag session
+                    if let PlatformEvent::Drag { name, payload, .. } = &mut event {
+                        if let Some(drag_payload) = &self.drag_payload {
+                            *payload = drag_payload.clone();
+                        }
+                        if *name == DragEventName::DragEnd {
+                            self.drag_payload = None;
+                        }
+                    }
+
+                    // The recorded drag events are replayed as they are instead of deriving them from the mouse events
+                    self.events_queue.push(event);
+                }
+                RecordedInput::Marker(name) => {
+                    self.wait_for_update().await;
+                    on_marker(self, name);
+                }
+            }
+        }
+
+        self.wait_for_update().await;
+    }
 }
@@ -1,7 +1,17 @@
+use accesskit::{
+    Node,
+    NodeId as AccessibilityId,
+    Role,
+};
 use freya_core::{
+    accessibility::{
+        AccessibilityTree,
+        NodeAccessibility,
+    },
     custom_attributes::CustomAttributeValues,
     node_state_snapshot::NodeState,
     states::{
+        AccessibilityState,
         StyleState,
         ViewportState,
     },
@@ -16,7 +26,10 @@ use torin::{
     prelude::LayoutNode,
 };
 
-use crate::test_utils::TestUtils;
+use crate::{
+    query::AccessibilityQuery,
+    test_utils::TestUtils,
+};
 
 /// Represents a `Node` in the DOM.
 #[derive(Clone)]
@@ -151,4 +164,113 @@ impl TestNode {
             .first()
             .cloned()
     }
+
+    /// Get all the text of this Node and its descendants.
+    pub fn text_content(&self) -> String {
+        self.utils()
+            .get_node_matching_inside_id(self.node_id, |node| node.node_type().is_text())
+            .iter()
+            .filter_map(|node| node.text().map(str::to_string))
+            .collect()
+    }
+
+    /// Get the [AccessibilityId] of this Node, if it is part of the accessibility tree.
+    pub fn a11y_id(&self) -> Option<AccessibilityId> {
+        let sdom = self.utils().sdom();
+        let fdom = sdom.get();
+        let node = fdom.rdom().get(self.node_id)?;
+        node.get_accessibility_id()
+    }
+
+    /// Get the accessibility node of this Node, just like assistive technology sees it.
+    pub fn accessibility_node(&self) -> Option<Node> {
+        let sdom = self.utils().sdom();
+        let fdom = sdom.get();
+        let layout = fdom.layout();
+        let node = fdom.rdom().get(self.node_id)?;
+        node.get_accessibility_id()?;
+        let accessibility_state = node.get::<AccessibilityState>()?;
+        let layout_node = layout.get(self.node_id)?;
+        Some(AccessibilityTree::create_node(
+            &node,
+            layout_node,
+            &accessibility_state,
+        ))
+    }
+
+    /// Get the accessibility [Role] of this Node.
+    pub fn role(&self) -> Option<Role> {
+        self.accessibility_node().map(|node| node.role())
+    }
+
+    /// Get the accessible name of this Node. Falls back to its text content when there is no explicit name.
+    pub fn name(&self) -> Option<String> {
+        let node = self.accessibility_node()?;
+        // `a11y_name` is exposed as the class name
+        if let Some(name) = node.label().or_else(|| node.class_name()) {
+            return Some(name.to_string());
+        }
+        let text = self.text_content();
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Get the accessible description of this Node.
+    pub fn description(&self) -> Option<String> {
+        self.accessibility_node()?.description().map(str::to_string)
+    }
+
+    /// Get the accessible value of this Node.
+    pub fn value(&self) -> Option<String> {
+        self.accessibility_node()?.value().map(str::to_string)
+    }
+
+    /// Check if this Node is focused.
+    pub fn is_focused(&self) -> bool {
+        self.a11y_id() == Some(self.utils().focused_id())
+    }
+
+    /// Check if this Node is expanded, `None` if it can't be expanded.
+    pub fn is_expanded(&self) -> Option<bool> {
+        self.accessibility_node()?.is_expanded()
+    }
+
+    /// Check if this Node is selected, `None` if it can't be selected.
+    pub fn is_selected(&self) -> Option<bool> {
+        self.accessibility_node()?.is_selected()
+    }
+
+    /// Check if this Node is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.accessibility_node()
+            .is_some_and(|node| node.is_disabled())
+    }
+
+    /// Get all the descendant Nodes of this Node, including itself, that match the given [AccessibilityQuery].
+    pub fn get_all_by_accessibility(&self, query: &AccessibilityQuery) -> Vec<Self> {
+        self.utils()
+            .get_node_matching_inside_id(self.node_id, |node| node.get_accessibility_id().is_some())
+            .into_iter()
+            .filter(|node| query.matches(node))
+            .collect()
+    }
+
+    /// Get the first descendant Node of this Node, including itself, that matches the given [AccessibilityQuery].
+    pub fn get_by_accessibility(&self, query: &AccessibilityQuery) -> Option<Self> {
+        self.get_all_by_accessibility(query).into_iter().next()
+    }
+
+    /// Get the first descendant Node of this Node with the given [Role].
+    pub fn get_by_role(&self, role: Role) -> Option<Self> {
+        self.get_by_accessibility(&AccessibilityQuery::role(role))
+    }
+
+    /// Get the first descendant Node of this Node with the given accessible name.
+    pub fn get_by_name(&self, name: &str) -> Option<Self> {
+        self.get_by_accessibility(&AccessibilityQuery::new().with_name(name))
+    }
+
+    /// Get the descendant Node of this Node with the given [AccessibilityId].
+    pub fn get_by_a11y_id(&self, id: AccessibilityId) -> Option<Self> {
+        self.get_by_accessibility(&AccessibilityQuery::new().with_id(id))
+    }
 }
@@ -5,6 +5,10 @@ use freya_core::{
         SafeDOM,
     },
     node_state_snapshot::NodeStateSnapshot,
+    types::{
+        AccessibilityId,
+        NativePlatformReceiver,
+    },
 };
 use freya_native_core::{
     real_dom::NodeImmutable,
@@ -17,6 +21,7 @@ use crate::test_node::TestNode;
 #[derive(Clone)]
 pub struct TestUtils {
     pub(crate) sdom: SafeDOM,
+    pub(crate) platform_receiver: NativePlatformReceiver,
 }
 
 impl TestUtils {
@@ -25,6 +30,11 @@ impl TestUtils {
         &self.sdom
     }
 
+    /// Get the [AccessibilityId] of the focused node.
+    pub(crate) fn focused_id(&self) -> AccessibilityId {
+        self.platform_receiver.borrow().focused_accessibility_id
+    }
+
     /// Get a Node by the given ID
     pub fn get_node_by_id(&self, node_id: NodeId) -> TestNode {
         let utils = self.clone();
@@ -83,7 +93,7 @@ impl TestUtils {
                 let node_type = node.node_type().clone();
 
                 nodes.push(TestNode {
-                    node_id,
+                    node_id: node.id(),
                     utils,
                     children_ids,
                     height,
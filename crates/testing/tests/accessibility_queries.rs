@@ -0,0 +1,119 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+async fn query_by_role_and_name() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            Button {
+                onpress: move |_| clicks += 1,
+                label {
+                    "Increase"
+                }
+            }
+            label {
+                "Clicks {clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let button = utils.get_by_role(Role::Button).unwrap();
+    assert_eq!(button.name().as_deref(), Some("Increase"));
+    assert!(utils.get_by_name("Clicks 0").is_some());
+    assert!(utils
+        .get_by_accessibility(&AccessibilityQuery::role(Role::Button).with_name("Decrease"))
+        .is_none());
+
+    utils.click_by_role(Role::Button, "Increase").await;
+
+    assert!(utils.get_by_name("Clicks 1").is_some());
+    assert!(utils.get_by_role(Role::Button).unwrap().is_focused());
+}
+
+#[tokio::test]
+async fn query_by_description_value_and_id() {
+    fn app() -> Element {
+        let focus = use_focus();
+
+        rsx!(
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_role: "tab",
+                a11y_name: "Settings",
+                a11y_description: "Opens the settings",
+                a11y_value: "3 changes",
+                a11y_expanded: "true",
+                a11y_selected: "false",
+            }
+            rect {
+                a11y_role: "tab",
+                a11y_name: "Profile",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils
+            .get_all_by_accessibility(&AccessibilityQuery::role(Role::Tab))
+            .len(),
+        2
+    );
+
+    let settings = utils
+        .get_by_accessibility(
+            &AccessibilityQuery::new()
+                .with_description("Opens the settings")
+                .with_value("3 changes"),
+        )
+        .unwrap();
+    assert_eq!(settings.name().as_deref(), Some("Settings"));
+    assert_eq!(settings.is_expanded(), Some(true));
+    assert_eq!(settings.is_selected(), Some(false));
+    assert!(!settings.is_focused());
+
+    let id = settings.a11y_id().unwrap();
+    assert_eq!(
+        utils.get_by_a11y_id(id).unwrap().name().as_deref(),
+        Some("Settings")
+    );
+
+    let profile = utils.get_by_name("Profile").unwrap();
+    assert_eq!(profile.is_expanded(), None);
+
+    utils.focus(&settings).await;
+
+    assert!(settings.is_focused());
+    assert_eq!(utils.focus_id(), id);
+}
+
+#[tokio::test]
+async fn type_into_input() {
+    fn app() -> Element {
+        let mut value = use_signal(String::new);
+
+        rsx!(Input {
+            value,
+            onchange: move |new_value| {
+                value.set(new_value);
+            }
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let input = utils.get_by_role(Role::TextInput).unwrap();
+    utils.type_into(&input, "Hi").await;
+
+    let input = utils.get_by_role(Role::TextInput).unwrap();
+    assert!(input.is_focused());
+    assert_eq!(input.value().as_deref(), Some("Hi"));
+}
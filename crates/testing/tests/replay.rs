@@ -0,0 +1,102 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "100",
+            onclick: move |_| clicks += 1,
+        }
+        label {
+            "Clicks {clicks}"
+        }
+    )
+}
+
+fn click(recording: &mut InputRecording, time: u64, cursor: (f64, f64)) {
+    recording.push(
+        time,
+        RecordedInput::Event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        }),
+    );
+    recording.push(
+        time + 20,
+        RecordedInput::Event(PlatformEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        }),
+    );
+}
+
+#[tokio::test]
+async fn replay_recording() {
+    let mut recording = InputRecording::new();
+    recording.push(0, RecordedInput::Resize((300., 300.).into()));
+    click(&mut recording, 10, (50., 50.));
+    recording.push(50, RecordedInput::Marker("first-click".to_string()));
+    click(&mut recording, 100, (50., 50.));
+    // Outside of the rect
+    click(&mut recording, 150, (50., 200.));
+    recording.push(200, RecordedInput::Marker("end".to_string()));
+
+    // Recordings survive a round trip through their file format
+    let recording = InputRecording::parse(&recording.to_json_lines().unwrap()).unwrap();
+    assert_eq!(
+        recording.markers().collect::<Vec<_>>(),
+        vec!["first-click", "end"]
+    );
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let mut markers = Vec::new();
+    utils
+        .replay_with(&recording, |utils, marker| {
+            let label = utils.root().get(1);
+            markers.push((marker.to_string(), label.get(0).text().unwrap().to_string()));
+        })
+        .await;
+
+    assert_eq!(utils.config().size(), Size2D::new(300., 300.));
+    assert_eq!(
+        markers,
+        vec![
+            ("first-click".to_string(), "Clicks 1".to_string()),
+            ("end".to_string(), "Clicks 2".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn parse_recording() {
+    let recording = InputRecording::parse(
+        r#"
+{"time":0,"input":{"Resize":[400.0,200.0]}}
+{"time":5,"input":{"Marker":"start"}}
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        recording.entries,
+        vec![
+            RecordedEntry {
+                time: 0,
+                input: RecordedInput::Resize((400., 200.).into()),
+            },
+            RecordedEntry {
+                time: 5,
+                input: RecordedInput::Marker("start".to_string()),
+            },
+        ]
+    );
+
+    assert!(InputRecording::parse("not json").is_err());
+}
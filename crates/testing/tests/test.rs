@@ -130,3 +130,61 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn compare_snapshots() {
+    fn app() -> Element {
+        let mut color = use_signal(|| "red");
+
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            background: "{color}",
+            onclick: move |_| color.set("blue"),
+        })
+    }
+
+    // Updating the snapshots would overwrite the golden instead of comparing against it
+    std::env::remove_var(UPDATE_SNAPSHOTS_ENV);
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let dir = std::env::temp_dir().join(format!(
+        "freya-testing-compare-snapshots-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let golden_path = dir.join("rect.png");
+    std::fs::write(&golden_path, &*utils.create_snapshot()).unwrap();
+
+    assert!(utils
+        .compare_snapshot(&golden_path, SnapshotTolerance::default())
+        .is_ok());
+    assert_snapshot!(utils, &golden_path);
+
+    utils.click_cursor((5., 5.)).await;
+
+    let result = utils.compare_snapshot(&golden_path, SnapshotTolerance::default());
+    let Err(SnapshotError::PixelsMismatch {
+        different_pixels,
+        diff_path,
+        ..
+    }) = result
+    else {
+        panic!("Expected the snapshot to be different.");
+    };
+    assert_eq!(different_pixels, 100 * 100);
+    assert!(diff_path.exists());
+
+    assert!(utils
+        .compare_snapshot(&golden_path, SnapshotTolerance::new(0, 100 * 100))
+        .is_ok());
+
+    assert!(matches!(
+        utils.compare_snapshot(dir.join("missing.png"), SnapshotTolerance::default()),
+        Err(SnapshotError::MissingGolden(_))
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -18,6 +18,7 @@ use crate::{
     },
     geometry::{
         Area,
+        Point2D,
         Size2D,
     },
     node::Node,
@@ -27,6 +28,8 @@ use crate::{
         AlignmentDirection,
         AreaModel,
         Direction,
+        GridPlacement,
+        GridTrack,
         LayoutMetadata,
         Length,
         Torin,
@@ -379,6 +382,21 @@ where
         // Parent Node is dirty.
         node_is_dirty: bool,
     ) {
+        if node.content.is_grid() {
+            self.measure_grid_children(
+                node_id,
+                node,
+                node_phase,
+                available_area,
+                inner_sizes,
+                node_area,
+                inner_area,
+                must_cache_children,
+                node_is_dirty,
+            );
+            return;
+        }
+
         let children = self.dom_adapter.children_of(node_id);
 
         // Used to calculate the spacing and some alignments
@@ -721,6 +739,207 @@ where
         }
     }
 
+    /// Updates the layout for a grid node by placing every child in its cells.
+    ///
+    /// Tracks are resolved in this order:
+    /// 1. Fixed tracks (pixels and percentages).
+    /// 2. `auto` tracks, sized by the biggest child that only spans that track.
+    ///    Columns are resolved before rows so children can be measured with their final width.
+    /// 3. `flex` tracks, which share the remaining space. When there are none, the remaining
+    ///    space is shared among the `auto` tracks instead.
+    #[allow(clippy::too_many_arguments)]
+    fn measure_grid_children(
+        &mut self,
+        node_id: &Key,
+        node: &Node,
+        node_phase: Phase,
+        // Area available for children inside the Node
+        available_area: &Area,
+        // Accumulated sizes in both axis in the Node
+        inner_sizes: &mut Size2D,
+        // Total area of the node.
+        node_area: &mut Area,
+        // Inner area of the node.
+        inner_area: &mut Area,
+        // Whether to cache the measurements of this Node's children
+        must_cache_children: bool,
+        // Parent Node is dirty.
+        node_is_dirty: bool,
+    ) {
+        let children = self.dom_adapter.children_of(node_id);
+
+        let mut grid_children = Vec::new();
+        let mut floating_children = Vec::new();
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+            // Floating children are not part of the grid
+            if child_data.position.is_stacked() {
+                grid_children.push((child_id, child_data));
+            } else {
+                floating_children.push((child_id, child_data));
+            }
+        }
+
+        let spacing = node.spacing.get();
+        let width_inner_sized = node.width.inner_sized(node_phase);
+        let height_inner_sized = node.height.inner_sized(node_phase);
+
+        let columns_len = node.grid_columns.len().max(1);
+        let cells = place_grid_cells(
+            node,
+            columns_len,
+            grid_children
+                .iter()
+                .map(|(_, child_data)| &child_data.grid_placement),
+        );
+        let rows_len = cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or_default()
+            .max(node.grid_rows.len());
+
+        // Measure the `auto` columns
+        let mut auto_columns = vec![0f32; columns_len];
+        for ((child_id, child_data), cell) in grid_children.iter().zip(&cells) {
+            if cell.column_span == 1
+                && is_auto_track(&node.grid_columns, cell.column, width_inner_sized)
+                && contributes_to_auto_track(&child_data.width)
+            {
+                let (_, mut child_areas) = self.measure_node(
+                    *child_id,
+                    child_data,
+                    inner_area,
+                    &Area::new(available_area.origin, inner_area.size),
+                    false,
+                    node_is_dirty,
+                    Phase::Initial,
+                );
+                child_areas.area.adjust_size(child_data);
+                auto_columns[cell.column] = auto_columns[cell.column].max(child_areas.area.width());
+            }
+        }
+
+        let columns = resolve_grid_tracks(
+            &node.grid_columns,
+            columns_len,
+            inner_area.width(),
+            spacing,
+            &auto_columns,
+            width_inner_sized,
+        );
+
+        // Measure the `auto` rows, now that the width of every column is known
+        let mut auto_rows = vec![0f32; rows_len];
+        for ((child_id, child_data), cell) in grid_children.iter().zip(&cells) {
+            if cell.row_span == 1
+                && is_auto_track(&node.grid_rows, cell.row, height_inner_sized)
+                && contributes_to_auto_track(&child_data.height)
+            {
+                let cell_width = tracks_length(&columns, cell.column, cell.column_span, spacing);
+                let cell_area = Area::new(
+                    available_area.origin,
+                    Size2D::new(cell_width, inner_area.height()),
+                );
+                let (_, mut child_areas) = self.measure_node(
+                    *child_id,
+                    child_data,
+                    &cell_area,
+                    &cell_area,
+                    false,
+                    node_is_dirty,
+                    Phase::Initial,
+                );
+                child_areas.area.adjust_size(child_data);
+                auto_rows[cell.row] = auto_rows[cell.row].max(child_areas.area.height());
+            }
+        }
+
+        let rows = resolve_grid_tracks(
+            &node.grid_rows,
+            rows_len,
+            inner_area.height(),
+            spacing,
+            &auto_rows,
+            height_inner_sized,
+        );
+
+        // Final phase: measure the children inside their cells
+        for ((child_id, child_data), cell) in grid_children.into_iter().zip(cells) {
+            let cell_area = Area::new(
+                Point2D::new(
+                    available_area.origin.x
+                        + tracks_length(&columns, 0, cell.column, spacing)
+                        + if cell.column > 0 { spacing } else { 0. },
+                    available_area.origin.y
+                        + tracks_length(&rows, 0, cell.row, spacing)
+                        + if cell.row > 0 { spacing } else { 0. },
+                ),
+                Size2D::new(
+                    tracks_length(&columns, cell.column, cell.column_span, spacing),
+                    tracks_length(&rows, cell.row, cell.row_span, spacing),
+                ),
+            );
+
+            let (child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                &cell_area,
+                &cell_area,
+                must_cache_children,
+                node_is_dirty,
+                Phase::Final,
+            );
+
+            // Adjust the size of the area if needed
+            child_areas.area.adjust_size(&child_data);
+
+            // Cache the child layout if it was mutated and children must be cached
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        for (child_id, child_data) in floating_children {
+            let (child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                inner_area,
+                available_area,
+                must_cache_children,
+                node_is_dirty,
+                Phase::Final,
+            );
+
+            child_areas.area.adjust_size(&child_data);
+
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        inner_sizes.width = tracks_length(&columns, 0, columns.len(), spacing);
+        inner_sizes.height = tracks_length(&rows, 0, rows.len(), spacing);
+
+        if width_inner_sized {
+            node_area.size.width =
+                inner_sizes.width + node.padding.horizontal() + node.margin.horizontal();
+            // Keep the inner area in sync
+            inner_area.size.width =
+                node_area.size.width - node.padding.horizontal() - node.margin.horizontal();
+        }
+
+        if height_inner_sized {
+            node_area.size.height =
+                inner_sizes.height + node.padding.vertical() + node.margin.vertical();
+            // Keep the inner area in sync
+            inner_area.size.height =
+                node_area.size.height - node.padding.vertical() - node.margin.vertical();
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn deferred_measure_children(
         &mut self,
@@ -1146,3 +1365,197 @@ where
         }
     }
 }
+
+/// Cells occupied by a child of a grid Node.
+#[derive(Clone, Copy, Debug)]
+struct GridCell {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+/// Find the cells of every child of a grid Node.
+/// Children with an area or an explicit column and row are placed first,
+/// the rest fill the free cells in order, row by row.
+fn place_grid_cells<'a>(
+    node: &Node,
+    columns_len: usize,
+    placements: impl Iterator<Item = &'a GridPlacement>,
+) -> Vec<GridCell> {
+    let placements = placements.collect::<Vec<_>>();
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let mut cells = vec![None; placements.len()];
+
+    let is_free = |occupied: &Vec<Vec<bool>>, cell: &GridCell| {
+        (cell.row..cell.row + cell.row_span).all(|row| {
+            (cell.column..cell.column + cell.column_span)
+                .all(|column| occupied.get(row).and_then(|r| r.get(column)) != Some(&true))
+        })
+    };
+
+    let occupy = |occupied: &mut Vec<Vec<bool>>, cell: &GridCell| {
+        if occupied.len() < cell.row + cell.row_span {
+            occupied.resize(cell.row + cell.row_span, vec![false; columns_len]);
+        }
+        for row in &mut occupied[cell.row..cell.row + cell.row_span] {
+            for column in &mut row[cell.column..cell.column + cell.column_span] {
+                *column = true;
+            }
+        }
+    };
+
+    // Explicitly placed children
+    for (cell, placement) in cells.iter_mut().zip(&placements) {
+        let area = placement
+            .area
+            .as_ref()
+            .and_then(|name| node.grid_areas.iter().find(|area| &area.name == name));
+
+        let (column, row, column_span, row_span) = if let Some(area) = area {
+            (area.column, area.row, area.column_span, area.row_span)
+        } else if let (Some(column), Some(row)) = (placement.column, placement.row) {
+            (column, row, placement.column_span, placement.row_span)
+        } else {
+            continue;
+        };
+
+        let column_span = column_span.clamp(1, columns_len);
+        let explicit_cell = GridCell {
+            column: column.min(columns_len - column_span),
+            row,
+            column_span,
+            row_span: row_span.max(1),
+        };
+        occupy(&mut occupied, &explicit_cell);
+        *cell = Some(explicit_cell);
+    }
+
+    // Automatically placed children
+    let mut cursor = (0, 0);
+    for (cell, placement) in cells.iter_mut().zip(&placements) {
+        if cell.is_some() {
+            continue;
+        }
+
+        let column_span = placement.column_span.clamp(1, columns_len);
+        let row_span = placement.row_span.max(1);
+        let last_column = columns_len - column_span;
+        let candidate = |column: usize, row: usize| GridCell {
+            column,
+            row,
+            column_span,
+            row_span,
+        };
+
+        let auto_cell = if let Some(column) = placement.column {
+            let column = column.min(last_column);
+            // There is always room after the last occupied row
+            (0..=occupied.len())
+                .map(|row| candidate(column, row))
+                .find(|cell| is_free(&occupied, cell))
+        } else if let Some(row) = placement.row {
+            (0..=last_column)
+                .map(|column| candidate(column, row))
+                .find(|cell| is_free(&occupied, cell))
+                .or(Some(candidate(0, row)))
+        } else {
+            let (cursor_row, cursor_column) = cursor;
+            (cursor_row..=occupied.len().max(cursor_row) + 1)
+                .flat_map(|row| {
+                    let first_column = if row == cursor_row { cursor_column } else { 0 };
+                    (first_column..=last_column).map(move |column| candidate(column, row))
+                })
+                .find(|cell| is_free(&occupied, cell))
+                .inspect(|cell| cursor = (cell.row, cell.column + column_span))
+        };
+
+        if let Some(auto_cell) = auto_cell {
+            occupy(&mut occupied, &auto_cell);
+            *cell = Some(auto_cell);
+        }
+    }
+
+    cells.into_iter().flatten().collect()
+}
+
+/// Resolve the sizes of the tracks of a grid Node.
+fn resolve_grid_tracks(
+    tracks: &[GridTrack],
+    tracks_len: usize,
+    available: f32,
+    spacing: f32,
+    auto_sizes: &[f32],
+    inner_sized: bool,
+) -> Vec<f32> {
+    let track = |i: usize| tracks.get(i).copied().unwrap_or_default();
+
+    let mut sizes = vec![0f32; tracks_len];
+    let mut flex_grows = Length::default();
+    let mut used_size = spacing * tracks_len.saturating_sub(1) as f32;
+
+    for (i, size) in sizes.iter_mut().enumerate() {
+        *size = match track(i) {
+            GridTrack::Pixels(px) => px.get(),
+            GridTrack::Percentage(per) => available / 100.0 * per.get(),
+            GridTrack::Flex(flex_grow) if !inner_sized => {
+                flex_grows += flex_grow;
+                continue;
+            }
+            GridTrack::Auto | GridTrack::Flex(_) => auto_sizes[i],
+        };
+        used_size += *size;
+    }
+
+    // There is no space to distribute when the grid is sized by its content
+    if inner_sized {
+        return sizes;
+    }
+
+    let free_size = (available - used_size).max(0.);
+
+    if flex_grows.get() > 0. {
+        let flex_grows = flex_grows.max(Length::new(1.0));
+        for (i, size) in sizes.iter_mut().enumerate() {
+            if let Some(flex_grow) = track(i).flex_grow() {
+                *size = free_size * flex_grow.get() / flex_grows.get();
+            }
+        }
+    } else {
+        // Stretch the auto tracks
+        let auto_tracks = (0..tracks_len).filter(|i| track(*i).is_auto()).count();
+        if auto_tracks > 0 {
+            let extra_size = free_size / auto_tracks as f32;
+            for (i, size) in sizes.iter_mut().enumerate() {
+                if track(i).is_auto() {
+                    *size += extra_size;
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Whether the track at the given index is sized by its content.
+fn is_auto_track(tracks: &[GridTrack], index: usize, inner_sized: bool) -> bool {
+    match tracks.get(index).copied().unwrap_or_default() {
+        GridTrack::Auto => true,
+        GridTrack::Flex(_) => inner_sized,
+        _ => false,
+    }
+}
+
+/// Children whose size depends on their cell can't be used to size an auto track.
+fn contributes_to_auto_track(size: &Size) -> bool {
+    matches!(
+        size,
+        Size::Inner | Size::Pixels(_) | Size::RootPercentage(_)
+    )
+}
+
+/// Length of `count` tracks starting at `start`, including the spacing between them.
+fn tracks_length(sizes: &[f32], start: usize, count: usize, spacing: f32) -> f32 {
+    let tracks = &sizes[start.min(sizes.len())..(start + count).min(sizes.len())];
+    tracks.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32
+}
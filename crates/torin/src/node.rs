@@ -7,6 +7,9 @@ use crate::{
     geometry::Length,
     prelude::{
         Content,
+        GridArea,
+        GridPlacement,
+        GridTrack,
         Phase,
         Position,
         VisibleSize,
@@ -67,6 +70,18 @@ pub struct Node {
     pub contains_text: bool,
 
     pub spacing: Length,
+
+    /// Columns of a grid container
+    pub grid_columns: Vec<GridTrack>,
+
+    /// Rows of a grid container, missing rows are sized automatically
+    pub grid_rows: Vec<GridTrack>,
+
+    /// Named areas of a grid container
+    pub grid_areas: Vec<GridArea>,
+
+    /// Position of this Node inside its grid container
+    pub grid_placement: GridPlacement,
 }
 
 impl Scaled for Node {
@@ -83,6 +98,13 @@ impl Scaled for Node {
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
         self.spacing *= scale_factor;
+        for track in self
+            .grid_columns
+            .iter_mut()
+            .chain(self.grid_rows.iter_mut())
+        {
+            track.scale(scale_factor);
+        }
     }
 }
 
@@ -284,6 +306,37 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size and the grid tracks
+    pub fn from_size_and_grid(
+        width: Size,
+        height: Size,
+        grid_columns: Vec<GridTrack>,
+        grid_rows: Vec<GridTrack>,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            content: Content::Grid,
+            grid_columns,
+            grid_rows,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and a grid placement
+    pub fn from_size_and_grid_placement(
+        width: Size,
+        height: Size,
+        grid_placement: GridPlacement,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_placement,
+            ..Default::default()
+        }
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized(Phase::Initial)
@@ -297,5 +350,6 @@ impl Node {
         self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.has_layout_references
+            || self.content.is_grid()
     }
 }
@@ -5,6 +5,7 @@ pub enum Content {
     Normal,
     Fit,
     Flex,
+    Grid,
 }
 
 impl Content {
@@ -15,6 +16,10 @@ impl Content {
     pub fn is_flex(&self) -> bool {
         self == &Self::Flex
     }
+
+    pub fn is_grid(&self) -> bool {
+        self == &Self::Grid
+    }
 }
 
 impl Content {
@@ -23,6 +28,7 @@ impl Content {
             Self::Normal => "normal".to_owned(),
             Self::Fit => "fit".to_owned(),
             Self::Flex => "flex".to_owned(),
+            Self::Grid => "grid".to_owned(),
         }
     }
 }
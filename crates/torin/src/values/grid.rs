@@ -0,0 +1,160 @@
+use crate::{
+    geometry::Length,
+    scaled::Scaled,
+};
+
+/// Size of a single column or row of a grid container.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum GridTrack {
+    /// Sized by the biggest element placed only in this track.
+    #[default]
+    Auto,
+    Pixels(Length),
+    /// Percentage of the grid container inner size.
+    Percentage(Length),
+    /// Fraction of the space left by the other tracks.
+    Flex(Length),
+}
+
+impl GridTrack {
+    pub fn is_auto(&self) -> bool {
+        matches!(self, Self::Auto)
+    }
+
+    pub fn flex_grow(&self) -> Option<Length> {
+        match self {
+            Self::Flex(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Auto => "auto".to_string(),
+            Self::Pixels(s) => format!("{}", s.get()),
+            Self::Percentage(p) => format!("{}%", p.get()),
+            Self::Flex(f) => format!("flex({})", f.get()),
+        }
+    }
+}
+
+impl Scaled for GridTrack {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Pixels(s) = self {
+            *s *= scale_factor;
+        }
+    }
+}
+
+/// Named region of a grid container, e.g `header`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GridArea {
+    pub name: String,
+    pub column: usize,
+    pub row: usize,
+    pub column_span: usize,
+    pub row_span: usize,
+}
+
+impl GridArea {
+    /// Create the named areas of a template where every row lists the area name of each cell.
+    /// Cells named `.` are left unnamed.
+    pub fn from_template(template: &[Vec<&str>]) -> Vec<Self> {
+        let mut areas: Vec<Self> = Vec::new();
+
+        for (row, cells) in template.iter().enumerate() {
+            for (column, name) in cells.iter().enumerate() {
+                if *name == "." {
+                    continue;
+                }
+
+                if let Some(area) = areas.iter_mut().find(|area| area.name == *name) {
+                    let end_column = (area.column + area.column_span).max(column + 1);
+                    let end_row = (area.row + area.row_span).max(row + 1);
+                    area.column = area.column.min(column);
+                    area.row = area.row.min(row);
+                    area.column_span = end_column - area.column;
+                    area.row_span = end_row - area.row;
+                } else {
+                    areas.push(Self {
+                        name: (*name).to_string(),
+                        column,
+                        row,
+                        column_span: 1,
+                        row_span: 1,
+                    });
+                }
+            }
+        }
+
+        areas
+    }
+}
+
+/// Where an element is placed inside its grid container.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct GridPlacement {
+    /// Starting column, automatically placed if not specified.
+    pub column: Option<usize>,
+    /// Starting row, automatically placed if not specified.
+    pub row: Option<usize>,
+    pub column_span: usize,
+    pub row_span: usize,
+    /// Name of the grid container area to occupy, takes precedence over the rest.
+    pub area: Option<String>,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            column: None,
+            row: None,
+            column_span: 1,
+            row_span: 1,
+            area: None,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub fn new(column: usize, row: usize) -> Self {
+        Self {
+            column: Some(column),
+            row: Some(row),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn with_spans(mut self, column_span: usize, row_span: usize) -> Self {
+        self.column_span = column_span;
+        self.row_span = row_span;
+        self
+    }
+
+    pub fn from_area(area: impl Into<String>) -> Self {
+        Self {
+            area: Some(area.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        if let Some(area) = &self.area {
+            return area.clone();
+        }
+        let column = self
+            .column
+            .map_or_else(|| "auto".to_string(), |c| c.to_string());
+        let row = self
+            .row
+            .map_or_else(|| "auto".to_string(), |r| r.to_string());
+        format!(
+            "column {column} span {}, row {row} span {}",
+            self.column_span, self.row_span
+        )
+    }
+}
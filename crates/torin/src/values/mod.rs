@@ -2,6 +2,7 @@ pub mod alignment;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod grid;
 pub mod position;
 pub mod size;
 pub mod visible_size;
@@ -13,6 +14,7 @@ pub mod prelude {
         content::*,
         direction::*,
         gaps::*,
+        grid::*,
         position::*,
         size::*,
         visible_size::*,
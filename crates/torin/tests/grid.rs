@@ -0,0 +1,456 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn grid_fixed_and_flex_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4, 5, 6],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![
+                GridTrack::Pixels(Length::new(100.0)),
+                GridTrack::Flex(Length::new(1.0)),
+                GridTrack::Flex(Length::new(3.0)),
+            ],
+            vec![
+                GridTrack::Pixels(Length::new(100.0)),
+                GridTrack::Flex(Length::new(1.0)),
+            ],
+        ),
+    );
+    for child in 1..=6 {
+        mocked_dom.add(
+            child,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 300.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(75.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(175.0, 0.0), Size2D::new(225.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(100.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(100.0, 100.0), Size2D::new(75.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(6).unwrap().area,
+        Rect::new(Point2D::new(175.0, 100.0), Size2D::new(225.0, 200.0)),
+    );
+}
+
+#[test]
+pub fn grid_auto_tracks_and_spacing() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    let mut grid = Node::from_size_and_grid(
+        Size::Pixels(Length::new(500.0)),
+        Size::Inner,
+        vec![
+            GridTrack::Auto,
+            GridTrack::Percentage(Length::new(50.0)),
+            GridTrack::Auto,
+        ],
+        vec![],
+    );
+    grid.spacing = Length::new(10.0);
+    mocked_dom.add(0, None, vec![1, 2, 3, 4], grid);
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(80.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(30.0)),
+            Size::Pixels(Length::new(60.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(120.0)),
+            Size::Pixels(Length::new(20.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Auto columns are 120 and 30 wide, and share the remaining 80 pixels
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(500.0, 90.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(80.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(170.0, 0.0), Size2D::new(250.0, 60.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(430.0, 0.0), Size2D::new(30.0, 60.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 70.0), Size2D::new(120.0, 20.0)),
+    );
+}
+
+#[test]
+pub fn grid_spans() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            vec![GridTrack::Flex(Length::new(1.0)); 3],
+            vec![GridTrack::Flex(Length::new(1.0)); 3],
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::new(0, 0).with_spans(2, 1),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::new(2, 0).with_spans(1, 3),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_grid_placement(
+            Size::Fill,
+            Size::Fill,
+            GridPlacement::default().with_spans(2, 1),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(100.0, 300.0)),
+    );
+    // First free cell
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(100.0, 100.0)),
+    );
+    // Does not fit next to the previous one so it goes to the next row
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn grid_named_areas() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    let mut grid = Node::from_size_and_grid(
+        Size::Pixels(Length::new(400.0)),
+        Size::Pixels(Length::new(300.0)),
+        vec![
+            GridTrack::Pixels(Length::new(100.0)),
+            GridTrack::Flex(Length::new(1.0)),
+        ],
+        vec![
+            GridTrack::Pixels(Length::new(50.0)),
+            GridTrack::Flex(Length::new(1.0)),
+            GridTrack::Pixels(Length::new(50.0)),
+        ],
+    );
+    grid.grid_areas = GridArea::from_template(&[
+        vec!["header", "header"],
+        vec!["sidebar", "main"],
+        vec!["footer", "footer"],
+    ]);
+    mocked_dom.add(0, None, vec![1, 2, 3, 4], grid);
+    for (child, area) in [(1, "main"), (2, "footer"), (3, "header"), (4, "sidebar")] {
+        mocked_dom.add(
+            child,
+            Some(0),
+            vec![],
+            Node::from_size_and_grid_placement(
+                Size::Fill,
+                Size::Fill,
+                GridPlacement::from_area(area),
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(100.0, 50.0), Size2D::new(300.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(400.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(100.0, 200.0)),
+    );
+}
+
+#[test]
+pub fn grid_inner_sized() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    let mut grid = Node::from_size_and_grid(
+        Size::Inner,
+        Size::Inner,
+        vec![
+            GridTrack::Pixels(Length::new(50.0)),
+            GridTrack::Flex(Length::new(1.0)),
+        ],
+        vec![GridTrack::Auto],
+    );
+    grid.spacing = Length::new(5.0);
+    mocked_dom.add(1, Some(0), vec![2, 3], grid);
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(30.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(20.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Flex tracks are sized by their content when the grid is sized by its content
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(75.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 30.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(55.0, 0.0), Size2D::new(20.0, 40.0)),
+    );
+}
+
+#[test]
+pub fn grid_invalidation() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node::from_size_and_grid(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(100.0)),
+            vec![GridTrack::Auto, GridTrack::Flex(Length::new(1.0))],
+            vec![GridTrack::Flex(Length::new(1.0))],
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(50.0, 0.0), Size2D::new(250.0, 100.0)),
+    );
+
+    mocked_dom.set_node(
+        2,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(120.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(2);
+
+    layout.find_best_root(&mut mocked_dom);
+
+    // It is Node 1 because the tracks of a grid depend on its children
+    assert_eq!(layout.get_root_candidate(), RootNodeCandidate::Valid(1));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(120.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(120.0, 0.0), Size2D::new(180.0, 100.0)),
+    );
+}
@@ -1,3 +1,4 @@
+use accesskit::TreeUpdate;
 use accesskit_winit::Adapter;
 use freya_core::{
     accessibility::{
@@ -68,12 +69,13 @@ impl WinitAcessibilityTree {
         rdom: &DioxusDOM,
         layout: &Torin<NodeId>,
         dirty_nodes: &mut AccessibilityDirtyNodes,
-    ) {
+    ) -> TreeUpdate {
         let tree = self.accessibility_tree.init(rdom, layout, dirty_nodes);
         self.accessibility_adapter.update_if_active(|| {
             self.adapter_initialized = true;
-            tree
+            tree.clone()
         });
+        tree
     }
 
     /// Process any pending accessibility tree update and update the adapter
@@ -85,7 +87,7 @@ impl WinitAcessibilityTree {
         window: &Window,
         dirty_nodes: &mut AccessibilityDirtyNodes,
         event_emitter: &EventEmitter,
-    ) {
+    ) -> TreeUpdate {
         let (tree, node_id) =
             self.accessibility_tree
                 .process_updates(rdom, layout, dirty_nodes, event_emitter);
@@ -117,7 +119,9 @@ impl WinitAcessibilityTree {
 
         if self.adapter_initialized {
             // Update the Adapter
-            self.accessibility_adapter.update_if_active(|| tree);
+            self.accessibility_adapter.update_if_active(|| tree.clone());
         }
+
+        tree
     }
 }
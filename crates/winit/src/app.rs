@@ -21,6 +21,7 @@ use freya_core::{
         EventsMeasurerAdapter,
         PlatformEvent,
     },
+    layers::Layers,
     layout::process_layout,
     platform_state::{
         NativePlatformState,
@@ -47,7 +48,10 @@ use freya_core::{
     window_config::WindowConfig,
 };
 use freya_engine::prelude::*;
-use freya_native_core::NodeId;
+use freya_native_core::{
+    attributes::AttributeName,
+    NodeId,
+};
 use futures_task::Waker;
 use futures_util::Future;
 use ragnarok::{
@@ -65,6 +69,10 @@ use tokio::{
 };
 use torin::geometry::Area;
 use winit::{
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
     event_loop::EventLoopProxy,
     window::Window,
 };
@@ -105,6 +113,8 @@ pub struct Application {
     pub(crate) graphics_driver: GraphicsDriver,
     pub(crate) window: Window,
     pub(crate) is_window_focused: bool,
+    pub(crate) is_ime_enabled: bool,
+    pub(crate) is_ime_composing: bool,
     pub(crate) proxy: EventLoopProxy<EventLoopMessage>,
     pub(crate) plugins: PluginsManager,
 
@@ -142,7 +152,7 @@ impl Application {
             vdom_waker: winit_waker(proxy, window.id()),
             event_emitter,
             event_receiver,
-            nodes_state: NodesState::default(),
+            nodes_state: NodesState::new(window_config.click_count_config),
             accessibility,
             platform_sender,
             platform_receiver,
@@ -155,6 +165,8 @@ impl Application {
             surface,
             graphics_driver,
             is_window_focused: false,
+            is_ime_enabled: false,
+            is_ime_composing: false,
             window,
             proxy: proxy.clone(),
             plugins,
@@ -193,7 +205,25 @@ impl Application {
             PluginHandle::new(&self.proxy),
         );
 
-        let (repaint, relayout) = fdom.render_mutations(&mut self.vdom, scale_factor);
+        fdom.apply_mutations(&mut self.vdom, scale_factor);
+
+        self.plugins.send(
+            PluginEvent::StartedUpdatingStates {
+                window: &self.window,
+                fdom,
+            },
+            PluginHandle::new(&self.proxy),
+        );
+
+        let (repaint, relayout) = fdom.update_states();
+
+        self.plugins.send(
+            PluginEvent::FinishedUpdatingStates {
+                window: &self.window,
+                fdom,
+            },
+            PluginHandle::new(&self.proxy),
+        );
 
         self.plugins.send(
             PluginEvent::FinishedUpdatingDOM {
@@ -206,19 +236,47 @@ impl Application {
         (repaint, relayout)
     }
 
+    /// Override the attribute of an element and rerender if necessary
+    pub fn set_attribute(&mut self, node_id: NodeId, attribute: AttributeName, value: String) {
+        let (must_repaint, must_relayout) =
+            self.sdom.get_mut().set_attribute(node_id, attribute, value);
+
+        if must_relayout {
+            self.process_layout_on_next_render = true;
+            self.accessibility_tasks_for_next_render
+                .replace(AccessibilityTask::ProcessUpdate);
+        }
+
+        if must_relayout || must_repaint {
+            self.window.request_redraw();
+        }
+    }
+
     /// Poll the VirtualDOM for any new change
     pub fn poll_vdom(&mut self) {
         let mut cx = std::task::Context::from_waker(&self.vdom_waker);
+        let mut dispatched_events = self.plugins.wants_dispatched_events().then(Vec::new);
 
-        {
+        self.plugins.send(
+            PluginEvent::StartedPollingVDOM {
+                window: &self.window,
+                fdom: &self.sdom.get(),
+            },
+            PluginHandle::new(&self.proxy),
+        );
+
+        let is_ready = {
             let fut = std::pin::pin!(async {
                 select! {
                     Some(processed_events) = self.event_receiver.recv() => {
                         let fdom = self.sdom.get();
                         let rdom = fdom.rdom();
+                        fdom.accessibility_dirty_nodes()
+                            .track_pointer_interaction(&processed_events.emmitable_events);
                         let events_executor_adapter = EventsExecutorAdapter {
                             rdom,
                             vdom: &mut self.vdom,
+                            dispatched_events: dispatched_events.as_mut(),
                         };
                         events_executor_adapter.run(&mut self.nodes_state,
                             processed_events);
@@ -235,9 +293,33 @@ impl Application {
                             action: EventLoopMessageAction::PollVDOM,
                         })
                         .ok();
+                    true
                 }
-                std::task::Poll::Pending => return,
+                std::task::Poll::Pending => false,
             }
+        };
+
+        self.plugins.send(
+            PluginEvent::FinishedPollingVDOM {
+                window: &self.window,
+                fdom: &self.sdom.get(),
+            },
+            PluginHandle::new(&self.proxy),
+        );
+
+        if !is_ready {
+            return;
+        }
+
+        if let Some(dispatched_events) = dispatched_events.filter(|events| !events.is_empty()) {
+            self.plugins.send(
+                PluginEvent::DispatchedEvents {
+                    window: &self.window,
+                    fdom: &self.sdom.get(),
+                    events: &dispatched_events,
+                },
+                PluginHandle::new(&self.proxy),
+            );
         }
 
         let (must_repaint, must_relayout) =
@@ -298,27 +380,63 @@ impl Application {
         let rdom = fdom.rdom();
         let layout = fdom.layout();
         let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
-        self.accessibility
-            .init_accessibility(rdom, &layout, &mut dirty_accessibility_tree);
+        let tree =
+            self.accessibility
+                .init_accessibility(rdom, &layout, &mut dirty_accessibility_tree);
+        drop(dirty_accessibility_tree);
+        drop(layout);
+
+        self.plugins.send(
+            PluginEvent::AccessibilityTreeUpdated {
+                window: &self.window,
+                fdom: &fdom,
+                tree: &tree,
+            },
+            PluginHandle::new(&self.proxy),
+        );
     }
 
     pub fn process_accessibility(&mut self) {
-        let fdom = self.sdom.get();
-        let rdom = fdom.rdom();
-        let layout = fdom.layout();
-        let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
-        self.accessibility.process_updates(
-            rdom,
-            &layout,
-            &self.platform_sender,
-            &self.window,
-            &mut dirty_accessibility_tree,
-            &self.event_emitter,
-        );
+        {
+            let fdom = self.sdom.get();
+            let rdom = fdom.rdom();
+            let layout = fdom.layout();
+            let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
+            let tree = self.accessibility.process_updates(
+                rdom,
+                &layout,
+                &self.platform_sender,
+                &self.window,
+                &mut dirty_accessibility_tree,
+                &self.event_emitter,
+            );
+            drop(dirty_accessibility_tree);
+            drop(layout);
+
+            self.plugins.send(
+                PluginEvent::AccessibilityTreeUpdated {
+                    window: &self.window,
+                    fdom: &fdom,
+                    tree: &tree,
+                },
+                PluginHandle::new(&self.proxy),
+            );
+        }
+
+        // Keep the IME candidate window next to the text cursor
+        self.update_ime_cursor_area();
     }
 
     /// Send an event
     pub fn send_event(&mut self, event: PlatformEvent, scale_factor: f64) {
+        self.plugins.send(
+            PluginEvent::ReceivedPlatformEvent {
+                window: &self.window,
+                event: &event,
+                scale_factor,
+            },
+            PluginHandle::new(&self.proxy),
+        );
         self.events.push(event);
         self.process_events(scale_factor);
     }
@@ -406,6 +524,24 @@ impl Application {
         self.window.request_redraw();
     }
 
+    /// Place the IME candidate window at the text cursor of the focused node.
+    pub fn update_ime_cursor_area(&self) {
+        if !self.is_ime_enabled {
+            return;
+        }
+
+        let Some(node_id) = self.accessibility.focused_node_id() else {
+            return;
+        };
+
+        if let Some(area) = self.sdom.get().cursor_area(node_id) {
+            self.window.set_ime_cursor_area(
+                PhysicalPosition::new(area.min_x(), area.min_y()),
+                PhysicalSize::new(area.width(), area.height()),
+            );
+        }
+    }
+
     /// Notify components subscribed to event loop ticks.
     pub fn event_loop_tick(&self) {
         self.ticker_sender.send(()).ok();
@@ -442,6 +578,7 @@ impl Application {
             let mut dirty_accessibility_tree = fdom.accessibility_dirty_nodes();
             let mut compositor_dirty_nodes = fdom.compositor_dirty_nodes();
             let mut compositor_dirty_area = fdom.compositor_dirty_area();
+            let mut layout_observers = fdom.layout_observers();
 
             process_layout(
                 rdom,
@@ -450,11 +587,15 @@ impl Application {
                 &mut dirty_accessibility_tree,
                 &mut compositor_dirty_nodes,
                 &mut compositor_dirty_area,
+                &mut layout_observers,
                 Area::from_size(self.window.inner_size().to_torin()),
                 font_collection,
                 scale_factor as f32,
                 fallback_fonts,
             );
+
+            // Notify the elements whose layout changed
+            layout_observers.emit_events(&self.event_emitter);
         }
 
         self.plugins.send(
@@ -485,6 +626,7 @@ impl Application {
         fallback_fonts: &[String],
     ) {
         let fdom = self.sdom.get();
+        let mut dirty_layers = Layers::default();
 
         let mut render_pipeline = RenderPipeline {
             canvas_area: Area::from_size(self.window.inner_size().to_torin()),
@@ -504,6 +646,23 @@ impl Application {
             fallback_fonts,
             images_cache: &mut fdom.images_cache(),
         };
-        render_pipeline.run();
+
+        self.plugins.send(
+            PluginEvent::StartedCompositing {
+                window: &self.window,
+            },
+            PluginHandle::new(&self.proxy),
+        );
+
+        let rendering_layers = render_pipeline.compose(&mut dirty_layers);
+
+        self.plugins.send(
+            PluginEvent::FinishedCompositing {
+                window: &self.window,
+            },
+            PluginHandle::new(&self.proxy),
+        );
+
+        render_pipeline.paint(rendering_layers);
     }
 }
@@ -9,13 +9,18 @@ use freya_core::{
     style::fallback_fonts,
     window_config::WindowConfig,
 };
+use freya_elements::events::DragPayload;
 use image::ImageReader;
 use winit::{
     event_loop::EventLoopBuilder,
-    window::Icon,
+    window::{
+        Icon,
+        Window,
+    },
 };
 
 pub type EventLoopBuilderHook = Box<dyn FnOnce(&mut EventLoopBuilder<EventLoopMessage>)>;
+pub type DragOutHandler = Box<dyn FnMut(&Window, &DragPayload) -> bool>;
 pub type EmbeddedFonts<'a> = Vec<(&'a str, &'a [u8])>;
 
 /// Launch configuration.
@@ -28,6 +33,9 @@ pub struct LaunchConfig<'a> {
 
     /// Hook function called with the Event Loop Builder.
     pub event_loop_builder_hook: Option<EventLoopBuilderHook>,
+
+    /// Handler called when a drag session leaves the windows of the app.
+    pub drag_out_handler: Option<DragOutHandler>,
 }
 
 impl Default for LaunchConfig<'_> {
@@ -38,6 +46,7 @@ impl Default for LaunchConfig<'_> {
             plugins: Default::default(),
             fallback_fonts: fallback_fonts(),
             event_loop_builder_hook: None,
+            drag_out_handler: None,
         }
     }
 }
@@ -102,4 +111,16 @@ impl<'a> LaunchConfig<'a> {
         self.event_loop_builder_hook = Some(Box::new(event_loop_builder_hook));
         self
     }
+
+    /// Register a handler to hand the drag sessions that leave the windows of the app to the OS,
+    /// for example by starting a native drag of the `text` or `uri` representation of the payload from the given window.
+    ///
+    /// It returns whether the OS took over the drag session, in which case it ends in Freya without a drop.
+    pub fn with_drag_out_handler(
+        mut self,
+        drag_out_handler: impl FnMut(&Window, &DragPayload) -> bool + 'static,
+    ) -> Self {
+        self.drag_out_handler = Some(Box::new(drag_out_handler));
+        self
+    }
 }
@@ -1,4 +1,6 @@
 pub use config::*;
+#[cfg(feature = "recorder")]
+pub use recorder::*;
 pub use renderer::WinitRenderer;
 
 mod accessibility;
@@ -7,6 +9,8 @@ mod config;
 mod drivers;
 mod events;
 mod keyboard;
+#[cfg(feature = "recorder")]
+mod recorder;
 mod renderer;
 mod renderer_state;
 mod size;
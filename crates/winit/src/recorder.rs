@@ -0,0 +1,144 @@
+use std::{
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::PathBuf,
+    time::Instant,
+};
+
+use freya_core::{
+    events::{
+        KeyboardEventName,
+        PlatformEvent,
+        RecordedEntry,
+        RecordedInput,
+    },
+    plugins::{
+        FreyaPlugin,
+        PluginEvent,
+        PluginHandle,
+    },
+};
+use freya_elements::events::keyboard::Key;
+use torin::geometry::Size2D;
+use winit::window::{
+    Window,
+    WindowId,
+};
+
+/// Record every input received by the first window of the app into a file,
+/// so the session can later be replayed in tests with `freya-testing`.
+///
+/// Pressing the marker key (`F8` by default) records a marker, which can be used to take snapshots when replaying.
+///
+/// ```rust, no_run
+/// # use freya_winit::*;
+/// let config = LaunchConfig::new().with_plugin(InputRecorderPlugin::new("./session.jsonl"));
+/// ```
+pub struct InputRecorderPlugin {
+    path: PathBuf,
+    marker_key: Key,
+    window_id: Option<WindowId>,
+    writer: Option<BufWriter<File>>,
+    started: Instant,
+    size: Size2D,
+    markers: usize,
+}
+
+impl InputRecorderPlugin {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            marker_key: Key::F8,
+            window_id: None,
+            writer: None,
+            started: Instant::now(),
+            size: Size2D::default(),
+            markers: 0,
+        }
+    }
+
+    /// Change the key used to record markers.
+    pub fn with_marker_key(mut self, marker_key: Key) -> Self {
+        self.marker_key = marker_key;
+        self
+    }
+
+    fn record(&mut self, input: RecordedInput) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+
+        let entry = RecordedEntry {
+            time: self.started.elapsed().as_millis() as u64,
+            input,
+        };
+        let res = entry
+            .to_json_line()
+            .map_err(std::io::Error::from)
+            .and_then(|line| writer.write_all(line.as_bytes()))
+            .and_then(|_| writer.flush());
+
+        if let Err(err) = res {
+            tracing::error!("Failed to record input: {err}");
+            self.writer = None;
+        }
+    }
+
+    fn record_size(&mut self, window: &Window) {
+        let size = window.inner_size().to_logical::<f32>(window.scale_factor());
+        let size = Size2D::new(size.width, size.height);
+        if size != self.size {
+            self.size = size;
+            self.record(RecordedInput::Resize(size));
+        }
+    }
+}
+
+impl FreyaPlugin for InputRecorderPlugin {
+    fn on_event(&mut self, event: &PluginEvent, _handle: PluginHandle) {
+        match event {
+            PluginEvent::WindowCreated { window, .. } if self.window_id.is_none() => {
+                match File::create(&self.path) {
+                    Ok(file) => {
+                        self.window_id = Some(window.id());
+                        self.writer = Some(BufWriter::new(file));
+                        self.started = Instant::now();
+                        self.record_size(window);
+                    }
+                    Err(err) => {
+                        tracing::error!(
+                            "Failed to create the input recording {}: {err}",
+                            self.path.display()
+                        );
+                    }
+                }
+            }
+            PluginEvent::BeforeRender { window, .. } if self.window_id == Some(window.id()) => {
+                self.record_size(window);
+            }
+            PluginEvent::ReceivedPlatformEvent {
+                window,
+                event,
+                scale_factor,
+            } if self.window_id == Some(window.id()) => match event {
+                PlatformEvent::Keyboard { name, key, .. } if *key == self.marker_key => {
+                    if *name == KeyboardEventName::KeyDown {
+                        self.markers += 1;
+                        self.record(RecordedInput::Marker(format!("marker-{}", self.markers)));
+                    }
+                }
+                event => {
+                    let event = (*event).clone().scaled(1. / scale_factor);
+                    self.record(RecordedInput::Event(event));
+                }
+            },
+            PluginEvent::WindowClosed { window, .. } if self.window_id == Some(window.id()) => {
+                self.writer = None;
+            }
+            _ => {}
+        }
+    }
+}
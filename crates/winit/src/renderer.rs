@@ -7,7 +7,10 @@ use freya_core::{
         EventLoopMessageAction,
     },
     events::{
+        CompositionEventName,
+        DragEventName,
         FileEventName,
+        GestureEventName,
         KeyboardEventName,
         MouseEventName,
         PlatformEvent,
@@ -19,11 +22,17 @@ use freya_core::{
 };
 use freya_elements::events::{
     Code,
+    DragPayload,
     Key,
 };
-use torin::geometry::CursorPoint;
+use freya_engine::prelude::IRect;
+use torin::{
+    geometry::CursorPoint,
+    prelude::Area,
+};
 use winit::{
     application::ApplicationHandler,
+    dpi::PhysicalPosition,
     event::{
         ElementState,
         Ime,
@@ -40,6 +49,10 @@ use winit::{
         EventLoopProxy,
     },
     keyboard::ModifiersState,
+    window::{
+        Window,
+        WindowId,
+    },
 };
 
 use crate::{
@@ -54,7 +67,11 @@ use crate::{
         map_winit_modifiers,
         map_winit_physical_key,
     },
-    renderer_state::RendererState,
+    renderer_state::{
+        DragSession,
+        RendererState,
+    },
+    DragOutHandler,
     LaunchConfig,
 };
 
@@ -70,6 +87,7 @@ pub struct WinitRenderer {
     pub(crate) modifiers_state: ModifiersState,
     pub(crate) dropped_file_paths: Vec<PathBuf>,
     pub(crate) custom_scale_factor: f64,
+    pub(crate) drag_out_handler: Option<DragOutHandler>,
 }
 
 impl WinitRenderer {
@@ -92,7 +110,8 @@ impl WinitRenderer {
         event_loop.run_app(&mut winit_renderer).unwrap();
     }
 
-    pub fn new(config: LaunchConfig<'_>, proxy: EventLoopProxy<EventLoopMessage>) -> Self {
+    pub fn new(mut config: LaunchConfig<'_>, proxy: EventLoopProxy<EventLoopMessage>) -> Self {
+        let drag_out_handler = config.drag_out_handler.take();
         WinitRenderer {
             state: RendererState::new(
                 config.windows_configs,
@@ -107,8 +126,220 @@ impl WinitRenderer {
             modifiers_state: ModifiersState::default(),
             dropped_file_paths: Vec::new(),
             custom_scale_factor: 0.,
+            drag_out_handler,
+        }
+    }
+
+    /// Start a drag session in the given window.
+    fn start_drag(&mut self, window_id: WindowId, payload: DragPayload, preview: Option<Area>) {
+        let custom_scale_factor = self.custom_scale_factor;
+        let cursor = self.cursor_pos;
+        let Some(app) = self.state.apps.get_mut(&window_id) else {
+            return;
+        };
+        let scale_factor = app.window.scale_factor() + custom_scale_factor;
+
+        let preview = preview.and_then(|area| {
+            let area = area * scale_factor as f32;
+            let image = app.surface.image_snapshot_with_bounds(IRect::new(
+                area.min_x() as i32,
+                area.min_y() as i32,
+                area.max_x() as i32,
+                area.max_y() as i32,
+            ))?;
+            let offset = CursorPoint::new(
+                cursor.x - area.min_x() as f64,
+                cursor.y - area.min_y() as f64,
+            );
+            Some((image, offset))
+        });
+
+        self.state.drag_session = Some(DragSession {
+            payload,
+            source: window_id,
+            target: Some(window_id),
+            cursor,
+            preview,
+        });
+    }
+
+    /// Route the cursor and mouse events of an active drag session to the window under the cursor as drag events.
+    /// The events are still processed as usual afterwards.
+    fn process_drag_event(&mut self, window_id: WindowId, event: &WindowEvent) {
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_pos = CursorPoint::from((position.x, position.y));
+
+                let target = self.window_at(window_id, *position);
+                let Some(session) = self.state.drag_session.as_mut() else {
+                    return;
+                };
+
+                let previous_target = session.target;
+                session.target = target.map(|(target, _)| target);
+                if let Some((_, cursor)) = target {
+                    session.cursor = cursor;
+                }
+                let payload = session.payload.clone();
+                let source = session.source;
+
+                if previous_target != session.target {
+                    if let Some(previous_target) = previous_target {
+                        // Moving the cursor outside will emit the leave events in the previous window
+                        self.send_drag_event(
+                            previous_target,
+                            DragEventName::DragOver,
+                            CursorPoint::new(-1.0, -1.0),
+                            payload.clone(),
+                        );
+                    }
+                }
+
+                if let Some((target, cursor)) = target {
+                    self.send_drag_event(target, DragEventName::DragOver, cursor, payload);
+                } else if previous_target.is_some() {
+                    self.drag_out(source);
+                }
+
+                // Redraw the windows where the preview was or is now drawn
+                for window_id in [previous_target, target.map(|(target, _)| target)]
+                    .into_iter()
+                    .flatten()
+                    .filter(|window_id| *window_id != source)
+                {
+                    self.state
+                        .with_app(window_id, |app, _| app.window.request_redraw());
+                }
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Released,
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.mouse_state = ElementState::Released;
+
+                let Some(session) = self.state.drag_session.take() else {
+                    return;
+                };
+
+                if let Some(target) = session.target {
+                    self.send_drag_event(
+                        target,
+                        DragEventName::Drop,
+                        session.cursor,
+                        session.payload.clone(),
+                    );
+                    self.state
+                        .with_app(target, |app, _| app.window.request_redraw());
+                }
+
+                let mut windows = vec![session.source];
+                windows.extend(session.target.filter(|target| *target != session.source));
+
+                for window_id in windows {
+                    let cursor = if Some(window_id) == session.target {
+                        session.cursor
+                    } else {
+                        CursorPoint::new(-1.0, -1.0)
+                    };
+                    self.send_drag_event(
+                        window_id,
+                        DragEventName::DragEnd,
+                        cursor,
+                        session.payload.clone(),
+                    );
+                }
+            }
+            _ => {}
         }
     }
+
+    /// Hand the drag session to the OS if the cursor left the windows of the app and there is a handler for it.
+    fn drag_out(&mut self, source: WindowId) {
+        let (Some(handler), Some(session), Some(app)) = (
+            self.drag_out_handler.as_mut(),
+            self.state.drag_session.as_ref(),
+            self.state.apps.get(&source),
+        ) else {
+            return;
+        };
+
+        if !handler(&app.window, &session.payload) {
+            return;
+        }
+
+        // The OS took over, so the drag session ends here
+        if let Some(session) = self.state.drag_session.take() {
+            self.send_drag_event(
+                source,
+                DragEventName::DragEnd,
+                CursorPoint::new(-1.0, -1.0),
+                session.payload,
+            );
+            self.state
+                .with_app(source, |app, _| app.window.request_redraw());
+        }
+    }
+
+    /// Find the window under the given cursor position, relative to the given window.
+    fn window_at(
+        &self,
+        window_id: WindowId,
+        position: PhysicalPosition<f64>,
+    ) -> Option<(WindowId, CursorPoint)> {
+        let is_inside = |window: &Window, x: f64, y: f64| {
+            let size = window.inner_size();
+            x >= 0. && y >= 0. && x < size.width as f64 && y < size.height as f64
+        };
+
+        let app = self.state.apps.get(&window_id)?;
+
+        let Ok(origin) = app.window.inner_position() else {
+            // Some platforms (e.g Wayland) don't expose the windows positions, so only the
+            // window receiving the events can be targeted
+            return is_inside(&app.window, position.x, position.y)
+                .then_some((window_id, CursorPoint::new(position.x, position.y)));
+        };
+
+        let screen_x = origin.x as f64 + position.x;
+        let screen_y = origin.y as f64 + position.y;
+
+        // Prioritize the window receiving the events in case of overlapping windows
+        std::iter::once(app)
+            .chain(
+                self.state
+                    .apps
+                    .values()
+                    .filter(|app| app.window.id() != window_id),
+            )
+            .find_map(|app| {
+                let origin = app.window.inner_position().ok()?;
+                let x = screen_x - origin.x as f64;
+                let y = screen_y - origin.y as f64;
+                is_inside(&app.window, x, y).then_some((app.window.id(), CursorPoint::new(x, y)))
+            })
+    }
+
+    fn send_drag_event(
+        &mut self,
+        window_id: WindowId,
+        name: DragEventName,
+        cursor: CursorPoint,
+        payload: DragPayload,
+    ) {
+        let custom_scale_factor = self.custom_scale_factor;
+        self.state.with_app(window_id, |app, _| {
+            let scale_factor = app.window.scale_factor() + custom_scale_factor;
+            app.send_event(
+                PlatformEvent::Drag {
+                    name,
+                    cursor,
+                    payload,
+                },
+                scale_factor,
+            );
+        });
+    }
 }
 
 impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
@@ -174,6 +405,11 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
             return;
         }
 
+        if let EventLoopMessageAction::StartDrag { payload, preview } = action {
+            self.start_drag(window_id, payload, preview);
+            return;
+        }
+
         let mut remove_app = false;
         self.state.with_app(window_id, |app, _state| {
             let scale_factor = app.window.scale_factor() + custom_scale_factor;
@@ -221,6 +457,13 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                 EventLoopMessageAction::PollVDOM => {
                     app.poll_vdom();
                 }
+                EventLoopMessageAction::SetAttribute {
+                    node_id,
+                    attribute,
+                    value,
+                } => {
+                    app.set_attribute(node_id, attribute, value);
+                }
 
                 _ => {}
             }
@@ -243,6 +486,10 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
     ) {
         let custom_scale_factor = self.custom_scale_factor;
 
+        if self.state.drag_session.is_some() {
+            self.process_drag_event(window_id, &event);
+        }
+
         let mut remove_app = false;
 
         self.state.with_app(window_id, |app, state| {
@@ -257,7 +504,77 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                     });
                 }
 
+                WindowEvent::Ime(Ime::Enabled) => {
+                    app.is_ime_enabled = true;
+                    app.update_ime_cursor_area();
+                }
+                WindowEvent::Ime(Ime::Disabled) => {
+                    app.is_ime_enabled = false;
+
+                    if app.is_ime_composing {
+                        app.is_ime_composing = false;
+                        app.send_event(
+                            PlatformEvent::Composition {
+                                name: CompositionEventName::CompositionEnd,
+                                text: String::new(),
+                                cursor: None,
+                            },
+                            scale_factor,
+                        );
+                    }
+                }
+                WindowEvent::Ime(Ime::Preedit(text, cursor)) => {
+                    if text.is_empty() {
+                        // The composition was either cancelled or is about to be committed
+                        if app.is_ime_composing {
+                            app.is_ime_composing = false;
+                            app.send_event(
+                                PlatformEvent::Composition {
+                                    name: CompositionEventName::CompositionEnd,
+                                    text,
+                                    cursor: None,
+                                },
+                                scale_factor,
+                            );
+                        }
+                        return;
+                    }
+
+                    if !app.is_ime_composing {
+                        app.is_ime_composing = true;
+                        app.update_ime_cursor_area();
+                        app.send_event(
+                            PlatformEvent::Composition {
+                                name: CompositionEventName::CompositionStart,
+                                text: String::new(),
+                                cursor: None,
+                            },
+                            scale_factor,
+                        );
+                    }
+
+                    app.send_event(
+                        PlatformEvent::Composition {
+                            name: CompositionEventName::CompositionUpdate,
+                            text,
+                            cursor,
+                        },
+                        scale_factor,
+                    );
+                }
                 WindowEvent::Ime(Ime::Commit(text)) => {
+                    if app.is_ime_composing {
+                        app.is_ime_composing = false;
+                        app.send_event(
+                            PlatformEvent::Composition {
+                                name: CompositionEventName::CompositionEnd,
+                                text: String::new(),
+                                cursor: None,
+                            },
+                            scale_factor,
+                        );
+                    }
+
                     app.send_event(
                         PlatformEvent::Keyboard {
                             name: KeyboardEventName::KeyDown,
@@ -321,6 +638,10 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                         state.fallback_fonts,
                     );
 
+                    if let Some(drag_session) = state.drag_session {
+                        drag_session.render_preview(window_id, app.surface.canvas());
+                    }
+
                     app.event_loop_tick();
                     app.window.pre_present_notify();
                     app.graphics_driver.flush_and_submit();
@@ -353,7 +674,9 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                     );
                 }
                 WindowEvent::MouseWheel { delta, phase, .. } => {
-                    if TouchPhase::Moved == phase {
+                    // Touchpads also report when the gesture starts and ends, which is used for kinetic scrolling
+                    let is_touchpad = matches!(delta, MouseScrollDelta::PixelDelta(_));
+                    if TouchPhase::Moved == phase || is_touchpad {
                         let scroll_data = {
                             match delta {
                                 MouseScrollDelta::LineDelta(x, y) => (
@@ -372,11 +695,34 @@ impl ApplicationHandler<EventLoopMessage> for WinitRenderer {
                                 name: WheelEventName::Wheel,
                                 scroll: CursorPoint::from(scroll_data),
                                 cursor: self.cursor_pos,
+                                phase: map_winit_touch_phase(phase),
                             },
                             scale_factor,
                         );
                     }
                 }
+                WindowEvent::PinchGesture { delta, phase, .. } => {
+                    app.send_event(
+                        PlatformEvent::Gesture {
+                            name: GestureEventName::Pinch,
+                            cursor: self.cursor_pos,
+                            delta,
+                            phase: map_winit_touch_phase(phase),
+                        },
+                        scale_factor,
+                    );
+                }
+                WindowEvent::RotationGesture { delta, phase, .. } => {
+                    app.send_event(
+                        PlatformEvent::Gesture {
+                            name: GestureEventName::Rotate,
+                            cursor: self.cursor_pos,
+                            delta: delta as f64,
+                            phase: map_winit_touch_phase(phase),
+                        },
+                        scale_factor,
+                    );
+                }
                 WindowEvent::ModifiersChanged(modifiers) => {
                     self.modifiers_state = modifiers.state();
                 }
@@ -18,7 +18,9 @@ use freya_core::{
     },
     window_config::WindowConfig,
 };
+use freya_elements::events::DragPayload;
 use freya_engine::prelude::*;
+use torin::prelude::CursorPoint;
 use winit::{
     dpi::LogicalSize,
     event_loop::{
@@ -39,6 +41,40 @@ use crate::{
     EmbeddedFonts,
 };
 
+/// A drag session started by one of the windows, it ends once the mouse is released.
+pub struct DragSession {
+    pub(crate) payload: DragPayload,
+    /// Window that started the drag session.
+    pub(crate) source: WindowId,
+    /// Window currently under the cursor, if any.
+    pub(crate) target: Option<WindowId>,
+    /// Position of the cursor relative to the target window.
+    pub(crate) cursor: CursorPoint,
+    /// Snapshot of the dragged element and the position of the cursor relative to it.
+    pub(crate) preview: Option<(Image, CursorPoint)>,
+}
+
+impl DragSession {
+    /// Draw the preview of the dragged element if the cursor is over the given window.
+    /// The window that started the drag session is responsible of rendering its own preview.
+    pub fn render_preview(&self, window_id: WindowId, canvas: &Canvas) {
+        if self.target != Some(window_id) || self.source == window_id {
+            return;
+        }
+
+        if let Some((image, offset)) = &self.preview {
+            let x = (self.cursor.x - offset.x) as f32;
+            let y = (self.cursor.y - offset.y) as f32;
+            let rect = Rect::new(x, y, x + image.width() as f32, y + image.height() as f32);
+
+            canvas.save();
+            canvas.reset_matrix();
+            canvas.draw_image_rect(image, None, rect, &Paint::default());
+            canvas.restore();
+        }
+    }
+}
+
 pub struct RendererState {
     pub(crate) windows_configs: Vec<WindowConfig>,
     pub(crate) apps: HashMap<WindowId, Application>,
@@ -50,6 +86,8 @@ pub struct RendererState {
     pub(crate) plugins: PluginsManager,
     pub(crate) fallback_fonts: Vec<String>,
 
+    pub(crate) drag_session: Option<DragSession>,
+
     pub(crate) resumed: bool,
 }
 
@@ -84,6 +122,8 @@ impl RendererState {
             font_mgr,
             proxy: proxy.clone(),
 
+            drag_session: None,
+
             resumed: false,
         }
     }
@@ -101,6 +141,8 @@ impl RendererState {
 
                     font_collection: &mut self.font_collection,
                     font_mgr: &mut self.font_mgr,
+
+                    drag_session: &self.drag_session,
                 },
             );
         }
@@ -222,4 +264,6 @@ pub struct PartialRendererState<'a> {
 
     pub(crate) font_collection: &'a mut FontCollection,
     pub(crate) font_mgr: &'a mut FontMgr,
+
+    pub(crate) drag_session: &'a Option<DragSession>,
 }
@@ -70,6 +70,22 @@ fn app() -> Element {
                 BorderBox {
                     border: "2 0 0 0 inner red, 0 2 0 0 inner blue",
                 }
+
+                BorderBox {
+                    border: "2 inner dashed white",
+                }
+
+                BorderBox {
+                    border: "3 center dashed(12, 4) orange",
+                }
+
+                BorderBox {
+                    border: "4 inner dotted(6) linear-gradient(90deg, red 0%, blue 100%)",
+                }
+
+                BorderBox {
+                    border: "2 6 inner dashed green",
+                }
             }
         }
     )
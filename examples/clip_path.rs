@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+fn main() {
+    launch_with_params(app, "Clip Path", (500.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            direction: "horizontal",
+            width: "100%",
+            height: "100%",
+            spacing: "20",
+            rect {
+                background: "rgb(143, 67, 238)",
+                width: "120",
+                height: "120",
+                main_align: "center",
+                cross_align: "center",
+                clip_path: "circle(50%)",
+                onclick: move |_| clicks += 1,
+                label {
+                    color: "white",
+                    "Clicked {clicks} times"
+                }
+            }
+            rect {
+                background: "rgb(65, 53, 67)",
+                width: "120",
+                height: "120",
+                clip_path: "polygon(50% 0%, 100% 100%, 0% 100%)",
+            }
+            rect {
+                background: "linear-gradient(rgb(238, 67, 143), rgb(67, 143, 238))",
+                width: "120",
+                height: "120",
+                mask: "image",
+                mask_data: static_bytes(RUST_LOGO),
+            }
+        }
+    )
+}
@@ -0,0 +1,90 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        LaunchConfig::new()
+            .with_window(
+                WindowConfig::new(source_window)
+                    .with_title("Drag from here")
+                    .with_size(300., 300.),
+            )
+            .with_window(
+                WindowConfig::new(target_window)
+                    .with_title("Drop here")
+                    .with_size(300., 300.),
+            ),
+    );
+}
+
+#[derive(Clone, PartialEq)]
+struct Fruit(&'static str);
+
+fn source_window() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            spacing: "10",
+            padding: "10",
+            for fruit in ["Apple", "Banana", "Kiwi"] {
+                DragZone {
+                    key: "{fruit}",
+                    data: Fruit(fruit),
+                    text: fruit.to_string(),
+                    drag_element: rsx!(
+                        label {
+                            "{fruit}"
+                        }
+                    ),
+                    rect {
+                        background: "rgb(210, 210, 210)",
+                        corner_radius: "8",
+                        padding: "10",
+                        label {
+                            "{fruit}"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn target_window() -> Element {
+    let mut fruits = use_signal(Vec::<&'static str>::new);
+    let mut hovering = use_signal(|| false);
+
+    let dropped = fruits.read().join(", ");
+    let background = if hovering() {
+        "rgb(200, 230, 200)"
+    } else {
+        "white"
+    };
+
+    rsx!(
+        DropZone {
+            width: "100%",
+            height: "100%",
+            ondrop: move |fruit: Fruit| {
+                hovering.set(false);
+                fruits.push(fruit.0);
+            },
+            ondragover: move |_| hovering.set(true),
+            ondragleave: move |_| hovering.set(false),
+            rect {
+                width: "100%",
+                height: "100%",
+                padding: "10",
+                background,
+                label {
+                    "Dropped: {dropped}"
+                }
+            }
+        }
+    )
+}
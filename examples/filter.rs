@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+const FILTERS: [&str; 9] = [
+    "blur(3)",
+    "brightness(150%)",
+    "contrast(200%)",
+    "grayscale(100%)",
+    "hue-rotate(90deg)",
+    "invert(100%)",
+    "saturate(300%)",
+    "sepia(100%)",
+    "drop-shadow(4 4 6 rgb(0, 0, 0, 0.5))",
+];
+
+fn main() {
+    launch_with_params(app, "Filter", (600.0, 500.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            direction: "horizontal",
+            wrap_content: "wrap",
+            spacing: "20",
+            padding: "20",
+            for filter in FILTERS {
+                rect {
+                    key: "{filter}",
+                    spacing: "6",
+                    cross_align: "center",
+                    image {
+                        image_data: static_bytes(RUST_LOGO),
+                        width: "120",
+                        height: "120",
+                        filter,
+                    }
+                    label {
+                        font_size: "12",
+                        "{filter}"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -6,13 +6,14 @@
 use freya::prelude::*;
 
 fn main() {
-    launch_with_params(app, "Form", (300.0, 250.0));
+    launch_with_params(app, "Form", (300.0, 350.0));
 }
 
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 enum FormEntry {
     Name,
     Description,
+    Newsletter,
 }
 
 impl std::fmt::Display for FormEntry {
@@ -20,22 +21,48 @@ impl std::fmt::Display for FormEntry {
         match self {
             Self::Description => f.write_str("Description"),
             Self::Name => f.write_str("Name"),
+            Self::Newsletter => f.write_str("Newsletter"),
         }
     }
 }
 
 fn app() -> Element {
-    let form = use_form(|data| {
-        println!("Submitting: {data:?}");
-    });
+    let form = use_form(
+        || {
+            FormConfig::new()
+                .with_field(
+                    FormEntry::Name,
+                    FormField::text("").required("The name is required"),
+                )
+                .with_field(
+                    FormEntry::Description,
+                    FormField::text("").with_validator(|value| {
+                        if value.to_string().len() > 20 {
+                            Err("The description is too long".to_string())
+                        } else {
+                            Ok(())
+                        }
+                    }),
+                )
+                .with_field(FormEntry::Newsletter, FormField::bool(false))
+        },
+        |data| {
+            println!("Submitting: {data:?}");
+        },
+    );
 
     rsx!(
         Input {
             ..form.input(FormEntry::Name)
         }
+        {form.error_label(&FormEntry::Name)}
         Input {
             ..form.input(FormEntry::Description)
         }
+        {form.error_label(&FormEntry::Description)}
+        Switch {
+            ..form.switch(FormEntry::Newsletter)
+        }
         Button {
             children: rsx!(
                 label {
@@ -44,5 +71,11 @@ fn app() -> Element {
             ),
             ..form.submit(),
         }
+        Button {
+            onpress: move |_| form.reset(),
+            label {
+                "Reset"
+            }
+        }
     )
 }
@@ -0,0 +1,64 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_params(app, "Rich Text Editor", (600.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut editable = use_rich_editable(|| {
+        EditableConfig::new_rich(&[
+            TextSpan::new("Select some text and press ", SpanStyle::default()),
+            TextSpan::new("Ctrl+B", SpanStyle::default().with_bold(true)),
+            TextSpan::new(" or ", SpanStyle::default()),
+            TextSpan::new("Ctrl+I", SpanStyle::default().with_italic(true)),
+            TextSpan::new(", or use the ", SpanStyle::default()),
+            TextSpan::new(
+                "buttons",
+                SpanStyle::default().with_link("https://freyaui.dev"),
+            ),
+            TextSpan::new(".", SpanStyle::default()),
+        ])
+    });
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "10",
+            spacing: "10",
+            rect {
+                direction: "horizontal",
+                spacing: "5",
+                Button {
+                    onpress: move |_| editable.editor_mut().write().toggle_bold(),
+                    label { "Bold" }
+                }
+                Button {
+                    onpress: move |_| editable.editor_mut().write().toggle_italic(),
+                    label { "Italic" }
+                }
+                Button {
+                    onpress: move |_| editable.editor_mut().write().set_color(Some("rgb(220, 50, 50)".to_string())),
+                    label { "Red" }
+                }
+                Button {
+                    onpress: move |_| editable.editor_mut().write().set_font_size(Some(24.)),
+                    label { "Big" }
+                }
+                Button {
+                    onpress: move |_| editable.editor_mut().write().set_link(Some("https://freyaui.dev".to_string())),
+                    label { "Link" }
+                }
+            }
+            RichTextEditor {
+                editable,
+                height: "fill",
+            }
+        }
+    )
+}
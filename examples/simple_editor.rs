@@ -23,10 +23,17 @@ fn app() -> Element {
         EditableMode::MultipleLinesSingleEditor,
     );
 
+    let focus = use_focus();
     let cursor_reference = editable.cursor_attr();
     let highlights = editable.highlights_attr(0);
     let editor = editable.editor().read();
-    let cursor_char = editor.cursor_pos();
+    let cursor_char = editor.visible_cursor_pos();
+    let text = editor.to_string();
+    // Show the text being composed with an IME where it will be inserted
+    let (before, composition, after) =
+        editable
+            .split_composition(&text, 0)
+            .unwrap_or((text.as_str(), String::new(), ""));
 
     let onmousedown = move |e: MouseEvent| {
         editable.process_event(&EditableEvent::MouseDown(e.data, 0));
@@ -48,10 +55,27 @@ fn app() -> Element {
         editable.process_event(&EditableEvent::KeyUp(e.data));
     };
 
+    let oncompositionstart = move |_| {
+        editable.process_event(&EditableEvent::CompositionStart);
+    };
+
+    let oncompositionupdate = move |e: CompositionEvent| {
+        editable.process_event(&EditableEvent::CompositionUpdate(e.data));
+    };
+
+    let oncompositionend = move |_| {
+        editable.process_event(&EditableEvent::CompositionEnd);
+    };
+
     rsx!(
         rect {
             width: "100%",
             height: "100%",
+            a11y_id: focus.attribute(),
+            a11y_auto_focus: true,
+            oncompositionstart,
+            oncompositionupdate,
+            oncompositionend,
             ScrollView {
                 width: "100%",
                 height: "calc(100% - 30)",
@@ -72,7 +96,14 @@ fn app() -> Element {
                     onglobalkeydown,
                     onglobalkeyup,
                     text {
-                        "{editable.editor()}"
+                        "{before}"
+                    }
+                    text {
+                        decoration: "underline",
+                        "{composition}"
+                    }
+                    text {
+                        "{after}"
                     }
                 }
             }
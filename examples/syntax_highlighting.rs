@@ -0,0 +1,144 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_params(app, "Syntax highlighting", (600.0, 400.0));
+}
+
+const CODE: &str = r#"/*
+  Edit me!
+*/
+fn main() {
+    let text = "Hello, World!";
+    println!("{text}");
+}
+"#;
+
+const KEYWORDS: &[&str] = &["fn", "let", "mut", "pub", "struct", "impl", "use", "return"];
+
+/// Tokenizer for a small subset of Rust.
+struct RustTokenizer;
+
+impl Tokenizer for RustTokenizer {
+    /// Whether the line starts inside a block comment.
+    type State = bool;
+
+    fn tokenize_line(&self, line: &str, in_comment: &mut bool) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < line.len() {
+            let rest = &line[i..];
+            if *in_comment {
+                let end = rest.find("*/").map(|end| i + end + 2);
+                *in_comment = end.is_none();
+                let end = end.unwrap_or(line.len());
+                tokens.push(Token::new(
+                    i..end,
+                    SpanStyle::default().with_color("rgb(110, 150, 110)"),
+                ));
+                i = end;
+            } else if rest.starts_with("/*") {
+                *in_comment = true;
+            } else if rest.starts_with('"') {
+                let end = rest[1..].find('"').map_or(line.len(), |end| i + end + 2);
+                tokens.push(Token::new(
+                    i..end,
+                    SpanStyle::default().with_color("rgb(210, 150, 110)"),
+                ));
+                i = end;
+            } else {
+                let word_len = rest
+                    .find(|c: char| !c.is_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len())
+                    .max(rest.chars().next().unwrap().len_utf8());
+                if KEYWORDS.contains(&&rest[..word_len]) {
+                    tokens.push(Token::new(
+                        i..i + word_len,
+                        SpanStyle::default()
+                            .with_color("rgb(90, 150, 220)")
+                            .with_bold(true),
+                    ));
+                }
+                i += word_len;
+            }
+        }
+
+        tokens
+    }
+}
+
+fn app() -> Element {
+    let mut editable = use_editable(
+        || EditableConfig::new(CODE.to_string()).with_allow_tabs(true),
+        EditableMode::MultipleLinesSingleEditor,
+    );
+    let highlighted = use_syntax_highlighter(editable, || RustTokenizer);
+    let mut focus = use_focus();
+
+    let cursor_reference = editable.cursor_attr();
+    let highlights = editable.highlights_attr(0);
+    let cursor_char = editable.editor().read().cursor_pos();
+
+    let onmousedown = move |e: MouseEvent| {
+        focus.request_focus();
+        editable.process_event(&EditableEvent::MouseDown(e.data, 0));
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        editable.process_event(&EditableEvent::MouseMove(e.data, 0));
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        editable.process_event(&EditableEvent::Click);
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        editable.process_event(&EditableEvent::KeyDown(e.data));
+    };
+
+    let onglobalkeyup = move |e: KeyboardEvent| {
+        editable.process_event(&EditableEvent::KeyUp(e.data));
+    };
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "10",
+            background: "rgb(30, 30, 30)",
+            paragraph {
+                cursor_reference,
+                a11y_id: focus.attribute(),
+                width: "100%",
+                font_family: "Jetbrains Mono",
+                font_size: "15",
+                color: "rgb(230, 230, 230)",
+                cursor_id: "0",
+                cursor_index: "{cursor_char}",
+                cursor_mode: "editable",
+                cursor_color: "white",
+                highlights,
+                onglobalclick,
+                onmousemove,
+                onmousedown,
+                onkeydown,
+                onglobalkeyup,
+                for (line_idx, line) in highlighted.read().iter().enumerate() {
+                    for (span_idx, span) in line.spans.iter().enumerate() {
+                        text {
+                            key: "{line_idx}-{span_idx}",
+                            color: span.style.color.clone().unwrap_or_else(|| "inherit".to_string()),
+                            font_weight: if span.style.bold { "bold" } else { "normal" },
+                            "{span.text}"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_params(app, "Transform", (400.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+    let animation = use_animation(|conf| {
+        conf.on_creation(OnCreation::Run);
+        conf.on_finish(OnFinish::Reverse);
+        AnimNum::new(0., 30.).time(1500).ease(Ease::InOut)
+    });
+
+    let skew = animation.get().read().read();
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            width: "100%",
+            height: "100%",
+            spacing: "30",
+            rect {
+                background: "rgb(143, 67, 238)",
+                width: "150",
+                height: "100",
+                main_align: "center",
+                cross_align: "center",
+                transform_origin: "top left",
+                translate: "40 0",
+                skew: "{skew}deg",
+                rotate: "10deg",
+                onclick: move |_| clicks += 1,
+                label {
+                    color: "white",
+                    "Clicked {clicks} times"
+                }
+            }
+            rect {
+                background: "rgb(65, 53, 67)",
+                width: "150",
+                height: "100",
+                matrix: "1, 0.2, -0.3, 1, 0, 0",
+            }
+        }
+    )
+}